@@ -0,0 +1,23 @@
+// Sonic
+//
+// Fast, lightweight and schema-less search backend
+// Copyright: 2019, Valerian Saliou <valerian@valeriansaliou.name>
+// License: Mozilla Public License v2.0 (MPL v2.0)
+
+#[cfg(feature = "transport-grpc")]
+fn main() {
+    // Compile Protocol Buffers definitions (this uses a pure-Rust compiler, thus 'protoc' is not \
+    //   required to build Sonic with gRPC support)
+    let file_descriptors = protox::compile(["proto/sonic.proto"], ["proto/"])
+        .expect("failed compiling grpc protocol definitions");
+
+    tonic_build::configure()
+        .build_client(false)
+        .compile_fds(file_descriptors)
+        .expect("failed generating grpc service");
+
+    println!("cargo:rerun-if-changed=proto/sonic.proto");
+}
+
+#[cfg(not(feature = "transport-grpc"))]
+fn main() {}
@@ -5,24 +5,61 @@
 // License: Mozilla Public License v2.0 (MPL v2.0)
 
 use hashbrown::HashMap;
+use log::LevelFilter;
 use rand::distributions::Alphanumeric;
 use rand::{thread_rng, Rng};
+use sha2::{Digest, Sha256};
 use std::fmt;
+use std::net::SocketAddr;
+use std::panic::{self, AssertUnwindSafe};
 use std::path::Path;
-use std::str::{self, SplitWhitespace};
+use std::str::{self, FromStr, SplitWhitespace};
+use std::time::{Duration, Instant};
 use std::vec::Vec;
-
-use super::format::unescape;
-use super::statistics::ChannelStatistics;
+use unicode_segmentation::UnicodeSegmentation;
+use whatlang::Lang;
+
+use super::format::{decode_cursor, escape, unescape};
+use super::message::{ChannelMessage, ChannelMessageMode, ChannelMessageModeSearch};
+use super::statistics::{ChannelConnections, ChannelStatistics};
+use crate::executor::clone::ExecutorClone;
+use crate::executor::compactiids::ExecutorCompactIIDs;
+use crate::executor::dumpfst::ExecutorDumpFST;
+use crate::executor::fsck::ExecutorFsck;
+use crate::executor::push::{PUSH_REJECT_DOCID_IN_USE, PUSH_REJECT_VECTOR_DIMENSION};
+use crate::executor::rebuildreverseindex::ExecutorRebuildReverseIndex;
+use crate::executor::reindex::ExecutorReindex;
+use crate::executor::reopen::ExecutorReopen;
+use crate::executor::vacuum::ExecutorVacuum;
+use crate::executor::verifyconsistency::ExecutorVerifyConsistency;
+use crate::executor::verifyfst::ExecutorVerifyFST;
+use crate::lexer::stopwords::LexerStopWord;
+use crate::lexer::synonyms::LexerSynonyms;
 use crate::query::builder::{QueryBuilder, QueryBuilderResult};
+use crate::query::cache::QueryCache;
+use crate::query::guard::{QueryGuard, GUARD_REJECT_OID_TOO_LONG, GUARD_REJECT_TEXT_TOO_LONG};
 use crate::query::types::{
-    ListMetaData, QueryGenericLang, QueryMetaData, QuerySearchLimit, QuerySearchOffset,
+    ListMetaData, PushMetaData, QueryGenericGeo, QueryGenericLang, QueryGenericSegment,
+    QueryGenericStopwords, QueryGenericTags, QueryGenericVector, QueryMetaData, QueryPushChecksum,
+    QueryPushDocID, QueryPushOptions, QueryPushPriority, QueryPushWeight, QuerySearchAbstract,
+    QuerySearchBoost, QuerySearchBucketWeight, QuerySearchDecay, QuerySearchHighlightStyle,
+    QuerySearchLimit, QuerySearchMustNot, QuerySearchOffset, QuerySearchOptions,
+    QuerySearchPenalty, QuerySearchRandom, QuerySearchRecent, QuerySearchShard, QuerySearchSortBy,
+    QuerySearchSpan, QuerySearchTermWeight, QuerySearchTimeout, QuerySearchWithinDistance,
+    QuerySuggestContext, QuerySuggestMinFreq, QuerySuggestOptions, SuggestMetaData,
+    VsearchMetaData,
 };
 use crate::store::fst::StoreFSTPool;
+use crate::store::item::StoreItemBuilder;
 use crate::store::kv::StoreKVPool;
 use crate::store::operation::StoreOperationDispatch;
+use crate::tasker::compaction::TaskerCompaction;
+use crate::tasker::executor_pool::{TaskerExecutorJob, TaskerExecutorPool};
 use crate::APP_CONF;
 
+#[cfg(unix)]
+use nix::libc;
+
 #[derive(PartialEq)]
 pub enum ChannelCommandError {
     UnknownCommand,
@@ -55,28 +92,84 @@ pub struct ChannelCommandControl;
 
 pub type ChannelCommandResponseArgs = (&'static str, Option<Vec<String>>);
 
-type ChannelResult = Result<Vec<ChannelCommandResponse>, ChannelCommandError>;
+pub type ChannelResult = Result<Vec<ChannelCommandResponse>, ChannelCommandError>;
+pub type ChannelPendingResult = Result<(Option<String>, Vec<ChannelCommandResponse>), ()>;
 type MetaPartsResult<'a> = Result<(&'a str, &'a str), (&'a str, &'a str)>;
 
 pub const EVENT_ID_SIZE: usize = 8;
+pub const MUX_ID_SIZE_MAX: usize = 32;
+pub const QUERY_PENALTY_DEFAULT: QuerySearchPenalty = 0.5;
 
 const TEXT_PART_BOUNDARY: char = '"';
 const TEXT_PART_ESCAPE: char = '\\';
 const META_PART_GROUP_OPEN: char = '(';
 const META_PART_GROUP_CLOSE: char = ')';
+const QUERY_OR_OPERATOR: &str = "|";
+const QUERY_NOT_OPERATOR: &str = "!";
+const QUERY_PHRASE_DELIMITERS: [(char, char); 2] = [('[', ']'), ('\'', '\'')];
 
 static BACKUP_KV_PATH: &str = "kv";
 static BACKUP_FST_PATH: &str = "fst";
 
 lazy_static! {
     pub static ref COMMANDS_MODE_SEARCH: Vec<&'static str> =
-        vec!["QUERY", "SUGGEST", "LIST", "PING", "HELP", "QUIT"];
-    pub static ref COMMANDS_MODE_INGEST: Vec<&'static str> =
-        vec!["PUSH", "POP", "COUNT", "FLUSHC", "FLUSHB", "FLUSHO", "PING", "HELP", "QUIT"];
-    pub static ref COMMANDS_MODE_CONTROL: Vec<&'static str> =
-        vec!["TRIGGER", "INFO", "PING", "HELP", "QUIT"];
-    pub static ref CONTROL_TRIGGER_ACTIONS: Vec<&'static str> =
-        vec!["consolidate", "backup", "restore"];
+        vec!["QUERY", "SUGGEST", "LIST", "VSEARCH", "GET", "PING", "HELP", "QUIT"];
+    pub static ref COMMANDS_MODE_INGEST: Vec<&'static str> = vec![
+        "PUSH",
+        "MPUSH",
+        "POP",
+        "COUNT",
+        "FLUSHC",
+        "FLUSHB",
+        "FLUSHO",
+        "FLUSHT",
+        "FLUSH_ORPHANS",
+        "LEARN",
+        "INSPECT",
+        "LISTCOLLECTIONS",
+        "LISTBUCKETS",
+        "PING",
+        "HELP",
+        "QUIT"
+    ];
+    pub static ref COMMANDS_MODE_CONTROL: Vec<&'static str> = vec![
+        "TRIGGER",
+        "INFO",
+        "CHANNEL",
+        "LISTCOLLECTIONS",
+        "LISTBUCKETS",
+        "PING",
+        "HELP",
+        "QUIT"
+    ];
+    pub static ref CONTROL_TRIGGER_ACTIONS: Vec<&'static str> = vec![
+        "consolidate",
+        "backup",
+        "backup_incremental",
+        "backup_prune",
+        "restore",
+        "verify",
+        "export",
+        "import",
+        "compact_iids",
+        "clone",
+        "dump_fst",
+        "reopen",
+        "verify_fst",
+        "verify_consistency",
+        "rebuild_reverse_index",
+        "reindex",
+        "vacuum",
+        "fsck",
+        "index_stats",
+        "compaction_stats",
+        "compaction_schedule",
+        "import_stopwords",
+        "reload_synonym_map",
+        "profile",
+        "set_log_level",
+        "kill_connection"
+    ];
     static ref MANUAL_MODE_SEARCH: HashMap<&'static str, &'static Vec<&'static str>> =
         [("commands", &*COMMANDS_MODE_SEARCH)]
             .iter()
@@ -129,6 +222,32 @@ impl ChannelCommandBase {
         }
     }
 
+    pub fn dispatch_listcollections(mut parts: SplitWhitespace) -> ChannelResult {
+        match parts.next() {
+            None => {
+                debug!("dispatching list collections");
+
+                // Make 'listcollections' query
+                Self::commit_result_operation(QueryBuilder::listcollections())
+            }
+            _ => Err(ChannelCommandError::InvalidFormat("LISTCOLLECTIONS")),
+        }
+    }
+
+    pub fn dispatch_listbuckets(mut parts: SplitWhitespace) -> ChannelResult {
+        match (parts.next(), parts.next()) {
+            (Some(collection), None) => {
+                debug!("dispatching list buckets in collection: {}", collection);
+
+                // Make 'listbuckets' query
+                Self::commit_result_operation(QueryBuilder::listbuckets(collection))
+            }
+            _ => Err(ChannelCommandError::InvalidFormat(
+                "LISTBUCKETS <collection>",
+            )),
+        }
+    }
+
     pub fn generic_dispatch_help(
         mut parts: SplitWhitespace,
         manuals: &HashMap<&str, &Vec<&str>>,
@@ -160,7 +279,16 @@ impl ChannelCommandBase {
         }
     }
 
-    pub fn parse_text_parts(parts: &mut SplitWhitespace) -> Option<String> {
+    pub fn parse_text_parts(
+        parts: &mut SplitWhitespace,
+    ) -> Result<Option<String>, ChannelCommandError> {
+        Self::parse_text_parts_limited(parts, QueryGuard::max_text_length())
+    }
+
+    pub fn parse_text_parts_limited(
+        parts: &mut SplitWhitespace,
+        max_text_length: Option<usize>,
+    ) -> Result<Option<String>, ChannelCommandError> {
         // Parse text parts and nest them together
         let mut text_raw = String::new();
 
@@ -210,7 +338,7 @@ impl ChannelCommandBase {
         {
             info!("could not properly parse text parts: {}", text_raw);
 
-            None
+            Ok(None)
         } else {
             debug!(
                 "parsed text parts (still needs post-processing): {}",
@@ -224,11 +352,15 @@ impl ChannelCommandBase {
 
                     debug!("parsed text parts (post-processed): {}", text_inner_string);
 
-                    // Text must not be empty
-                    if !text_inner_string.is_empty() {
-                        Some(text_inner_string)
+                    // Text must not be empty, and must not be over length limit (if any)
+                    if text_inner_string.is_empty() {
+                        Ok(None)
+                    } else if QueryGuard::text_over_limit(&text_inner_string, max_text_length) {
+                        Err(ChannelCommandError::PolicyReject(
+                            GUARD_REJECT_TEXT_TOO_LONG,
+                        ))
                     } else {
-                        None
+                        Ok(Some(text_inner_string))
                     }
                 }
                 Err(err) => {
@@ -237,7 +369,7 @@ impl ChannelCommandBase {
                         text_raw, err
                     );
 
-                    None
+                    Ok(None)
                 }
             }
         }
@@ -288,6 +420,243 @@ impl ChannelCommandBase {
         }
     }
 
+    pub fn parse_mux(meta_value: &str) -> Option<String> {
+        // Multiplexing identifiers are echoed back as-is in responses, so they must be kept short
+        if !meta_value.is_empty() && meta_value.len() <= MUX_ID_SIZE_MAX {
+            Some(meta_value.to_owned())
+        } else {
+            None
+        }
+    }
+
+    pub fn parse_boost(meta_value: &str) -> Option<QuerySearchBoost> {
+        // Boosts are formatted as '<word>,<factor>' (no space is allowed, as meta parts are \
+        //   separated by spaces); the factor must be a strictly positive number
+        let mut boost_parts = meta_value.splitn(2, ',');
+
+        match (boost_parts.next(), boost_parts.next()) {
+            (Some(word), Some(factor)) if !word.is_empty() => match factor.parse::<f32>() {
+                Ok(factor) if factor.is_finite() && factor > 0.0 => {
+                    Some((word.to_lowercase(), factor))
+                }
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+
+    pub fn parse_term_weights(meta_value: &str) -> Option<Vec<QuerySearchTermWeight>> {
+        // Term weights are formatted as '<term>:<weight>,<term>:<weight>' (no space is allowed, \
+        //   as meta parts are separated by spaces); weights must be strictly positive numbers, \
+        //   and a term cannot be weighted twice
+        let mut term_weights: Vec<QuerySearchTermWeight> = Vec::new();
+
+        for term_weight_value in meta_value.split(',') {
+            let (term, weight) = term_weight_value.split_once(':')?;
+            let term = term.to_lowercase();
+
+            match weight.parse::<f32>() {
+                Ok(weight)
+                    if !term.is_empty()
+                        && weight.is_finite()
+                        && weight > 0.0
+                        && !term_weights
+                            .iter()
+                            .any(|(weighted_term, _)| *weighted_term == term) =>
+                {
+                    term_weights.push((term, weight))
+                }
+                _ => return None,
+            }
+        }
+
+        Some(term_weights)
+    }
+
+    pub fn parse_buckets(meta_value: &str) -> Option<Vec<String>> {
+        // Buckets are formatted as '<bucket>,<bucket>' (no space is allowed, as meta parts are \
+        //   separated by spaces)
+        let buckets: Vec<String> = meta_value.split(',').map(str::to_string).collect();
+
+        if buckets.iter().all(|bucket| !bucket.is_empty()) {
+            Some(buckets)
+        } else {
+            None
+        }
+    }
+
+    pub fn parse_bucket_weights(meta_value: &str) -> Option<Vec<QuerySearchBucketWeight>> {
+        // Bucket weights are formatted as '<bucket>:<factor>,<bucket>:<factor>' (no space is \
+        //   allowed); factors must be strictly positive numbers, and a bucket cannot be \
+        //   weighted twice
+        let mut bucket_weights: Vec<QuerySearchBucketWeight> = Vec::new();
+
+        for bucket_weight_value in meta_value.split(',') {
+            let (bucket, factor) = bucket_weight_value.split_once(':')?;
+
+            match factor.parse::<f32>() {
+                Ok(factor)
+                    if !bucket.is_empty()
+                        && factor.is_finite()
+                        && factor > 0.0
+                        && !bucket_weights
+                            .iter()
+                            .any(|(weighted_bucket, _)| weighted_bucket == bucket) =>
+                {
+                    bucket_weights.push((bucket.to_string(), factor))
+                }
+                _ => return None,
+            }
+        }
+
+        Some(bucket_weights)
+    }
+
+    pub fn parse_geo(meta_value: &str) -> Option<QueryGenericGeo> {
+        // Locations are formatted as '<latitude>,<longitude>' (no space is allowed)
+        let mut geo_parts = meta_value.splitn(2, ',');
+
+        match (
+            geo_parts.next().map(|part| part.parse::<f32>()),
+            geo_parts.next().map(|part| part.parse::<f32>()),
+        ) {
+            (Some(Ok(latitude)), Some(Ok(longitude)))
+                if (-90.0..=90.0).contains(&latitude) && (-180.0..=180.0).contains(&longitude) =>
+            {
+                Some((latitude, longitude))
+            }
+            _ => None,
+        }
+    }
+
+    pub fn parse_within_distance(meta_value: &str) -> Option<QuerySearchWithinDistance> {
+        // Distances are formatted as '<latitude>,<longitude>,<distance>' (no space is allowed)
+        let (geo_value, distance_value) = meta_value.rsplit_once(',')?;
+
+        match (Self::parse_geo(geo_value), distance_value.parse::<f32>()) {
+            (Some(geo), Ok(distance)) if distance.is_finite() && distance > 0.0 => {
+                Some((geo, distance))
+            }
+            _ => None,
+        }
+    }
+
+    pub fn parse_penalty(meta_value: &str) -> Option<QuerySearchPenalty> {
+        // Penalties are formatted as '<factor>', and must be within ]0.0, 1.0]
+        match meta_value.parse::<f32>() {
+            Ok(penalty) if penalty > 0.0 && penalty <= 1.0 => Some(penalty),
+            _ => None,
+        }
+    }
+
+    pub fn parse_shard(meta_value: &str) -> Option<QuerySearchShard> {
+        // Shards are formatted as '<shard_id>,<total_shards>' (no space is allowed), where the \
+        //   shard identifier must be lower than the total number of shards
+        let mut shard_parts = meta_value.splitn(2, ',');
+
+        match (
+            shard_parts.next().map(|part| part.parse::<u32>()),
+            shard_parts.next().map(|part| part.parse::<u32>()),
+        ) {
+            (Some(Ok(shard_id)), Some(Ok(total_shards))) if shard_id < total_shards => {
+                Some((shard_id, total_shards))
+            }
+            _ => None,
+        }
+    }
+
+    pub fn parse_weight(meta_value: &str) -> Option<QueryPushWeight> {
+        // Weights are formatted as '<weight>', and must be within [0.0, 1.0]
+        match meta_value.parse::<f32>() {
+            Ok(weight) if (0.0..=1.0).contains(&weight) => Some(weight),
+            _ => None,
+        }
+    }
+
+    pub fn parse_tags(meta_value: &str) -> Option<QueryGenericTags> {
+        // Tags are formatted as '<tag>,<tag>' (no space is allowed, as meta parts are separated \
+        //   by spaces); they are stored sorted and de-duplicated, as they form a set
+        let mut tags: QueryGenericTags = meta_value.split(',').map(|tag| tag.to_string()).collect();
+
+        if tags.iter().all(|tag| !tag.is_empty()) {
+            tags.sort_unstable();
+            tags.dedup();
+
+            Some(tags)
+        } else {
+            None
+        }
+    }
+
+    pub fn parse_must_not(meta_value: &str) -> Option<QuerySearchMustNot> {
+        // Excluded terms are formatted as '"<terms>"' or '<terms>' (no space is allowed, as meta \
+        //   parts are separated by spaces; multiple terms can be separated with commas)
+        let must_not = meta_value
+            .strip_prefix('"')
+            .and_then(|meta_value| meta_value.strip_suffix('"'))
+            .unwrap_or(meta_value);
+
+        if !must_not.is_empty() && !must_not.contains('"') {
+            Some(must_not.to_string())
+        } else {
+            None
+        }
+    }
+
+    pub fn parse_vector(meta_value: &str) -> Option<QueryGenericVector> {
+        // Vectors are formatted as '<number>,<number>' (no space is allowed, as meta parts are \
+        //   separated by spaces); they are normalized to a unit length, thus a null vector is \
+        //   invalid
+        let vector: QueryGenericVector = meta_value
+            .split(',')
+            .map(|component| component.parse::<f32>())
+            .collect::<Result<_, _>>()
+            .ok()?;
+
+        let norm = vector
+            .iter()
+            .map(|component| component * component)
+            .sum::<f32>()
+            .sqrt();
+
+        if norm.is_finite() && norm > 0.0 {
+            Some(vector.iter().map(|component| component / norm).collect())
+        } else {
+            None
+        }
+    }
+
+    pub fn parse_checksum(meta_value: &str) -> Option<QueryPushChecksum> {
+        // Checksums are formatted as the hexadecimal SHA-256 digest of a text (64 characters, \
+        //   either lowercase or uppercase)
+        if meta_value.len() != 64 || !meta_value.is_ascii() {
+            return None;
+        }
+
+        let mut checksum = [0; 32];
+
+        for (index, byte) in checksum.iter_mut().enumerate() {
+            *byte = u8::from_str_radix(&meta_value[(index * 2)..(index * 2 + 2)], 16).ok()?;
+        }
+
+        Some(checksum)
+    }
+
+    pub fn parse_context(meta_value: &str) -> Option<QuerySuggestContext> {
+        // Context words are formatted as '<word>,<word>' (no space is allowed, as meta parts are \
+        //   separated by spaces)
+        let context: QuerySuggestContext = meta_value
+            .split(',')
+            .map(|word| word.to_lowercase())
+            .collect();
+
+        if context.iter().all(|word| !word.is_empty()) {
+            Some(context)
+        } else {
+            None
+        }
+    }
+
     pub fn make_error_invalid_meta_key(meta_key: &str, meta_value: &str) -> ChannelCommandError {
         ChannelCommandError::InvalidMetaKey((meta_key.to_owned(), meta_value.to_owned()))
     }
@@ -296,11 +665,21 @@ impl ChannelCommandBase {
         ChannelCommandError::InvalidMetaValue((meta_key.to_owned(), meta_value.to_owned()))
     }
 
-    pub fn commit_ok_operation(query_builder: QueryBuilderResult) -> ChannelResult {
-        query_builder
-            .and_then(StoreOperationDispatch::dispatch)
-            .map(|_| vec![ChannelCommandResponse::Ok])
-            .or(Err(ChannelCommandError::QueryError))
+    pub fn commit_ok_operation(
+        query_builder: QueryBuilderResult,
+        reject_reasons: &[&'static str],
+    ) -> ChannelResult {
+        // Notice: an operation committed there yields a result only if it got rejected, in \
+        //   which case the result holds the reject reason
+        match query_builder.and_then(StoreOperationDispatch::dispatch) {
+            Ok(None) => Ok(vec![ChannelCommandResponse::Ok]),
+            Ok(Some(reason)) => Err(reject_reasons
+                .iter()
+                .find(|reject_reason| **reject_reason == reason)
+                .map(|reject_reason| ChannelCommandError::PolicyReject(reject_reason))
+                .unwrap_or(ChannelCommandError::InternalError)),
+            Err(_) => Err(ChannelCommandError::QueryError),
+        }
     }
 
     pub fn commit_result_operation(query_builder: QueryBuilderResult) -> ChannelResult {
@@ -316,33 +695,97 @@ impl ChannelCommandBase {
             })
     }
 
-    pub fn commit_pending_operation(
+    pub fn ensure_pending_item(collection: &str, bucket: &str) -> Result<(), ChannelCommandError> {
+        // Notice: pending operations get built and executed once already acknowledged, thus \
+        //   their collection and bucket get validated beforehand, as to reject an invalid \
+        //   operation right away rather than after its pending acknowledgement.
+        StoreItemBuilder::from_depth_2(collection, bucket)
+            .map(|_| ())
+            .or(Err(ChannelCommandError::QueryError))
+    }
+
+    pub fn commit_pending_operation<F>(
         query_type: &'static str,
         query_id: &str,
-        query_builder: QueryBuilderResult,
-    ) -> ChannelResult {
-        // Idea: this could be made asynchronous in the future, if there are some latency issues \
-        //   on large Sonic deployments. The idea would be to have a number of worker threads for \
-        //   the whole running daemon, and channel threads dispatching work to those threads. This \
-        //   way Sonic can be up-scaled to N CPUs instead of 1 CPU per channel connection. Now on, \
-        //   the only way to scale Sonic executors to multiple CPUs is opening multiple parallel \
-        //   Sonic Channel connections and dispatching work evenly to each connection. It does not \
-        //   prevent scaling Sonic vertically, but could be made simpler for the Sonic Channel \
-        //   consumer via a worker thread pool.
+        query_mux: Option<&str>,
+        query_executor: F,
+    ) -> ChannelResult
+    where
+        F: FnOnce() -> ChannelPendingResult + Send + 'static,
+    {
+        // Tag query identifier with multiplexing identifier? (if any; this lets the Sonic \
+        //   Channel consumer route the response to the correct in-flight request)
+        let query_tag = if let Some(query_mux) = query_mux {
+            format!("{} MUX({})", query_id, query_mux)
+        } else {
+            query_id.to_string()
+        };
+
+        // Execute on the executor pool? (only if the connection has an event writer, which is \
+        //   then used to send the event once executed; otherwise execute inline)
+        if let Some(event_writer) = ChannelMessage::event_writer() {
+            let (job_writer, job_tag) = (event_writer.clone(), query_tag.clone());
+
+            let job: TaskerExecutorJob = Box::new(move || {
+                // Notice: the query error is sent after the pending acknowledgement there, as \
+                //   the query gets executed once already acknowledged (the query itself gets \
+                //   validated beforehand, thus this only happens upon store failures). A \
+                //   panicking query is caught here and answered with a query error as well, \
+                //   otherwise the client would wait forever on its pending acknowledgement.
+                let responses: Vec<ChannelCommandResponseArgs> =
+                    match panic::catch_unwind(AssertUnwindSafe(query_executor)) {
+                        Ok(Ok((results, extra_responses))) => Self::make_event_responses(
+                            query_type,
+                            job_tag,
+                            results,
+                            extra_responses,
+                        ),
+                        Ok(Err(_)) => {
+                            vec![ChannelCommandResponse::Err(ChannelCommandError::QueryError)]
+                        }
+                        Err(_) => {
+                            error!("pending operation panicked: {}", job_tag);
 
-        query_builder
-            .and_then(StoreOperationDispatch::dispatch)
-            .map(|results| {
-                vec![
-                    ChannelCommandResponse::Pending(query_id.to_string()),
-                    ChannelCommandResponse::Event(
+                            vec![ChannelCommandResponse::Err(ChannelCommandError::QueryError)]
+                        }
+                    }
+                    .iter()
+                    .map(|response| response.to_args())
+                    .collect();
+
+                if job_writer.send(responses).is_err() {
+                    info!("could not send pending operation event, as connection is gone");
+                }
+            });
+
+            // Important: send the pending acknowledgement before the job is submitted, as to \
+            //   ensure its event gets written after the pending acknowledgement.
+            event_writer
+                .send(vec![ChannelCommandResponse::Pending(query_tag).to_args()])
+                .or(Err(ChannelCommandError::InternalError))?;
+
+            if let Err(job) = TaskerExecutorPool::submit(job) {
+                // Executor pool unavailable, execute inline
+                job();
+            }
+
+            Ok(vec![ChannelCommandResponse::Void])
+        } else {
+            query_executor()
+                .map(|(results, extra_responses)| {
+                    let mut responses = vec![ChannelCommandResponse::Pending(query_tag.to_owned())];
+
+                    responses.extend(Self::make_event_responses(
                         query_type,
-                        query_id.to_string(),
-                        results.unwrap_or_default(),
-                    ),
-                ]
-            })
-            .or(Err(ChannelCommandError::QueryError))
+                        query_tag,
+                        results,
+                        extra_responses,
+                    ));
+
+                    responses
+                })
+                .or(Err(ChannelCommandError::QueryError))
+        }
     }
 
     pub fn generate_event_id() -> String {
@@ -352,6 +795,37 @@ impl ChannelCommandBase {
             .map(|value| value as char)
             .collect()
     }
+
+    fn make_event_responses(
+        query_type: &'static str,
+        query_tag: String,
+        results: Option<String>,
+        mut extra_responses: Vec<ChannelCommandResponse>,
+    ) -> Vec<ChannelCommandResponse> {
+        let mut responses = vec![ChannelCommandResponse::Event(
+            query_type,
+            query_tag,
+            results.unwrap_or_default(),
+        )];
+
+        responses.append(&mut extra_responses);
+
+        responses
+    }
+
+    fn checksum_mismatch(text: &str, checksum: Option<QueryPushChecksum>) -> bool {
+        // Notice: the checksum is verified against the text as received, before it gets lexed, \
+        //   so that nothing gets written if the text got altered.
+        if let Some(checksum) = checksum {
+            if Sha256::digest(text.as_bytes())[..] != checksum[..] {
+                info!("text does not match checksum: {:x?}", checksum);
+
+                return true;
+            }
+        }
+
+        false
+    }
 }
 
 impl ChannelCommandSearch {
@@ -359,7 +833,7 @@ impl ChannelCommandSearch {
         match (
             parts.next(),
             parts.next(),
-            ChannelCommandBase::parse_text_parts(&mut parts),
+            ChannelCommandBase::parse_text_parts(&mut parts)?,
         ) {
             (Some(collection), Some(bucket), Some(text)) => {
                 // Generate command identifier
@@ -370,9 +844,34 @@ impl ChannelCommandSearch {
                     event_id, collection, bucket
                 );
 
+                // Extract phrases, whose words must directly follow each other in results
+                let (text, query_phrases) = Self::parse_phrases(text);
+
+                // Extract terms to unite results with (ie. terms preceded by an OR operator), \
+                //   and terms to exclude results with (ie. terms prefixed by a NOT operator)
+                let (text, query_or_terms, query_not_terms) = Self::parse_operators(text);
+
                 // Define query parameters
-                let (mut query_limit, mut query_offset, mut query_lang) =
-                    (APP_CONF.channel.search.query_limit_default, 0, None);
+                let (mut query_limit, mut query_offset, mut query_lang, mut query_stopwords) = (
+                    APP_CONF.channel.search.query_limit_default,
+                    None,
+                    None,
+                    QueryGenericStopwords::Enabled,
+                );
+                let (mut query_mux, mut query_boosts, mut query_within_distance) =
+                    (None, Vec::new(), None);
+                let mut query_term_weights = Vec::new();
+                let (mut query_buckets, mut query_bucket_weights) = (Vec::new(), Vec::new());
+                let mut query_spellcheck = APP_CONF.channel.search.auto_spellcheck;
+                let (mut query_penalty_empty, mut query_penalty) = (false, None);
+                let (mut query_abstract, mut query_lang_detect) = (None, true);
+                let (mut query_filter_tags, mut query_must_not) = (Vec::new(), query_not_terms);
+                let (mut query_personalize, mut query_recent, mut query_decay) = (None, None, None);
+                let (mut query_segment, mut query_shard, mut query_debug) = (None, None, false);
+                let (mut query_boost_recency, mut query_span, mut query_random) = (None, None, None);
+                let (mut query_highlight_style, mut query_sort, mut query_timeout) =
+                    (None, QuerySearchSortBy::default(), None);
+                let (mut query_cursor, mut query_highlight) = (None, false);
 
                 // Parse meta parts (meta comes after text; extract meta parts second)
                 let mut last_meta_err = None;
@@ -380,20 +879,113 @@ impl ChannelCommandSearch {
                 while let Some(meta_result) = ChannelCommandBase::parse_next_meta_parts(&mut parts)
                 {
                     match Self::handle_query_meta(meta_result) {
-                        Ok((Some(query_limit_parsed), None, None)) => {
+                        Ok(QueryMetaData::Limit(query_limit_parsed)) => {
                             query_limit = query_limit_parsed
                         }
-                        Ok((None, Some(query_offset_parsed), None)) => {
-                            query_offset = query_offset_parsed
+                        Ok(QueryMetaData::Offset(query_offset_parsed)) => {
+                            query_offset = Some(query_offset_parsed)
                         }
-                        Ok((None, None, Some(query_lang_parsed))) => {
+                        Ok(QueryMetaData::Lang(query_lang_parsed)) => {
                             query_lang = Some(query_lang_parsed)
                         }
+                        Ok(QueryMetaData::Stopwords(query_stopwords_parsed)) => {
+                            query_stopwords = query_stopwords_parsed
+                        }
+                        Ok(QueryMetaData::Mux(query_mux_parsed)) => {
+                            query_mux = Some(query_mux_parsed)
+                        }
+                        Ok(QueryMetaData::Boost(query_boost_parsed)) => {
+                            query_boosts.push(query_boost_parsed)
+                        }
+                        Ok(QueryMetaData::TermWeights(query_term_weights_parsed)) => {
+                            query_term_weights = query_term_weights_parsed
+                        }
+                        Ok(QueryMetaData::WithinDistance(query_within_distance_parsed)) => {
+                            query_within_distance = Some(query_within_distance_parsed)
+                        }
+                        Ok(QueryMetaData::Spellcheck(query_spellcheck_parsed)) => {
+                            query_spellcheck = query_spellcheck_parsed
+                        }
+                        Ok(QueryMetaData::PenaltyEmpty(query_penalty_empty_parsed)) => {
+                            query_penalty_empty = query_penalty_empty_parsed
+                        }
+                        Ok(QueryMetaData::Penalty(query_penalty_parsed)) => {
+                            query_penalty = Some(query_penalty_parsed)
+                        }
+                        Ok(QueryMetaData::Abstract(query_abstract_parsed)) => {
+                            query_abstract = Some(query_abstract_parsed)
+                        }
+                        Ok(QueryMetaData::LangDetect(query_lang_detect_parsed)) => {
+                            query_lang_detect = query_lang_detect_parsed
+                        }
+                        Ok(QueryMetaData::FilterTag(query_filter_tag_parsed)) => {
+                            query_filter_tags.push(query_filter_tag_parsed)
+                        }
+                        Ok(QueryMetaData::MustNot(query_must_not_parsed)) => {
+                            query_must_not.push(query_must_not_parsed)
+                        }
+                        Ok(QueryMetaData::Personalize(query_personalize_parsed)) => {
+                            query_personalize = Some(query_personalize_parsed)
+                        }
+                        Ok(QueryMetaData::Recent(query_recent_parsed)) => {
+                            query_recent = Some(query_recent_parsed)
+                        }
+                        Ok(QueryMetaData::Decay(query_decay_parsed)) => {
+                            query_decay = Some(query_decay_parsed)
+                        }
+                        Ok(QueryMetaData::BoostRecency(query_boost_recency_parsed)) => {
+                            query_boost_recency = Some(query_boost_recency_parsed)
+                        }
+                        Ok(QueryMetaData::Segment(query_segment_parsed)) => {
+                            query_segment = Some(query_segment_parsed)
+                        }
+                        Ok(QueryMetaData::Shard(query_shard_parsed)) => {
+                            query_shard = Some(query_shard_parsed)
+                        }
+                        Ok(QueryMetaData::Span(query_span_parsed)) => {
+                            query_span = Some(query_span_parsed)
+                        }
+                        Ok(QueryMetaData::Random(query_random_parsed)) => {
+                            query_random = Some(query_random_parsed)
+                        }
+                        Ok(QueryMetaData::MultiBucket(query_buckets_parsed)) => {
+                            query_buckets = query_buckets_parsed
+                        }
+                        Ok(QueryMetaData::BucketWeights(query_bucket_weights_parsed)) => {
+                            query_bucket_weights = query_bucket_weights_parsed
+                        }
+                        Ok(QueryMetaData::Debug(query_debug_parsed)) => {
+                            query_debug = query_debug_parsed
+                        }
+                        Ok(QueryMetaData::HighlightStyle(query_highlight_style_parsed)) => {
+                            query_highlight_style = Some(query_highlight_style_parsed)
+                        }
+                        Ok(QueryMetaData::Highlight(query_highlight_parsed)) => {
+                            query_highlight = query_highlight_parsed
+                        }
+                        Ok(QueryMetaData::Sort(query_sort_parsed)) => query_sort = query_sort_parsed,
+                        Ok(QueryMetaData::Timeout(query_timeout_parsed)) => {
+                            query_timeout = Some(query_timeout_parsed)
+                        }
+                        Ok(QueryMetaData::Cursor(query_cursor_parsed)) => {
+                            query_cursor = Some(query_cursor_parsed)
+                        }
                         Err(parse_err) => last_meta_err = Some(parse_err),
-                        _ => {}
                     }
                 }
 
+                // Disabling stopwords makes the lexer normalize only, which is what the 'none' \
+                //   locale does (any provided locale is therefore ignored)
+                if query_stopwords == QueryGenericStopwords::Disabled {
+                    query_lang = Some(QueryGenericLang::Disabled);
+                }
+
+                // Disabling language detection also makes the lexer normalize only, unless a \
+                //   locale is provided (in which case there is no language to be detected)
+                if !query_lang_detect && query_lang.is_none() {
+                    query_lang = Some(QueryGenericLang::Disabled);
+                }
+
                 if let Some(err) = last_meta_err {
                     Err(err)
                 } else if query_limit < 1
@@ -402,31 +994,147 @@ impl ChannelCommandSearch {
                     Err(ChannelCommandError::PolicyReject(
                         "LIMIT out of minimum/maximum bounds",
                     ))
+                } else if query_abstract.is_some() && !APP_CONF.store.kv.store_original_text {
+                    Err(ChannelCommandError::PolicyReject(
+                        "ABSTRACT requires original text to be stored",
+                    ))
+                } else if query_highlight_style.is_some() && query_abstract.is_none() {
+                    Err(ChannelCommandError::PolicyReject(
+                        "HIGHLIGHT_STYLE requires ABSTRACT to be set",
+                    ))
+                } else if query_highlight && !APP_CONF.store.kv.store_original_text {
+                    Err(ChannelCommandError::PolicyReject(
+                        "HIGHLIGHT requires original text to be stored",
+                    ))
+                } else if query_highlight && query_abstract.is_some() {
+                    Err(ChannelCommandError::PolicyReject(
+                        "HIGHLIGHT cannot be combined with ABSTRACT",
+                    ))
+                } else if !query_buckets.is_empty()
+                    && (query_debug || query_shard.is_some() || query_cursor.is_some())
+                {
+                    Err(ChannelCommandError::PolicyReject(
+                        "MULTIBUCKET cannot be combined with DEBUG, SHARD or CURSOR",
+                    ))
+                } else if query_offset.is_some() && query_cursor.is_some() {
+                    Err(ChannelCommandError::PolicyReject(
+                        "cannot mix OFFSET and CURSOR",
+                    ))
+                } else if !query_bucket_weights.is_empty() && query_buckets.is_empty() {
+                    Err(ChannelCommandError::PolicyReject(
+                        "BUCKET_WEIGHT requires MULTIBUCKET to be set",
+                    ))
+                } else if query_span.is_some() && !APP_CONF.store.kv.store_term_positions {
+                    Err(ChannelCommandError::PolicyReject(
+                        "SPAN requires term positions to be stored",
+                    ))
+                } else if !query_phrases.is_empty() && !APP_CONF.store.kv.store_term_positions {
+                    Err(ChannelCommandError::PolicyReject(
+                        "phrases require term positions to be stored",
+                    ))
                 } else {
                     debug!(
-                        "will search for #{} with text: {}, limit: {}, offset: {}, locale: <{:?}>",
-                        event_id, text, query_limit, query_offset, query_lang
+                        "will search for #{} with text: {}, limit: {}, offset: {}, locale: <{:?}>, \
+                            boosts: {:?}",
+                        event_id,
+                        text,
+                        query_limit,
+                        query_offset.unwrap_or(0),
+                        query_lang,
+                        query_boosts
                     );
 
+                    let query_options = QuerySearchOptions {
+                        lang: query_lang,
+                        boosts: query_boosts,
+                        term_weights: query_term_weights,
+                        within_distance: query_within_distance,
+                        penalty_empty: if query_penalty_empty {
+                            Some(query_penalty.unwrap_or(QUERY_PENALTY_DEFAULT))
+                        } else {
+                            None
+                        },
+                        abstract_chars: query_abstract,
+                        filter_tags: query_filter_tags,
+                        must_not: query_must_not,
+                        personalize: query_personalize,
+                        recent: query_recent,
+                        decay: query_decay,
+                        boost_recency: query_boost_recency,
+                        segment: query_segment,
+                        shard: query_shard,
+                        span: query_span,
+                        random: query_random,
+                        buckets: query_buckets,
+                        bucket_weights: query_bucket_weights,
+                        debug: query_debug,
+                        highlight_style: query_highlight_style,
+                        highlight: query_highlight,
+                        sort: query_sort,
+                        timeout_ms: query_timeout,
+                        cursor: query_cursor,
+                        or_terms: query_or_terms,
+                        phrases: query_phrases,
+                    };
+
+                    ChannelCommandBase::ensure_pending_item(collection, bucket)?;
+
+                    let (query_id, collection, bucket) =
+                        (event_id.clone(), collection.to_owned(), bucket.to_owned());
+
                     // Commit 'search' query
                     ChannelCommandBase::commit_pending_operation(
                         "QUERY",
                         &event_id,
-                        QueryBuilder::search(
-                            &event_id,
-                            collection,
-                            bucket,
-                            &text,
-                            query_limit,
-                            query_offset,
-                            query_lang,
-                        ),
+                        query_mux.as_deref(),
+                        move || {
+                            let results = QueryBuilder::search(
+                                &query_id,
+                                &collection,
+                                &bucket,
+                                &text,
+                                query_limit,
+                                query_offset.unwrap_or(0),
+                                query_options,
+                            )
+                            .and_then(StoreOperationDispatch::dispatch)?;
+
+                            let mut extra_responses = Vec::new();
+
+                            // Suggest a spelling correction? (only if search did not yield any \
+                            //   result)
+                            if query_spellcheck && matches!(results.as_deref(), None | Some("")) {
+                                if let Ok(Some(corrected)) =
+                                    QueryBuilder::spellcheck(&query_id, &collection, &bucket, &text)
+                                        .and_then(StoreOperationDispatch::dispatch)
+                                {
+                                    debug!("got spellcheck for #{}: {}", query_id, corrected);
+
+                                    extra_responses.push(ChannelCommandResponse::Result(format!(
+                                        "corrected(\"{}\")",
+                                        corrected
+                                    )));
+                                }
+                            }
+
+                            Ok((results, extra_responses))
+                        },
                     )
                 }
             }
             _ => Err(ChannelCommandError::InvalidFormat(
                 "QUERY <collection> <bucket> \"<terms>\" [LIMIT(<count>)]? [OFFSET(<count>)]? \
-                 [LANG(<locale>)]?",
+                 [LANG(<locale>)]? [STOPWORDS(<stopwords>)]? [MUX(<mux>)]? \
+                 [STOPBOOST(<word>,<factor>)]? [WEIGHTS(<term>:<weight>,..)]? \
+                 [WITHIN_DISTANCE(<latitude>,<longitude>,<distance>)]? \
+                 [SPELLCHECK(<spellcheck>)]? [PENALTY_EMPTY(<penalty_empty>)]? [PENALTY(<factor>)]? \
+                 [ABSTRACT(<count>)]? [LANG_DETECT(<lang_detect>)]? [FILTER_TAG(<tag>)]? \
+                 [MUST_NOT(\"<terms>\")]? [PERSONALIZE(<user>)]? [RECENT(<secs>)]? \
+                 [DECAY(<halflife>)]? [BOOST_RECENCY(<halflife>)]? [SEGMENT(<segment>)]? \
+                 [SHARD(<shard_id>,<total_shards>)]? [SPAN(<count>)]? [RANDOM(<seed>)]? [DEBUG(<debug>)]? \
+                 [HIGHLIGHT_STYLE(<style>)]? [MULTIBUCKET(<bucket>,..)]? \
+                 [BUCKET_WEIGHT(<bucket>:<factor>,..)]? [SORT(<sort>)]? [TIMEOUT(<ms>)]? \
+                 [CURSOR(<token>)]?",
             )),
         }
     }
@@ -435,7 +1143,7 @@ impl ChannelCommandSearch {
         match (
             parts.next(),
             parts.next(),
-            ChannelCommandBase::parse_text_parts(&mut parts),
+            ChannelCommandBase::parse_text_parts(&mut parts)?,
         ) {
             (Some(collection), Some(bucket), Some(text)) => {
                 // Generate command identifier
@@ -447,7 +1155,19 @@ impl ChannelCommandSearch {
                 );
 
                 // Define suggest parameters
-                let mut suggest_limit = APP_CONF.channel.search.suggest_limit_default;
+                let (
+                    mut suggest_limit,
+                    mut suggest_mux,
+                    mut suggest_context,
+                    mut suggest_expand,
+                    mut suggest_min_freq,
+                ) = (
+                    APP_CONF.channel.search.suggest_limit_default,
+                    None,
+                    Vec::new(),
+                    false,
+                    None,
+                );
 
                 // Parse meta parts (meta comes after text; extract meta parts second)
                 let mut last_meta_err = None;
@@ -455,9 +1175,22 @@ impl ChannelCommandSearch {
                 while let Some(meta_result) = ChannelCommandBase::parse_next_meta_parts(&mut parts)
                 {
                     match Self::handle_suggest_meta(meta_result) {
-                        Ok(Some(suggest_limit_parsed)) => suggest_limit = suggest_limit_parsed,
+                        Ok(SuggestMetaData::Limit(suggest_limit_parsed)) => {
+                            suggest_limit = suggest_limit_parsed
+                        }
+                        Ok(SuggestMetaData::Mux(suggest_mux_parsed)) => {
+                            suggest_mux = Some(suggest_mux_parsed)
+                        }
+                        Ok(SuggestMetaData::Context(suggest_context_parsed)) => {
+                            suggest_context = suggest_context_parsed
+                        }
+                        Ok(SuggestMetaData::Expand(suggest_expand_parsed)) => {
+                            suggest_expand = suggest_expand_parsed
+                        }
+                        Ok(SuggestMetaData::MinFreq(suggest_min_freq_parsed)) => {
+                            suggest_min_freq = Some(suggest_min_freq_parsed)
+                        }
                         Err(parse_err) => last_meta_err = Some(parse_err),
-                        _ => {}
                     }
                 }
 
@@ -471,20 +1204,50 @@ impl ChannelCommandSearch {
                     ))
                 } else {
                     debug!(
-                        "will suggest for #{} with text: {}, limit: {}",
-                        event_id, text, suggest_limit
+                        "will suggest for #{} with text: {}, limit: {}, context: {:?}, expand: {}, \
+                            min_freq: {:?}",
+                        event_id,
+                        text,
+                        suggest_limit,
+                        suggest_context,
+                        suggest_expand,
+                        suggest_min_freq
                     );
 
+                    let suggest_options = QuerySuggestOptions {
+                        context: suggest_context,
+                        expand: suggest_expand,
+                        min_freq: suggest_min_freq,
+                    };
+
+                    ChannelCommandBase::ensure_pending_item(collection, bucket)?;
+
+                    let (query_id, collection, bucket) =
+                        (event_id.clone(), collection.to_owned(), bucket.to_owned());
+
                     // Commit 'suggest' query
                     ChannelCommandBase::commit_pending_operation(
                         "SUGGEST",
                         &event_id,
-                        QueryBuilder::suggest(&event_id, collection, bucket, &text, suggest_limit),
+                        suggest_mux.as_deref(),
+                        move || {
+                            QueryBuilder::suggest(
+                                &query_id,
+                                &collection,
+                                &bucket,
+                                &text,
+                                suggest_limit,
+                                suggest_options,
+                            )
+                            .and_then(StoreOperationDispatch::dispatch)
+                            .map(|results| (results, Vec::new()))
+                        },
                     )
                 }
             }
             _ => Err(ChannelCommandError::InvalidFormat(
-                "SUGGEST <collection> <bucket> \"<word>\" [LIMIT(<count>)]?",
+                "SUGGEST <collection> <bucket> \"<word>\" [LIMIT(<count>)]? [MUX(<mux>)]? \
+                 [CONTEXT(<context>)]? [EXPAND(<expand>)]? [MIN_FREQ(<count>)]?",
             )),
         }
     }
@@ -527,11 +1290,27 @@ impl ChannelCommandSearch {
                         "LIMIT out of minimum/maximum bounds",
                     ))
                 } else {
+                    ChannelCommandBase::ensure_pending_item(collection, bucket)?;
+
+                    let (query_id, collection, bucket) =
+                        (event_id.clone(), collection.to_owned(), bucket.to_owned());
+
                     // Commit 'list' query
                     ChannelCommandBase::commit_pending_operation(
                         "LIST",
                         &event_id,
-                        QueryBuilder::list(&event_id, collection, bucket, list_limit, list_offset),
+                        None,
+                        move || {
+                            QueryBuilder::list(
+                                &query_id,
+                                &collection,
+                                &bucket,
+                                list_limit,
+                                list_offset,
+                            )
+                            .and_then(StoreOperationDispatch::dispatch)
+                            .map(|results| (results, Vec::new()))
+                        },
                     )
                 }
             }
@@ -541,22 +1320,231 @@ impl ChannelCommandSearch {
         }
     }
 
-    pub fn dispatch_help(parts: SplitWhitespace) -> ChannelResult {
-        ChannelCommandBase::generic_dispatch_help(parts, &*MANUAL_MODE_SEARCH)
+    pub fn dispatch_get(mut parts: SplitWhitespace) -> ChannelResult {
+        match (parts.next(), parts.next(), parts.next(), parts.next()) {
+            (Some(collection), Some(bucket), Some(object), None) => {
+                debug!(
+                    "dispatching search get on collection: {} and bucket: {} for object: {}",
+                    collection, bucket, object
+                );
+
+                if !APP_CONF.store.kv.store_original_text {
+                    Err(ChannelCommandError::PolicyReject(
+                        "GET requires original text to be stored",
+                    ))
+                } else {
+                    // Make 'get' query (objects with no stored text are not found)
+                    match QueryBuilder::get(collection, bucket, object)
+                        .and_then(StoreOperationDispatch::dispatch)
+                    {
+                        Ok(Some(text)) => Ok(vec![ChannelCommandResponse::Result(format!(
+                            "\"{}\"",
+                            escape(&text)
+                        ))]),
+                        Ok(None) => Err(ChannelCommandError::NotFound),
+                        Err(_) => Err(ChannelCommandError::QueryError),
+                    }
+                }
+            }
+            _ => Err(ChannelCommandError::InvalidFormat(
+                "GET <collection> <bucket> <object>",
+            )),
+        }
     }
 
-    fn handle_query_meta(
-        meta_result: MetaPartsResult,
-    ) -> Result<QueryMetaData, ChannelCommandError> {
-        match meta_result {
-            Ok((meta_key, meta_value)) => {
-                debug!("handle query meta: {} = {}", meta_key, meta_value);
+    pub fn dispatch_vsearch(mut parts: SplitWhitespace) -> ChannelResult {
+        match (parts.next(), parts.next()) {
+            (Some(collection), Some(bucket)) => {
+                // Generate command identifier
+                let event_id = ChannelCommandBase::generate_event_id();
 
-                match meta_key {
-                    "LIMIT" => {
-                        // 'LIMIT(<count>)' where 0 <= <count> < 2^16
+                debug!(
+                    "dispatching search vsearch #{} on collection: {} and bucket: {}",
+                    event_id, collection, bucket
+                );
+
+                // Define vsearch parameters
+                let (mut vsearch_vector, mut vsearch_limit, mut vsearch_mux) =
+                    (None, APP_CONF.channel.search.query_limit_default, None);
+
+                // Parse meta parts (meta comes last; extract meta parts second)
+                let mut last_meta_err = None;
+
+                while let Some(meta_result) = ChannelCommandBase::parse_next_meta_parts(&mut parts)
+                {
+                    match Self::handle_vsearch_meta(meta_result) {
+                        Ok(VsearchMetaData::Vector(vsearch_vector_parsed)) => {
+                            vsearch_vector = Some(vsearch_vector_parsed)
+                        }
+                        Ok(VsearchMetaData::Limit(vsearch_limit_parsed)) => {
+                            vsearch_limit = vsearch_limit_parsed
+                        }
+                        Ok(VsearchMetaData::Mux(vsearch_mux_parsed)) => {
+                            vsearch_mux = Some(vsearch_mux_parsed)
+                        }
+                        Err(parse_err) => last_meta_err = Some(parse_err),
+                    }
+                }
+
+                if let Some(err) = last_meta_err {
+                    Err(err)
+                } else if let Some(vsearch_vector) = vsearch_vector {
+                    if vsearch_limit < 1
+                        || vsearch_limit > APP_CONF.channel.search.query_limit_maximum
+                    {
+                        Err(ChannelCommandError::PolicyReject(
+                            "LIMIT out of minimum/maximum bounds",
+                        ))
+                    } else {
+                        debug!(
+                            "will vsearch for #{} with vector dimension: {}, limit: {}",
+                            event_id,
+                            vsearch_vector.len(),
+                            vsearch_limit
+                        );
+
+                        ChannelCommandBase::ensure_pending_item(collection, bucket)?;
+
+                        let (query_id, collection, bucket) =
+                            (event_id.clone(), collection.to_owned(), bucket.to_owned());
+
+                        // Commit 'vsearch' query
+                        ChannelCommandBase::commit_pending_operation(
+                            "VSEARCH",
+                            &event_id,
+                            vsearch_mux.as_deref(),
+                            move || {
+                                QueryBuilder::vsearch(
+                                    &query_id,
+                                    &collection,
+                                    &bucket,
+                                    vsearch_vector,
+                                    vsearch_limit,
+                                )
+                                .and_then(StoreOperationDispatch::dispatch)
+                                .map(|results| (results, Vec::new()))
+                            },
+                        )
+                    }
+                } else {
+                    Err(ChannelCommandError::InvalidFormat(
+                        "VSEARCH <collection> <bucket> VECTOR(<vector>) [LIMIT(<count>)]? \
+                         [MUX(<mux>)]?",
+                    ))
+                }
+            }
+            _ => Err(ChannelCommandError::InvalidFormat(
+                "VSEARCH <collection> <bucket> VECTOR(<vector>) [LIMIT(<count>)]? [MUX(<mux>)]?",
+            )),
+        }
+    }
+
+    pub fn dispatch_help(parts: SplitWhitespace) -> ChannelResult {
+        ChannelCommandBase::generic_dispatch_help(parts, &*MANUAL_MODE_SEARCH)
+    }
+
+    fn parse_phrases(text: String) -> (String, Vec<String>) {
+        // Notice: a phrase opens with a part starting with a '[' or a quote delimiter, and closes \
+        //   with the next part ending with the matching delimiter. Phrase words are still kept \
+        //   as search terms, and unclosed phrases are searched for as plain words.
+        if !text.split_whitespace().any(|part| {
+            QUERY_PHRASE_DELIMITERS
+                .iter()
+                .any(|(opening, _)| part.starts_with(*opening))
+        }) {
+            return (text, Vec::new());
+        }
+
+        let (mut text_parts, mut phrases) = (Vec::new(), Vec::new());
+        let mut phrase: Option<(char, Vec<&str>)> = None;
+
+        for part in text.split_whitespace() {
+            let mut word = part;
+
+            if phrase.is_none() {
+                if let Some((opening, closing)) = QUERY_PHRASE_DELIMITERS
+                    .iter()
+                    .find(|(opening, _)| word.starts_with(*opening))
+                {
+                    word = &word[opening.len_utf8()..];
+                    phrase = Some((*closing, Vec::new()));
+                }
+            }
+
+            if let Some((closing, mut phrase_words)) = phrase.take() {
+                let is_closed = word.ends_with(closing);
+
+                if is_closed {
+                    word = &word[..(word.len() - closing.len_utf8())];
+                }
+                if !word.is_empty() {
+                    phrase_words.push(word);
+                }
+
+                if !is_closed {
+                    phrase = Some((closing, phrase_words));
+                } else if !phrase_words.is_empty() {
+                    phrases.push(phrase_words.join(" "));
+                }
+            }
+
+            if !word.is_empty() {
+                text_parts.push(word);
+            }
+        }
+
+        (text_parts.join(" "), phrases)
+    }
+
+    fn parse_operators(text: String) -> (String, Vec<String>, Vec<String>) {
+        // Notice: the OR operator is a standalone '|' part, that applies to the first word of the \
+        //   part that follows it (words get lexed along with search terms afterwards, eg. they \
+        //   get stemmed as well), while the NOT operator is a '!' prefix, which removes the \
+        //   negated part from search terms.
+        if !text.split_whitespace().any(|part| {
+            part == QUERY_OR_OPERATOR
+                || (part.len() > QUERY_NOT_OPERATOR.len() && part.starts_with(QUERY_NOT_OPERATOR))
+        }) {
+            return (text, Vec::new(), Vec::new());
+        }
+
+        let (mut text_parts, mut or_terms, mut not_terms) = (Vec::new(), Vec::new(), Vec::new());
+        let mut is_or = false;
+
+        for part in text.split_whitespace() {
+            if part == QUERY_OR_OPERATOR {
+                is_or = true;
+            } else if part.len() > QUERY_NOT_OPERATOR.len() && part.starts_with(QUERY_NOT_OPERATOR)
+            {
+                not_terms.push(part[QUERY_NOT_OPERATOR.len()..].to_string());
+            } else {
+                if is_or {
+                    if let Some(or_term) = part.to_lowercase().unicode_words().next() {
+                        or_terms.push(or_term.to_string());
+                    }
+
+                    is_or = false;
+                }
+
+                text_parts.push(part);
+            }
+        }
+
+        (text_parts.join(" "), or_terms, not_terms)
+    }
+
+    fn handle_query_meta(
+        meta_result: MetaPartsResult,
+    ) -> Result<QueryMetaData, ChannelCommandError> {
+        match meta_result {
+            Ok((meta_key, meta_value)) => {
+                debug!("handle query meta: {} = {}", meta_key, meta_value);
+
+                match meta_key {
+                    "LIMIT" => {
+                        // 'LIMIT(<count>)' where 0 <= <count> < 2^16
                         if let Ok(query_limit_parsed) = meta_value.parse::<QuerySearchLimit>() {
-                            Ok((Some(query_limit_parsed), None, None))
+                            Ok(QueryMetaData::Limit(query_limit_parsed))
                         } else {
                             Err(ChannelCommandBase::make_error_invalid_meta_value(
                                 meta_key, meta_value,
@@ -566,7 +1554,7 @@ impl ChannelCommandSearch {
                     "OFFSET" => {
                         // 'OFFSET(<count>)' where 0 <= <count> < 2^32
                         if let Ok(query_offset_parsed) = meta_value.parse::<QuerySearchOffset>() {
-                            Ok((None, Some(query_offset_parsed), None))
+                            Ok(QueryMetaData::Offset(query_offset_parsed))
                         } else {
                             Err(ChannelCommandBase::make_error_invalid_meta_value(
                                 meta_key, meta_value,
@@ -576,7 +1564,322 @@ impl ChannelCommandSearch {
                     "LANG" => {
                         // 'LANG(<locale>)' where <locale> ∈ ISO 639-3
                         if let Some(query_lang_parsed) = QueryGenericLang::from_value(meta_value) {
-                            Ok((None, None, Some(query_lang_parsed)))
+                            Ok(QueryMetaData::Lang(query_lang_parsed))
+                        } else {
+                            Err(ChannelCommandBase::make_error_invalid_meta_value(
+                                meta_key, meta_value,
+                            ))
+                        }
+                    }
+                    "STOPWORDS" => {
+                        // 'STOPWORDS(<stopwords>)' where <stopwords> ∈ {auto, none}
+                        if let Some(query_stopwords_parsed) =
+                            QueryGenericStopwords::from_value(meta_value)
+                        {
+                            Ok(QueryMetaData::Stopwords(query_stopwords_parsed))
+                        } else {
+                            Err(ChannelCommandBase::make_error_invalid_meta_value(
+                                meta_key, meta_value,
+                            ))
+                        }
+                    }
+                    "MUX" => {
+                        // 'MUX(<mux>)' where <mux> is a non-empty token
+                        if let Some(query_mux_parsed) = ChannelCommandBase::parse_mux(meta_value) {
+                            Ok(QueryMetaData::Mux(query_mux_parsed))
+                        } else {
+                            Err(ChannelCommandBase::make_error_invalid_meta_value(
+                                meta_key, meta_value,
+                            ))
+                        }
+                    }
+                    "WITHIN_DISTANCE" => {
+                        // 'WITHIN_DISTANCE(<latitude>,<longitude>,<distance>)' where <distance> \
+                        //   is a positive number of kilometers
+                        if let Some(query_within_distance_parsed) =
+                            ChannelCommandBase::parse_within_distance(meta_value)
+                        {
+                            Ok(QueryMetaData::WithinDistance(query_within_distance_parsed))
+                        } else {
+                            Err(ChannelCommandBase::make_error_invalid_meta_value(
+                                meta_key, meta_value,
+                            ))
+                        }
+                    }
+                    "STOPBOOST" => {
+                        // 'STOPBOOST(<word>,<factor>)' where <factor> is a positive number
+                        if let Some(query_boost_parsed) =
+                            ChannelCommandBase::parse_boost(meta_value)
+                        {
+                            Ok(QueryMetaData::Boost(query_boost_parsed))
+                        } else {
+                            Err(ChannelCommandBase::make_error_invalid_meta_value(
+                                meta_key, meta_value,
+                            ))
+                        }
+                    }
+                    "WEIGHTS" => {
+                        // 'WEIGHTS(<term>:<weight>,..)' where <weight> is a positive number
+                        if let Some(query_term_weights_parsed) =
+                            ChannelCommandBase::parse_term_weights(meta_value)
+                        {
+                            Ok(QueryMetaData::TermWeights(query_term_weights_parsed))
+                        } else {
+                            Err(ChannelCommandBase::make_error_invalid_meta_value(
+                                meta_key, meta_value,
+                            ))
+                        }
+                    }
+                    "PENALTY_EMPTY" => {
+                        // 'PENALTY_EMPTY(<penalty_empty>)' where <penalty_empty> ∈ {true, false}
+                        if let Ok(query_penalty_empty_parsed) = meta_value.parse::<bool>() {
+                            Ok(QueryMetaData::PenaltyEmpty(query_penalty_empty_parsed))
+                        } else {
+                            Err(ChannelCommandBase::make_error_invalid_meta_value(
+                                meta_key, meta_value,
+                            ))
+                        }
+                    }
+                    "PENALTY" => {
+                        // 'PENALTY(<factor>)' where <factor> ∈ ]0.0, 1.0]
+                        if let Some(query_penalty_parsed) =
+                            ChannelCommandBase::parse_penalty(meta_value)
+                        {
+                            Ok(QueryMetaData::Penalty(query_penalty_parsed))
+                        } else {
+                            Err(ChannelCommandBase::make_error_invalid_meta_value(
+                                meta_key, meta_value,
+                            ))
+                        }
+                    }
+                    "ABSTRACT" => {
+                        // 'ABSTRACT(<count>)' where 0 < <count> < 2^16
+                        match meta_value.parse::<u16>() {
+                            Ok(query_abstract_parsed) if query_abstract_parsed > 0 => {
+                                Ok(QueryMetaData::Abstract(
+                                    query_abstract_parsed as QuerySearchAbstract,
+                                ))
+                            }
+                            _ => Err(ChannelCommandBase::make_error_invalid_meta_value(
+                                meta_key, meta_value,
+                            )),
+                        }
+                    }
+                    "FILTER_TAG" => {
+                        // 'FILTER_TAG(<tag>)' where <tag> is a non-empty token
+                        match ChannelCommandBase::parse_tags(meta_value) {
+                            Some(mut query_filter_tags_parsed)
+                                if query_filter_tags_parsed.len() == 1 =>
+                            {
+                                Ok(QueryMetaData::FilterTag(query_filter_tags_parsed.remove(0)))
+                            }
+                            _ => Err(ChannelCommandBase::make_error_invalid_meta_value(
+                                meta_key, meta_value,
+                            )),
+                        }
+                    }
+                    "MUST_NOT" => {
+                        // 'MUST_NOT("<terms>")' where <terms> is a non-empty token
+                        if let Some(query_must_not_parsed) =
+                            ChannelCommandBase::parse_must_not(meta_value)
+                        {
+                            Ok(QueryMetaData::MustNot(query_must_not_parsed))
+                        } else {
+                            Err(ChannelCommandBase::make_error_invalid_meta_value(
+                                meta_key, meta_value,
+                            ))
+                        }
+                    }
+                    "PERSONALIZE" => {
+                        // 'PERSONALIZE(<user>)' where <user> is a non-empty token
+                        if !meta_value.is_empty() {
+                            Ok(QueryMetaData::Personalize(meta_value.to_string()))
+                        } else {
+                            Err(ChannelCommandBase::make_error_invalid_meta_value(
+                                meta_key, meta_value,
+                            ))
+                        }
+                    }
+                    "RECENT" => {
+                        // 'RECENT(<secs>)' where 0 < <secs> < 2^64
+                        match meta_value.parse::<QuerySearchRecent>() {
+                            Ok(query_recent_parsed) if query_recent_parsed > 0 => {
+                                Ok(QueryMetaData::Recent(query_recent_parsed))
+                            }
+                            _ => Err(ChannelCommandBase::make_error_invalid_meta_value(
+                                meta_key, meta_value,
+                            )),
+                        }
+                    }
+                    "DECAY" => {
+                        // 'DECAY(<halflife>)' where 0 < <halflife> < 2^64
+                        match meta_value.parse::<QuerySearchDecay>() {
+                            Ok(query_decay_parsed) if query_decay_parsed > 0 => {
+                                Ok(QueryMetaData::Decay(query_decay_parsed))
+                            }
+                            _ => Err(ChannelCommandBase::make_error_invalid_meta_value(
+                                meta_key, meta_value,
+                            )),
+                        }
+                    }
+                    "BOOST_RECENCY" => {
+                        // 'BOOST_RECENCY(<halflife>)' where 0 < <halflife> < 2^64
+                        match meta_value.parse::<QuerySearchDecay>() {
+                            Ok(query_boost_recency_parsed) if query_boost_recency_parsed > 0 => {
+                                Ok(QueryMetaData::BoostRecency(query_boost_recency_parsed))
+                            }
+                            _ => Err(ChannelCommandBase::make_error_invalid_meta_value(
+                                meta_key, meta_value,
+                            )),
+                        }
+                    }
+                    "SEGMENT" => {
+                        // 'SEGMENT(<segment>)' where 0 <= <segment> < 2^8
+                        if let Ok(query_segment_parsed) = meta_value.parse::<QueryGenericSegment>()
+                        {
+                            Ok(QueryMetaData::Segment(query_segment_parsed))
+                        } else {
+                            Err(ChannelCommandBase::make_error_invalid_meta_value(
+                                meta_key, meta_value,
+                            ))
+                        }
+                    }
+                    "SHARD" => {
+                        // 'SHARD(<shard_id>,<total_shards>)' where 0 <= <shard_id> < <total_shards>
+                        if let Some(query_shard_parsed) =
+                            ChannelCommandBase::parse_shard(meta_value)
+                        {
+                            Ok(QueryMetaData::Shard(query_shard_parsed))
+                        } else {
+                            Err(ChannelCommandBase::make_error_invalid_meta_value(
+                                meta_key, meta_value,
+                            ))
+                        }
+                    }
+                    "SPAN" => {
+                        // 'SPAN(<count>)' where 0 < <count> < 2^32
+                        match meta_value.parse::<QuerySearchSpan>() {
+                            Ok(query_span_parsed) if query_span_parsed > 0 => {
+                                Ok(QueryMetaData::Span(query_span_parsed))
+                            }
+                            _ => Err(ChannelCommandBase::make_error_invalid_meta_value(
+                                meta_key, meta_value,
+                            )),
+                        }
+                    }
+                    "MULTIBUCKET" => {
+                        // 'MULTIBUCKET(<bucket>,..)' where <bucket> is a bucket name
+                        if let Some(query_buckets_parsed) =
+                            ChannelCommandBase::parse_buckets(meta_value)
+                        {
+                            Ok(QueryMetaData::MultiBucket(query_buckets_parsed))
+                        } else {
+                            Err(ChannelCommandBase::make_error_invalid_meta_value(
+                                meta_key, meta_value,
+                            ))
+                        }
+                    }
+                    "BUCKET_WEIGHT" => {
+                        // 'BUCKET_WEIGHT(<bucket>:<factor>,..)' where <factor> is a positive number
+                        if let Some(query_bucket_weights_parsed) =
+                            ChannelCommandBase::parse_bucket_weights(meta_value)
+                        {
+                            Ok(QueryMetaData::BucketWeights(query_bucket_weights_parsed))
+                        } else {
+                            Err(ChannelCommandBase::make_error_invalid_meta_value(
+                                meta_key, meta_value,
+                            ))
+                        }
+                    }
+                    "RANDOM" => {
+                        // 'RANDOM(<seed>)' where 0 <= <seed> < 2^64
+                        if let Ok(query_random_parsed) = meta_value.parse::<QuerySearchRandom>() {
+                            Ok(QueryMetaData::Random(query_random_parsed))
+                        } else {
+                            Err(ChannelCommandBase::make_error_invalid_meta_value(
+                                meta_key, meta_value,
+                            ))
+                        }
+                    }
+                    "TIMEOUT" => {
+                        // 'TIMEOUT(<ms>)' where 0 <= <ms> < 2^64
+                        if let Ok(query_timeout_parsed) = meta_value.parse::<QuerySearchTimeout>() {
+                            Ok(QueryMetaData::Timeout(query_timeout_parsed))
+                        } else {
+                            Err(ChannelCommandBase::make_error_invalid_meta_value(
+                                meta_key, meta_value,
+                            ))
+                        }
+                    }
+                    "CURSOR" => {
+                        // 'CURSOR(<token>)' where <token> is a cursor from a previous page (empty \
+                        //   for the first page)
+                        if meta_value.is_empty() {
+                            Ok(QueryMetaData::Cursor(None))
+                        } else if let Some(query_cursor_parsed) = decode_cursor(meta_value) {
+                            Ok(QueryMetaData::Cursor(Some(query_cursor_parsed)))
+                        } else {
+                            Err(ChannelCommandBase::make_error_invalid_meta_value(
+                                meta_key, meta_value,
+                            ))
+                        }
+                    }
+                    "SORT" => {
+                        // 'SORT(<sort>)' where <sort> ∈ {relevance, insertion}
+                        if let Some(query_sort_parsed) = QuerySearchSortBy::from_value(meta_value) {
+                            Ok(QueryMetaData::Sort(query_sort_parsed))
+                        } else {
+                            Err(ChannelCommandBase::make_error_invalid_meta_value(
+                                meta_key, meta_value,
+                            ))
+                        }
+                    }
+                    "HIGHLIGHT" => {
+                        // 'HIGHLIGHT(<highlight>)' where <highlight> ∈ {true, false}
+                        if let Ok(query_highlight_parsed) = meta_value.parse::<bool>() {
+                            Ok(QueryMetaData::Highlight(query_highlight_parsed))
+                        } else {
+                            Err(ChannelCommandBase::make_error_invalid_meta_value(
+                                meta_key, meta_value,
+                            ))
+                        }
+                    }
+                    "HIGHLIGHT_STYLE" => {
+                        // 'HIGHLIGHT_STYLE(<style>)' where <style> ∈ {html, markdown, none}
+                        if let Some(query_highlight_style_parsed) =
+                            QuerySearchHighlightStyle::from_value(meta_value)
+                        {
+                            Ok(QueryMetaData::HighlightStyle(query_highlight_style_parsed))
+                        } else {
+                            Err(ChannelCommandBase::make_error_invalid_meta_value(
+                                meta_key, meta_value,
+                            ))
+                        }
+                    }
+                    "DEBUG" => {
+                        // 'DEBUG(<debug>)' where <debug> ∈ {true, false}
+                        if let Ok(query_debug_parsed) = meta_value.parse::<bool>() {
+                            Ok(QueryMetaData::Debug(query_debug_parsed))
+                        } else {
+                            Err(ChannelCommandBase::make_error_invalid_meta_value(
+                                meta_key, meta_value,
+                            ))
+                        }
+                    }
+                    "LANG_DETECT" => {
+                        // 'LANG_DETECT(<lang_detect>)' where <lang_detect> ∈ {true, false}
+                        if let Ok(query_lang_detect_parsed) = meta_value.parse::<bool>() {
+                            Ok(QueryMetaData::LangDetect(query_lang_detect_parsed))
+                        } else {
+                            Err(ChannelCommandBase::make_error_invalid_meta_value(
+                                meta_key, meta_value,
+                            ))
+                        }
+                    }
+                    "SPELLCHECK" => {
+                        // 'SPELLCHECK(<spellcheck>)' where <spellcheck> ∈ {true, false}
+                        if let Ok(query_spellcheck_parsed) = meta_value.parse::<bool>() {
+                            Ok(QueryMetaData::Spellcheck(query_spellcheck_parsed))
                         } else {
                             Err(ChannelCommandBase::make_error_invalid_meta_value(
                                 meta_key, meta_value,
@@ -596,7 +1899,7 @@ impl ChannelCommandSearch {
 
     fn handle_suggest_meta(
         meta_result: MetaPartsResult,
-    ) -> Result<Option<QuerySearchLimit>, ChannelCommandError> {
+    ) -> Result<SuggestMetaData, ChannelCommandError> {
         match meta_result {
             Ok((meta_key, meta_value)) => {
                 debug!("handle suggest meta: {} = {}", meta_key, meta_value);
@@ -605,7 +1908,52 @@ impl ChannelCommandSearch {
                     "LIMIT" => {
                         // 'LIMIT(<count>)' where 0 <= <count> < 2^16
                         if let Ok(suggest_limit_parsed) = meta_value.parse::<QuerySearchLimit>() {
-                            Ok(Some(suggest_limit_parsed))
+                            Ok(SuggestMetaData::Limit(suggest_limit_parsed))
+                        } else {
+                            Err(ChannelCommandBase::make_error_invalid_meta_value(
+                                meta_key, meta_value,
+                            ))
+                        }
+                    }
+                    "MUX" => {
+                        // 'MUX(<mux>)' where <mux> is a non-empty token
+                        if let Some(suggest_mux_parsed) = ChannelCommandBase::parse_mux(meta_value)
+                        {
+                            Ok(SuggestMetaData::Mux(suggest_mux_parsed))
+                        } else {
+                            Err(ChannelCommandBase::make_error_invalid_meta_value(
+                                meta_key, meta_value,
+                            ))
+                        }
+                    }
+                    "CONTEXT" => {
+                        // 'CONTEXT(<context>)' where <context> is a list of words
+                        if let Some(suggest_context_parsed) =
+                            ChannelCommandBase::parse_context(meta_value)
+                        {
+                            Ok(SuggestMetaData::Context(suggest_context_parsed))
+                        } else {
+                            Err(ChannelCommandBase::make_error_invalid_meta_value(
+                                meta_key, meta_value,
+                            ))
+                        }
+                    }
+                    "EXPAND" => {
+                        // 'EXPAND(<expand>)' where <expand> ∈ {true, false}
+                        if let Ok(suggest_expand_parsed) = meta_value.parse::<bool>() {
+                            Ok(SuggestMetaData::Expand(suggest_expand_parsed))
+                        } else {
+                            Err(ChannelCommandBase::make_error_invalid_meta_value(
+                                meta_key, meta_value,
+                            ))
+                        }
+                    }
+                    "MIN_FREQ" => {
+                        // 'MIN_FREQ(<count>)' where 0 <= <count> < 2^32
+                        if let Ok(suggest_min_freq_parsed) =
+                            meta_value.parse::<QuerySuggestMinFreq>()
+                        {
+                            Ok(SuggestMetaData::MinFreq(suggest_min_freq_parsed))
                         } else {
                             Err(ChannelCommandBase::make_error_invalid_meta_value(
                                 meta_key, meta_value,
@@ -659,6 +2007,58 @@ impl ChannelCommandSearch {
             )),
         }
     }
+
+    fn handle_vsearch_meta(
+        meta_result: MetaPartsResult,
+    ) -> Result<VsearchMetaData, ChannelCommandError> {
+        match meta_result {
+            Ok((meta_key, meta_value)) => {
+                debug!("handle vsearch meta: {} = {}", meta_key, meta_value);
+
+                match meta_key {
+                    "VECTOR" => {
+                        // 'VECTOR(<vector>)' where <vector> is a list of numbers
+                        if let Some(vsearch_vector_parsed) =
+                            ChannelCommandBase::parse_vector(meta_value)
+                        {
+                            Ok(VsearchMetaData::Vector(vsearch_vector_parsed))
+                        } else {
+                            Err(ChannelCommandBase::make_error_invalid_meta_value(
+                                meta_key, meta_value,
+                            ))
+                        }
+                    }
+                    "LIMIT" => {
+                        // 'LIMIT(<count>)' where 0 <= <count> < 2^16
+                        if let Ok(vsearch_limit_parsed) = meta_value.parse::<QuerySearchLimit>() {
+                            Ok(VsearchMetaData::Limit(vsearch_limit_parsed))
+                        } else {
+                            Err(ChannelCommandBase::make_error_invalid_meta_value(
+                                meta_key, meta_value,
+                            ))
+                        }
+                    }
+                    "MUX" => {
+                        // 'MUX(<mux>)' where <mux> is a non-empty token
+                        if let Some(vsearch_mux_parsed) = ChannelCommandBase::parse_mux(meta_value)
+                        {
+                            Ok(VsearchMetaData::Mux(vsearch_mux_parsed))
+                        } else {
+                            Err(ChannelCommandBase::make_error_invalid_meta_value(
+                                meta_key, meta_value,
+                            ))
+                        }
+                    }
+                    _ => Err(ChannelCommandBase::make_error_invalid_meta_key(
+                        meta_key, meta_value,
+                    )),
+                }
+            }
+            Err(err) => Err(ChannelCommandBase::make_error_invalid_meta_key(
+                err.0, err.1,
+            )),
+        }
+    }
 }
 
 impl ChannelCommandIngest {
@@ -667,7 +2067,10 @@ impl ChannelCommandIngest {
             parts.next(),
             parts.next(),
             parts.next(),
-            ChannelCommandBase::parse_text_parts(&mut parts),
+            ChannelCommandBase::parse_text_parts_limited(
+                &mut parts,
+                QueryGuard::max_push_text_length(),
+            )?,
         ) {
             (Some(collection), Some(bucket), Some(object), Some(text)) => {
                 debug!(
@@ -677,7 +2080,7 @@ impl ChannelCommandIngest {
                 debug!("ingest push has text: {}", text);
 
                 // Define push parameters
-                let mut push_lang = None;
+                let (mut push_options, mut push_checksum) = (QueryPushOptions::default(), None);
 
                 // Parse meta parts (meta comes after text; extract meta parts second)
                 let mut last_meta_err = None;
@@ -685,28 +2088,166 @@ impl ChannelCommandIngest {
                 while let Some(meta_result) = ChannelCommandBase::parse_next_meta_parts(&mut parts)
                 {
                     match Self::handle_push_meta(meta_result) {
-                        Ok(Some(push_lang_parsed)) => push_lang = Some(push_lang_parsed),
+                        Ok(PushMetaData::Lang(push_lang_parsed)) => {
+                            push_options.lang = Some(push_lang_parsed)
+                        }
+                        Ok(PushMetaData::Geo(push_geo_parsed)) => {
+                            push_options.geo = Some(push_geo_parsed)
+                        }
+                        Ok(PushMetaData::Weight(push_weight_parsed)) => {
+                            push_options.weight = Some(push_weight_parsed)
+                        }
+                        Ok(PushMetaData::Deduplicate(push_deduplicate_parsed)) => {
+                            push_options.deduplicate = push_deduplicate_parsed
+                        }
+                        Ok(PushMetaData::Tags(push_tags_parsed)) => {
+                            push_options.tags = Some(push_tags_parsed)
+                        }
+                        Ok(PushMetaData::DocID(push_docid_parsed)) => {
+                            push_options.docid = Some(push_docid_parsed)
+                        }
+                        Ok(PushMetaData::Vector(push_vector_parsed)) => {
+                            push_options.vector = Some(push_vector_parsed)
+                        }
+                        Ok(PushMetaData::Segment(push_segment_parsed)) => {
+                            push_options.segment = Some(push_segment_parsed)
+                        }
+                        Ok(PushMetaData::Priority(push_priority_parsed)) => {
+                            push_options.priority = Some(push_priority_parsed)
+                        }
+                        Ok(PushMetaData::Alias(push_alias_parsed)) => {
+                            push_options.alias = Some(push_alias_parsed)
+                        }
+                        Ok(PushMetaData::Checksum(push_checksum_parsed)) => {
+                            push_checksum = Some(push_checksum_parsed)
+                        }
                         Err(parse_err) => last_meta_err = Some(parse_err),
-                        _ => {}
                     }
                 }
 
                 if let Some(err) = last_meta_err {
                     Err(err)
+                } else if QueryGuard::oid_over_limit(object)
+                    || matches!(
+                        push_options.alias.as_deref(),
+                        Some(alias) if QueryGuard::oid_over_limit(alias)
+                    )
+                {
+                    Err(ChannelCommandError::PolicyReject(GUARD_REJECT_OID_TOO_LONG))
+                } else if ChannelCommandBase::checksum_mismatch(&text, push_checksum) {
+                    Err(ChannelCommandError::PolicyReject("checksum_mismatch"))
                 } else {
                     debug!(
-                        "will push for text: {} with hinted locale: <{:?}>",
-                        text, push_lang
+                        "will push for text: {} with hinted locale: <{:?}>, location: <{:?}>, \
+                            weight: <{:?}> and deduplicate: {}",
+                        text,
+                        push_options.lang,
+                        push_options.geo,
+                        push_options.weight,
+                        push_options.deduplicate
                     );
 
                     // Commit 'push' query
-                    ChannelCommandBase::commit_ok_operation(QueryBuilder::push(
-                        collection, bucket, object, &text, push_lang,
+                    ChannelCommandBase::commit_ok_operation(
+                        QueryBuilder::push(collection, bucket, object, &text, push_options),
+                        &[PUSH_REJECT_DOCID_IN_USE, PUSH_REJECT_VECTOR_DIMENSION],
+                    )
+                }
+            }
+            _ => Err(ChannelCommandError::InvalidFormat(
+                "PUSH <collection> <bucket> <object> \"<text>\" [LANG(<locale>)]? \
+                 [GEO(<latitude>,<longitude>)]? [WEIGHT(<weight>)]? \
+                 [DEDUPLICATE(<deduplicate>)]? [TAGS(<tags>)]? \
+                 [DOCID(<docid>)]? [VECTOR(<vector>)]? [SEGMENT(<segment>)]? \
+                 [PRIORITY(<priority>)]? [ALIAS(<object>)]? [CHECKSUM(<checksum>)]?",
+            )),
+        }
+    }
+
+    pub fn dispatch_mpush(mut parts: SplitWhitespace) -> ChannelResult {
+        match (parts.next(), parts.next()) {
+            (Some(collection), Some(bucket)) => {
+                debug!(
+                    "dispatching ingest batch push in collection: {} and bucket: {}",
+                    collection, bucket
+                );
+
+                // Parse object and text pairs (meta comes after text; extract meta parts second)
+                let pairs = Self::parse_batch_pairs(&mut parts)?;
+
+                // Parse meta parts (only the language can be hinted, for the whole batch)
+                let (mut push_lang, mut last_meta_err) = (None, None);
+
+                while let Some(meta_result) = ChannelCommandBase::parse_next_meta_parts(&mut parts)
+                {
+                    match meta_result {
+                        Ok((meta_key, meta_value)) if meta_key != "LANG" => {
+                            last_meta_err = Some(ChannelCommandBase::make_error_invalid_meta_key(
+                                meta_key, meta_value,
+                            ))
+                        }
+                        _ => match Self::handle_push_meta(meta_result) {
+                            Ok(PushMetaData::Lang(push_lang_parsed)) => {
+                                push_lang = Some(push_lang_parsed)
+                            }
+                            Ok(_) => {}
+                            Err(parse_err) => last_meta_err = Some(parse_err),
+                        },
+                    }
+                }
+
+                if let Some(err) = last_meta_err {
+                    Err(err)
+                } else if pairs.is_empty() {
+                    Err(ChannelCommandError::InvalidFormat(
+                        "MPUSH <collection> <bucket> <object> \"<text>\" \
+                         [<object> \"<text>\"]* [LANG(<locale>)]?",
                     ))
+                } else if pairs.len() > APP_CONF.channel.ingest.batch_push_limit {
+                    Err(ChannelCommandError::PolicyReject("batch_too_large"))
+                } else if pairs
+                    .iter()
+                    .any(|(object, _)| QueryGuard::oid_over_limit(object))
+                {
+                    // Notice: the whole batch gets rejected, as partially pushing it would be \
+                    //   confusing to the client.
+                    Err(ChannelCommandError::PolicyReject(GUARD_REJECT_OID_TOO_LONG))
+                } else {
+                    debug!(
+                        "will batch push {} objects with hinted locale: <{:?}>",
+                        pairs.len(),
+                        push_lang
+                    );
+
+                    // Commit one 'push' query per object (objects that fail being pushed are \
+                    //   not counted, and do not abort the whole batch)
+                    let mut count_pushed = 0;
+
+                    for (object, text) in pairs {
+                        let push_options = QueryPushOptions {
+                            lang: push_lang.clone(),
+                            ..Default::default()
+                        };
+
+                        match QueryBuilder::push(collection, bucket, object, &text, push_options)
+                            .and_then(StoreOperationDispatch::dispatch)
+                        {
+                            Ok(None) => count_pushed += 1,
+                            Ok(Some(reason)) => {
+                                info!("batch push rejected object: {} ({})", object, reason)
+                            }
+                            Err(_) => warn!("batch push failed for object: {}", object),
+                        }
+                    }
+
+                    Ok(vec![ChannelCommandResponse::Result(
+                        count_pushed.to_string(),
+                    )])
                 }
             }
             _ => Err(ChannelCommandError::InvalidFormat(
-                "PUSH <collection> <bucket> <object> \"<text>\" [LANG(<locale>)]?",
+                "MPUSH <collection> <bucket> <object> \"<text>\" \
+                 [<object> \"<text>\"]* [LANG(<locale>)]?",
             )),
         }
     }
@@ -716,7 +2257,7 @@ impl ChannelCommandIngest {
             parts.next(),
             parts.next(),
             parts.next(),
-            ChannelCommandBase::parse_text_parts(&mut parts),
+            ChannelCommandBase::parse_text_parts(&mut parts)?,
             parts.next(),
         ) {
             (Some(collection), Some(bucket), Some(object), Some(text), None) => {
@@ -726,10 +2267,14 @@ impl ChannelCommandIngest {
                 );
                 debug!("ingest pop has text: {}", text);
 
-                // Make 'pop' query
-                ChannelCommandBase::commit_result_operation(QueryBuilder::pop(
-                    collection, bucket, object, &text,
-                ))
+                if QueryGuard::oid_over_limit(object) {
+                    Err(ChannelCommandError::PolicyReject(GUARD_REJECT_OID_TOO_LONG))
+                } else {
+                    // Make 'pop' query
+                    ChannelCommandBase::commit_result_operation(QueryBuilder::pop(
+                        collection, bucket, object, &text,
+                    ))
+                }
             }
             _ => Err(ChannelCommandError::InvalidFormat(
                 "POP <collection> <bucket> <object> \"<text>\"",
@@ -742,12 +2287,16 @@ impl ChannelCommandIngest {
             (Some(collection), bucket_part, object_part, None) => {
                 debug!("dispatching ingest count in collection: {}", collection);
 
-                // Make 'count' query
-                ChannelCommandBase::commit_result_operation(QueryBuilder::count(
-                    collection,
-                    bucket_part,
-                    object_part,
-                ))
+                if matches!(object_part, Some(object) if QueryGuard::oid_over_limit(object)) {
+                    Err(ChannelCommandError::PolicyReject(GUARD_REJECT_OID_TOO_LONG))
+                } else {
+                    // Make 'count' query
+                    ChannelCommandBase::commit_result_operation(QueryBuilder::count(
+                        collection,
+                        bucket_part,
+                        object_part,
+                    ))
+                }
             }
             _ => Err(ChannelCommandError::InvalidFormat(
                 "COUNT <collection> [<bucket> [<object>]?]?",
@@ -784,37 +2333,178 @@ impl ChannelCommandIngest {
                 ))
             }
             _ => Err(ChannelCommandError::InvalidFormat(
-                "FLUSHB <collection> <bucket>",
+                "FLUSHB <collection> <bucket>",
+            )),
+        }
+    }
+
+    pub fn dispatch_flusho(mut parts: SplitWhitespace) -> ChannelResult {
+        match (parts.next(), parts.next(), parts.next(), parts.next()) {
+            (Some(collection), Some(bucket), Some(object), None) => {
+                debug!(
+                    "dispatching ingest flush object in collection: {}, bucket: {}, object: {}",
+                    collection, bucket, object
+                );
+
+                if QueryGuard::oid_over_limit(object) {
+                    Err(ChannelCommandError::PolicyReject(GUARD_REJECT_OID_TOO_LONG))
+                } else {
+                    // Make 'flusho' query
+                    ChannelCommandBase::commit_result_operation(QueryBuilder::flusho(
+                        collection, bucket, object,
+                    ))
+                }
+            }
+            _ => Err(ChannelCommandError::InvalidFormat(
+                "FLUSHO <collection> <bucket> <object>",
+            )),
+        }
+    }
+
+    pub fn dispatch_flusht(mut parts: SplitWhitespace) -> ChannelResult {
+        match (
+            parts.next(),
+            ChannelCommandBase::parse_text_parts(&mut parts)?,
+            parts.next(),
+        ) {
+            (Some(collection), Some(text), None) => {
+                debug!(
+                    "dispatching ingest flush term in collection: {}",
+                    collection
+                );
+                debug!("ingest flush term has text: {}", text);
+
+                // Make 'flusht' query
+                ChannelCommandBase::commit_result_operation(QueryBuilder::flusht(collection, &text))
+            }
+            _ => Err(ChannelCommandError::InvalidFormat(
+                "FLUSHT <collection> \"<text>\"",
+            )),
+        }
+    }
+
+    pub fn dispatch_flush_orphans(mut parts: SplitWhitespace) -> ChannelResult {
+        match (parts.next(), parts.next(), parts.next()) {
+            (Some(collection), Some(bucket), None) => {
+                debug!(
+                    "dispatching ingest flush orphans in collection: {}, bucket: {}",
+                    collection, bucket
+                );
+
+                // Make 'flush_orphans' query
+                ChannelCommandBase::commit_result_operation(QueryBuilder::flush_orphans(
+                    collection, bucket,
+                ))
+            }
+            _ => Err(ChannelCommandError::InvalidFormat(
+                "FLUSH_ORPHANS <collection> <bucket>",
+            )),
+        }
+    }
+
+    pub fn dispatch_learn(mut parts: SplitWhitespace) -> ChannelResult {
+        match (
+            parts.next(),
+            parts.next(),
+            ChannelCommandBase::parse_text_parts(&mut parts)?,
+            parts.next(),
+        ) {
+            (Some(collection), Some(user), Some(text), None) => {
+                debug!(
+                    "dispatching ingest learn in collection: {} for user: {}",
+                    collection, user
+                );
+                debug!("ingest learn has text: {}", text);
+
+                // Make 'learn' query
+                ChannelCommandBase::commit_result_operation(QueryBuilder::learn(
+                    collection, user, &text,
+                ))
+            }
+            _ => Err(ChannelCommandError::InvalidFormat(
+                "LEARN <collection> <user> \"<text>\"",
+            )),
+        }
+    }
+
+    pub fn dispatch_inspect(mut parts: SplitWhitespace) -> ChannelResult {
+        match (parts.next(), parts.next()) {
+            (Some(collection), Some(bucket)) => {
+                debug!(
+                    "dispatching ingest inspect in collection: {} and bucket: {}",
+                    collection, bucket
+                );
+
+                // Define inspect parameters
+                let mut inspect_limit = APP_CONF.channel.search.list_limit_default;
+
+                // Parse meta parts (meta comes last; extract meta parts second)
+                let mut last_meta_err = None;
+
+                while let Some(meta_result) = ChannelCommandBase::parse_next_meta_parts(&mut parts)
+                {
+                    match Self::handle_inspect_meta(meta_result) {
+                        Ok(inspect_limit_parsed) => inspect_limit = inspect_limit_parsed,
+                        Err(parse_err) => last_meta_err = Some(parse_err),
+                    }
+                }
+
+                if let Some(err) = last_meta_err {
+                    Err(err)
+                } else if !APP_CONF.channel.allow_inspect {
+                    Err(ChannelCommandError::PolicyReject("inspect_not_allowed"))
+                } else if inspect_limit < 1
+                    || inspect_limit > APP_CONF.channel.search.list_limit_maximum
+                {
+                    Err(ChannelCommandError::PolicyReject(
+                        "LIMIT out of minimum/maximum bounds",
+                    ))
+                } else {
+                    // Make 'inspect' query
+                    ChannelCommandBase::commit_result_operation(QueryBuilder::inspect(
+                        collection,
+                        bucket,
+                        inspect_limit,
+                    ))
+                }
+            }
+            _ => Err(ChannelCommandError::InvalidFormat(
+                "INSPECT <collection> <bucket> [LIMIT(<count>)]?",
             )),
         }
     }
 
-    pub fn dispatch_flusho(mut parts: SplitWhitespace) -> ChannelResult {
-        match (parts.next(), parts.next(), parts.next(), parts.next()) {
-            (Some(collection), Some(bucket), Some(object), None) => {
-                debug!(
-                    "dispatching ingest flush object in collection: {}, bucket: {}, object: {}",
-                    collection, bucket, object
-                );
+    pub fn dispatch_help(parts: SplitWhitespace) -> ChannelResult {
+        ChannelCommandBase::generic_dispatch_help(parts, &*MANUAL_MODE_INGEST)
+    }
 
-                // Make 'flusho' query
-                ChannelCommandBase::commit_result_operation(QueryBuilder::flusho(
-                    collection, bucket, object,
-                ))
+    fn parse_batch_pairs<'a>(
+        parts: &mut SplitWhitespace<'a>,
+    ) -> Result<Vec<(&'a str, String)>, ChannelCommandError> {
+        let mut pairs = Vec::new();
+
+        // Notice: a part that is not followed by a text is not an object, but the first meta \
+        //   part, thus parts are only consumed once a whole pair could be parsed.
+        loop {
+            let mut pair_parts = parts.clone();
+
+            match (
+                pair_parts.next(),
+                ChannelCommandBase::parse_text_parts(&mut pair_parts)?,
+            ) {
+                (Some(object), Some(text)) => {
+                    pairs.push((object, text));
+
+                    *parts = pair_parts;
+                }
+                _ => break,
             }
-            _ => Err(ChannelCommandError::InvalidFormat(
-                "FLUSHO <collection> <bucket> <object>",
-            )),
         }
-    }
 
-    pub fn dispatch_help(parts: SplitWhitespace) -> ChannelResult {
-        ChannelCommandBase::generic_dispatch_help(parts, &*MANUAL_MODE_INGEST)
+        Ok(pairs)
     }
 
-    fn handle_push_meta(
-        meta_result: MetaPartsResult,
-    ) -> Result<Option<QueryGenericLang>, ChannelCommandError> {
+    fn handle_push_meta(meta_result: MetaPartsResult) -> Result<PushMetaData, ChannelCommandError> {
         match meta_result {
             Ok((meta_key, meta_value)) => {
                 debug!("handle push meta: {} = {}", meta_key, meta_value);
@@ -823,7 +2513,139 @@ impl ChannelCommandIngest {
                     "LANG" => {
                         // 'LANG(<locale>)' where <locale> ∈ ISO 639-3
                         if let Some(query_lang_parsed) = QueryGenericLang::from_value(meta_value) {
-                            Ok(Some(query_lang_parsed))
+                            Ok(PushMetaData::Lang(query_lang_parsed))
+                        } else {
+                            Err(ChannelCommandBase::make_error_invalid_meta_value(
+                                meta_key, meta_value,
+                            ))
+                        }
+                    }
+                    "DEDUPLICATE" => {
+                        // 'DEDUPLICATE(<deduplicate>)' where <deduplicate> ∈ {true, false}
+                        if let Ok(push_deduplicate_parsed) = meta_value.parse::<bool>() {
+                            Ok(PushMetaData::Deduplicate(push_deduplicate_parsed))
+                        } else {
+                            Err(ChannelCommandBase::make_error_invalid_meta_value(
+                                meta_key, meta_value,
+                            ))
+                        }
+                    }
+                    "GEO" => {
+                        // 'GEO(<latitude>,<longitude>)' where <latitude> ∈ [-90, 90] and \
+                        //   <longitude> ∈ [-180, 180]
+                        if let Some(push_geo_parsed) = ChannelCommandBase::parse_geo(meta_value) {
+                            Ok(PushMetaData::Geo(push_geo_parsed))
+                        } else {
+                            Err(ChannelCommandBase::make_error_invalid_meta_value(
+                                meta_key, meta_value,
+                            ))
+                        }
+                    }
+                    "WEIGHT" => {
+                        // 'WEIGHT(<weight>)' where <weight> ∈ [0.0, 1.0]
+                        if let Some(push_weight_parsed) =
+                            ChannelCommandBase::parse_weight(meta_value)
+                        {
+                            Ok(PushMetaData::Weight(push_weight_parsed))
+                        } else {
+                            Err(ChannelCommandBase::make_error_invalid_meta_value(
+                                meta_key, meta_value,
+                            ))
+                        }
+                    }
+                    "DOCID" => {
+                        // 'DOCID(<docid>)' where 0 <= <docid> < 2^32 - 1 (the highest IID is \
+                        //   reserved, as further auto-incremented IIDs would overflow)
+                        match meta_value.parse::<QueryPushDocID>() {
+                            Ok(push_docid_parsed) if push_docid_parsed < QueryPushDocID::MAX => {
+                                Ok(PushMetaData::DocID(push_docid_parsed))
+                            }
+                            _ => Err(ChannelCommandBase::make_error_invalid_meta_value(
+                                meta_key, meta_value,
+                            )),
+                        }
+                    }
+                    "TAGS" => {
+                        // 'TAGS(<tags>)' where <tags> is a list of tags
+                        if let Some(push_tags_parsed) = ChannelCommandBase::parse_tags(meta_value) {
+                            Ok(PushMetaData::Tags(push_tags_parsed))
+                        } else {
+                            Err(ChannelCommandBase::make_error_invalid_meta_value(
+                                meta_key, meta_value,
+                            ))
+                        }
+                    }
+                    "VECTOR" => {
+                        // 'VECTOR(<vector>)' where <vector> is a list of numbers
+                        if let Some(push_vector_parsed) =
+                            ChannelCommandBase::parse_vector(meta_value)
+                        {
+                            Ok(PushMetaData::Vector(push_vector_parsed))
+                        } else {
+                            Err(ChannelCommandBase::make_error_invalid_meta_value(
+                                meta_key, meta_value,
+                            ))
+                        }
+                    }
+                    "SEGMENT" => {
+                        // 'SEGMENT(<segment>)' where 0 <= <segment> < 2^8
+                        if let Ok(push_segment_parsed) = meta_value.parse::<QueryGenericSegment>() {
+                            Ok(PushMetaData::Segment(push_segment_parsed))
+                        } else {
+                            Err(ChannelCommandBase::make_error_invalid_meta_value(
+                                meta_key, meta_value,
+                            ))
+                        }
+                    }
+                    "PRIORITY" => {
+                        // 'PRIORITY(<priority>)' where 0 <= <priority> < 2^8
+                        if let Ok(push_priority_parsed) = meta_value.parse::<QueryPushPriority>() {
+                            Ok(PushMetaData::Priority(push_priority_parsed))
+                        } else {
+                            Err(ChannelCommandBase::make_error_invalid_meta_value(
+                                meta_key, meta_value,
+                            ))
+                        }
+                    }
+                    "ALIAS" => {
+                        // 'ALIAS(<object>)' where <object> is the canonical object identifier
+                        Ok(PushMetaData::Alias(meta_value.to_string()))
+                    }
+                    "CHECKSUM" => {
+                        // 'CHECKSUM(<checksum>)' where <checksum> is an hexadecimal SHA-256 digest
+                        if let Some(push_checksum_parsed) =
+                            ChannelCommandBase::parse_checksum(meta_value)
+                        {
+                            Ok(PushMetaData::Checksum(push_checksum_parsed))
+                        } else {
+                            Err(ChannelCommandBase::make_error_invalid_meta_value(
+                                meta_key, meta_value,
+                            ))
+                        }
+                    }
+                    _ => Err(ChannelCommandBase::make_error_invalid_meta_key(
+                        meta_key, meta_value,
+                    )),
+                }
+            }
+            Err(err) => Err(ChannelCommandBase::make_error_invalid_meta_key(
+                err.0, err.1,
+            )),
+        }
+    }
+
+    fn handle_inspect_meta(
+        meta_result: MetaPartsResult,
+    ) -> Result<QuerySearchLimit, ChannelCommandError> {
+        match meta_result {
+            Ok((meta_key, meta_value)) => {
+                debug!("handle inspect meta: {} = {}", meta_key, meta_value);
+
+                match meta_key {
+                    "LIMIT" => {
+                        // 'LIMIT(<count>)' where 0 <= <count> < 2^16
+                        if let Ok(inspect_limit_parsed) = meta_value.parse::<QuerySearchLimit>() {
+                            Ok(inspect_limit_parsed)
                         } else {
                             Err(ChannelCommandBase::make_error_invalid_meta_value(
                                 meta_key, meta_value,
@@ -863,38 +2685,636 @@ impl ChannelCommandControl {
                             Err(ChannelCommandError::InvalidFormat("TRIGGER consolidate"))
                         }
                     }
-                    "backup" => {
-                        match (data_part, last_part) {
-                            (Some(path), None) => {
-                                // Proceed KV + FST backup
-                                let path = Path::new(path);
+                    "backup" => {
+                        match (data_part, last_part) {
+                            (Some(path), None) => {
+                                // Proceed KV + FST backup
+                                let path = Path::new(path);
+
+                                if StoreKVPool::backup(&path.join(BACKUP_KV_PATH)).is_ok()
+                                    && StoreFSTPool::backup(&path.join(BACKUP_FST_PATH)).is_ok()
+                                {
+                                    Ok(vec![ChannelCommandResponse::Ok])
+                                } else {
+                                    Err(ChannelCommandError::InternalError)
+                                }
+                            }
+                            _ => Err(ChannelCommandError::InvalidFormat("TRIGGER backup <path>")),
+                        }
+                    }
+                    "backup_incremental" => {
+                        match (data_part, last_part) {
+                            (Some(path), None) => {
+                                // Proceed KV incremental + FST backup
+                                // Notice: FST files cannot be backed up incrementally, thus they \
+                                //   always get replaced with their latest version, while KV \
+                                //   backups stack a new generation.
+                                let path = Path::new(path);
+
+                                if StoreKVPool::backup_incremental(&path.join(BACKUP_KV_PATH))
+                                    .is_ok()
+                                    && StoreFSTPool::backup(&path.join(BACKUP_FST_PATH)).is_ok()
+                                {
+                                    Ok(vec![ChannelCommandResponse::Ok])
+                                } else {
+                                    Err(ChannelCommandError::InternalError)
+                                }
+                            }
+                            _ => Err(ChannelCommandError::InvalidFormat(
+                                "TRIGGER backup_incremental <path>",
+                            )),
+                        }
+                    }
+                    "backup_prune" => {
+                        match (
+                            data_part,
+                            last_part.and_then(|keep_count| keep_count.parse::<u32>().ok()),
+                            parts.next(),
+                        ) {
+                            (Some(path), Some(keep_count), None) if keep_count > 0 => {
+                                // Proceed KV backup generations prune
+                                if StoreKVPool::backup_prune(
+                                    &Path::new(path).join(BACKUP_KV_PATH),
+                                    keep_count,
+                                )
+                                .is_ok()
+                                {
+                                    Ok(vec![ChannelCommandResponse::Ok])
+                                } else {
+                                    Err(ChannelCommandError::InternalError)
+                                }
+                            }
+                            _ => Err(ChannelCommandError::InvalidFormat(
+                                "TRIGGER backup_prune <path> <keep_count>",
+                            )),
+                        }
+                    }
+                    "restore" => {
+                        match (data_part, last_part) {
+                            (Some(path), None) => {
+                                // Proceed KV + FST restore
+                                let path = Path::new(path);
+
+                                let is_restored = StoreKVPool::restore(&path.join(BACKUP_KV_PATH))
+                                    .is_ok()
+                                    && StoreFSTPool::restore(&path.join(BACKUP_FST_PATH)).is_ok();
+
+                                // Notice: cached search results are cleared even if the restore \
+                                //   failed, as it may have been partially done.
+                                QueryCache::clear();
+
+                                if is_restored {
+                                    Ok(vec![ChannelCommandResponse::Ok])
+                                } else {
+                                    Err(ChannelCommandError::InternalError)
+                                }
+                            }
+                            _ => Err(ChannelCommandError::InvalidFormat("TRIGGER restore <path>")),
+                        }
+                    }
+                    "verify" => {
+                        match (data_part, last_part) {
+                            (Some(path), None) => {
+                                // Proceed KV + FST backup verification
+                                // Notice: both stores are always verified, so that all corrupt \
+                                //   files get logged.
+                                let path = Path::new(path);
+
+                                let kv_verified =
+                                    StoreKVPool::verify(&path.join(BACKUP_KV_PATH)).is_ok();
+                                let fst_verified =
+                                    StoreFSTPool::verify(&path.join(BACKUP_FST_PATH)).is_ok();
+
+                                if kv_verified && fst_verified {
+                                    Ok(vec![ChannelCommandResponse::Ok])
+                                } else {
+                                    Err(ChannelCommandError::InternalError)
+                                }
+                            }
+                            _ => Err(ChannelCommandError::InvalidFormat("TRIGGER verify <path>")),
+                        }
+                    }
+                    "export" => {
+                        match (data_part, last_part) {
+                            (Some(path), None) => {
+                                // Proceed FST words export
+                                StoreFSTPool::export(Path::new(path))
+                                    .map(|count| {
+                                        vec![ChannelCommandResponse::Result(count.to_string())]
+                                    })
+                                    .or(Err(ChannelCommandError::InternalError))
+                            }
+                            _ => Err(ChannelCommandError::InvalidFormat("TRIGGER export <path>")),
+                        }
+                    }
+                    "import" => {
+                        match (data_part, last_part) {
+                            (Some(path), None) => {
+                                // Proceed FST words import
+                                let import_result = StoreFSTPool::import(Path::new(path));
+
+                                QueryCache::clear();
+
+                                import_result
+                                    .map(|count| {
+                                        vec![ChannelCommandResponse::Result(count.to_string())]
+                                    })
+                                    .or(Err(ChannelCommandError::InternalError))
+                            }
+                            _ => Err(ChannelCommandError::InvalidFormat("TRIGGER import <path>")),
+                        }
+                    }
+                    "compact_iids" => {
+                        match (data_part, last_part, parts.next()) {
+                            (Some(collection), Some(bucket), None) => {
+                                // Proceed KV IIDs compaction
+                                if let Ok(store) =
+                                    StoreItemBuilder::from_depth_2(collection, bucket)
+                                {
+                                    let (collection, bucket) = (store.0, store.1);
+
+                                    // Notice: IIDs get remapped, thus cached results are stale.
+                                    StoreOperationDispatch::invalidate_cache(
+                                        ExecutorCompactIIDs::execute(store),
+                                        collection,
+                                        bucket,
+                                    )
+                                    .map(|count| {
+                                        vec![ChannelCommandResponse::Result(count.to_string())]
+                                    })
+                                    .or(Err(ChannelCommandError::InternalError))
+                                } else {
+                                    Err(ChannelCommandError::QueryError)
+                                }
+                            }
+                            _ => Err(ChannelCommandError::InvalidFormat(
+                                "TRIGGER compact_iids <collection> <bucket>",
+                            )),
+                        }
+                    }
+                    "clone" => {
+                        match (data_part, last_part, parts.next()) {
+                            (Some(source_collection), Some(target_collection), None) => {
+                                // Proceed KV + FST collection clone
+                                match (
+                                    StoreItemBuilder::from_depth_1(source_collection),
+                                    StoreItemBuilder::from_depth_1(target_collection),
+                                ) {
+                                    (Ok(source), Ok(target))
+                                        if source_collection != target_collection =>
+                                    {
+                                        let target_collection = target.0;
+
+                                        match StoreOperationDispatch::invalidate_cache(
+                                            ExecutorClone::execute(source, target),
+                                            target_collection,
+                                            None,
+                                        ) {
+                                            Ok(Some((count_objects, count_buckets))) => {
+                                                Ok(vec![ChannelCommandResponse::Result(format!(
+                                                    "objects({}) buckets({})",
+                                                    count_objects, count_buckets
+                                                ))])
+                                            }
+                                            Ok(None) => Err(ChannelCommandError::PolicyReject(
+                                                "target collection already exists",
+                                            )),
+                                            Err(_) => Err(ChannelCommandError::InternalError),
+                                        }
+                                    }
+                                    _ => Err(ChannelCommandError::QueryError),
+                                }
+                            }
+                            _ => Err(ChannelCommandError::InvalidFormat(
+                                "TRIGGER clone <src_collection> <dst_collection>",
+                            )),
+                        }
+                    }
+                    "dump_fst" => {
+                        match (data_part, last_part, parts.next(), parts.next()) {
+                            (Some(collection), Some(bucket), Some(path), None) => {
+                                // Proceed FST words dump
+                                if let Ok(store) =
+                                    StoreItemBuilder::from_depth_2(collection, bucket)
+                                {
+                                    ExecutorDumpFST::execute(store, Path::new(path))
+                                        .map(|count| {
+                                            vec![ChannelCommandResponse::Result(count.to_string())]
+                                        })
+                                        .or(Err(ChannelCommandError::InternalError))
+                                } else {
+                                    Err(ChannelCommandError::QueryError)
+                                }
+                            }
+                            _ => Err(ChannelCommandError::InvalidFormat(
+                                "TRIGGER dump_fst <collection> <bucket> <path>",
+                            )),
+                        }
+                    }
+                    "reopen" => {
+                        match (data_part, last_part) {
+                            (Some(collection), None) => {
+                                // Proceed KV + FST reopen
+                                if let Ok(store) = StoreItemBuilder::from_depth_1(collection) {
+                                    let collection = store.0;
+
+                                    StoreOperationDispatch::invalidate_cache(
+                                        ExecutorReopen::execute(store),
+                                        collection,
+                                        None,
+                                    )
+                                    .map(|(kv_reopened, fst_buckets_reopened)| {
+                                        vec![ChannelCommandResponse::Result(format!(
+                                            "{} fst_buckets_reopened({})",
+                                            if kv_reopened {
+                                                "kv_reopened"
+                                            } else {
+                                                "kv_not_found"
+                                            },
+                                            fst_buckets_reopened
+                                        ))]
+                                    })
+                                    .or(Err(ChannelCommandError::InternalError))
+                                } else {
+                                    Err(ChannelCommandError::QueryError)
+                                }
+                            }
+                            _ => Err(ChannelCommandError::InvalidFormat(
+                                "TRIGGER reopen <collection>",
+                            )),
+                        }
+                    }
+                    "verify_fst" => {
+                        match (data_part, last_part) {
+                            (Some(collection), Some(bucket)) => {
+                                // Parse meta parts (meta comes after bucket)
+                                let (mut verify_repair, mut last_meta_err) = (false, None);
+
+                                while let Some(meta_result) =
+                                    ChannelCommandBase::parse_next_meta_parts(&mut parts)
+                                {
+                                    match Self::handle_verify_fst_meta(meta_result) {
+                                        Ok(verify_repair_parsed) => {
+                                            verify_repair = verify_repair_parsed
+                                        }
+                                        Err(parse_err) => last_meta_err = Some(parse_err),
+                                    }
+                                }
+
+                                if let Some(err) = last_meta_err {
+                                    Err(err)
+                                } else if let Ok(store) =
+                                    StoreItemBuilder::from_depth_2(collection, bucket)
+                                {
+                                    let (collection, bucket) = (store.0, store.1);
+
+                                    // Proceed FST verification (a repaired FST gets erased, \
+                                    //   thus cached results are stale)
+                                    let verify_result =
+                                        ExecutorVerifyFST::execute(store, verify_repair);
+
+                                    if verify_repair {
+                                        if let Ok(Some(_)) = verify_result {
+                                            QueryCache::invalidate(collection, bucket);
+                                        }
+                                    }
+
+                                    verify_result
+                                        .map(|verify_error| {
+                                            vec![ChannelCommandResponse::Result(
+                                                if let Some(verify_error) = verify_error {
+                                                    format!("corrupt({})", verify_error)
+                                                } else {
+                                                    "ok".to_string()
+                                                },
+                                            )]
+                                        })
+                                        .or(Err(ChannelCommandError::InternalError))
+                                } else {
+                                    Err(ChannelCommandError::QueryError)
+                                }
+                            }
+                            _ => Err(ChannelCommandError::InvalidFormat(
+                                "TRIGGER verify_fst <collection> <bucket> [REPAIR(<repair>)]?",
+                            )),
+                        }
+                    }
+                    "verify_consistency" => {
+                        match (data_part, last_part) {
+                            (Some(collection), None) => {
+                                // Proceed KV + FST consistency verification
+                                if let Ok(store) = StoreItemBuilder::from_depth_1(collection) {
+                                    ExecutorVerifyConsistency::execute(store)
+                                        .map(|(count_kv_only, count_fst_only, count_common)| {
+                                            vec![ChannelCommandResponse::Result(format!(
+                                                "kv_only({}) fst_only({}) common({})",
+                                                count_kv_only, count_fst_only, count_common
+                                            ))]
+                                        })
+                                        .or(Err(ChannelCommandError::InternalError))
+                                } else {
+                                    Err(ChannelCommandError::QueryError)
+                                }
+                            }
+                            _ => Err(ChannelCommandError::InvalidFormat(
+                                "TRIGGER verify_consistency <collection>",
+                            )),
+                        }
+                    }
+                    "rebuild_reverse_index" => {
+                        match (data_part, last_part) {
+                            (Some(collection), None) => {
+                                // Proceed term hash to word reverse index rebuild
+                                if let Ok(store) = StoreItemBuilder::from_depth_1(collection) {
+                                    let collection = store.0;
+
+                                    StoreOperationDispatch::invalidate_cache(
+                                        ExecutorRebuildReverseIndex::execute(store),
+                                        collection,
+                                        None,
+                                    )
+                                    .map(|(count_mapped, count_unmapped)| {
+                                        vec![ChannelCommandResponse::Result(format!(
+                                            "terms_mapped({}) terms_unmapped({})",
+                                            count_mapped, count_unmapped
+                                        ))]
+                                    })
+                                    .or(Err(ChannelCommandError::InternalError))
+                                } else {
+                                    Err(ChannelCommandError::QueryError)
+                                }
+                            }
+                            _ => Err(ChannelCommandError::InvalidFormat(
+                                "TRIGGER rebuild_reverse_index <collection>",
+                            )),
+                        }
+                    }
+                    "reindex" => {
+                        let store = match (data_part, last_part, parts.next()) {
+                            (Some(collection), None, None) => {
+                                Some(StoreItemBuilder::from_depth_1(collection))
+                            }
+                            (Some(collection), Some(bucket), None) => {
+                                Some(StoreItemBuilder::from_depth_2(collection, bucket))
+                            }
+                            _ => None,
+                        };
+
+                        match store {
+                            Some(Ok(store)) => {
+                                // Proceed FST re-population from the KV store, then force a FST \
+                                //   consolidate (as pushed words are only pending until then)
+                                let (collection, bucket) = (store.0, store.1);
+
+                                let reindex_result = ExecutorReindex::execute(store);
+
+                                if reindex_result.is_ok() {
+                                    StoreFSTPool::consolidate(true);
+                                }
+
+                                // Notice: cached results are invalidated once consolidated.
+                                StoreOperationDispatch::invalidate_cache(
+                                    reindex_result,
+                                    collection,
+                                    bucket,
+                                )
+                                .map(|(count_pushed, count_unmapped)| {
+                                    vec![ChannelCommandResponse::Result(format!(
+                                        "words_pushed({}) terms_unmapped({})",
+                                        count_pushed, count_unmapped
+                                    ))]
+                                })
+                                .or(Err(ChannelCommandError::InternalError))
+                            }
+                            Some(Err(_)) => Err(ChannelCommandError::QueryError),
+                            None => Err(ChannelCommandError::InvalidFormat(
+                                "TRIGGER reindex <collection> [<bucket>]?",
+                            )),
+                        }
+                    }
+                    "vacuum" => {
+                        match (data_part, last_part) {
+                            (Some(collection), None) => {
+                                // Proceed KV store vacuum (ie. delete leftover empty keys)
+                                if let Ok(store) = StoreItemBuilder::from_depth_1(collection) {
+                                    let collection = store.0;
+
+                                    StoreOperationDispatch::invalidate_cache(
+                                        ExecutorVacuum::execute(store),
+                                        collection,
+                                        None,
+                                    )
+                                    .map(|count| {
+                                        vec![ChannelCommandResponse::Result(count.to_string())]
+                                    })
+                                    .or(Err(ChannelCommandError::InternalError))
+                                } else {
+                                    Err(ChannelCommandError::QueryError)
+                                }
+                            }
+                            _ => Err(ChannelCommandError::InvalidFormat(
+                                "TRIGGER vacuum <collection>",
+                            )),
+                        }
+                    }
+                    "fsck" => {
+                        if data_part.is_none() {
+                            // Proceed full KV + FST file system consistency check
+                            ExecutorFsck::execute()
+                                .map(|(count_warnings, count_errors)| {
+                                    vec![ChannelCommandResponse::Result(if count_errors > 0 {
+                                        format!("errors({})", count_errors)
+                                    } else if count_warnings > 0 {
+                                        format!("warnings({})", count_warnings)
+                                    } else {
+                                        "ok".to_string()
+                                    })]
+                                })
+                                .or(Err(ChannelCommandError::InternalError))
+                        } else {
+                            Err(ChannelCommandError::InvalidFormat("TRIGGER fsck"))
+                        }
+                    }
+                    "index_stats" => {
+                        if data_part.is_none() {
+                            // Gather statistics over all open KV + FST stores
+                            let (kv_stats, fst_stats) =
+                                (StoreKVPool::stats(), StoreFSTPool::stats());
+
+                            Ok(vec![ChannelCommandResponse::Result(format!(
+                                "collections_open({}) objects_total({}) terms_total({}) \
+                                    kv_disk_bytes({}) fst_disk_bytes({}) kv_memory_bytes({}) \
+                                    fst_memory_bytes({})",
+                                kv_stats.collections,
+                                kv_stats.objects,
+                                fst_stats.terms,
+                                kv_stats.disk_bytes,
+                                fst_stats.disk_bytes,
+                                kv_stats.memory_bytes,
+                                fst_stats.memory_bytes
+                            ))])
+                        } else {
+                            Err(ChannelCommandError::InvalidFormat("TRIGGER index_stats"))
+                        }
+                    }
+                    "compaction_stats" => {
+                        match (data_part, last_part) {
+                            (collection, None) => {
+                                // Gather raw RocksDB statistics over open KV stores (or a single \
+                                //   one); each statistics line is sent as its own 'RESULT' line, \
+                                //   prefixed with its store key
+                                let stores_stats = StoreKVPool::compaction_stats(collection);
+
+                                if stores_stats.is_empty() {
+                                    if collection.is_some() {
+                                        Err(ChannelCommandError::NotFound)
+                                    } else {
+                                        Ok(vec![ChannelCommandResponse::Ok])
+                                    }
+                                } else {
+                                    Ok(stores_stats
+                                        .iter()
+                                        .flat_map(|(pool_key, stats)| {
+                                            stats
+                                                .lines()
+                                                .map(str::trim_end)
+                                                .filter(|line| !line.is_empty())
+                                                .map(move |line| {
+                                                    ChannelCommandResponse::Result(format!(
+                                                        "{} {}",
+                                                        pool_key, line
+                                                    ))
+                                                })
+                                        })
+                                        .collect())
+                                }
+                            }
+                            _ => Err(ChannelCommandError::InvalidFormat(
+                                "TRIGGER compaction_stats [<collection>]?",
+                            )),
+                        }
+                    }
+                    "compaction_schedule" => {
+                        // Re-assemble schedule from all remaining parts (it is quoted, and thus \
+                        //   contains spaces)
+                        let schedule_raw =
+                            last_part.into_iter().chain(parts).collect::<Vec<&str>>();
+
+                        match (
+                            data_part,
+                            ChannelCommandBase::parse_text_parts(
+                                &mut schedule_raw.join(" ").split_whitespace(),
+                            )?,
+                        ) {
+                            (Some(collection), Some(expression)) => {
+                                // Override collection compaction schedule ('none' disables it)
+                                if expression == "none" {
+                                    TaskerCompaction::override_schedule(collection, None);
 
-                                if StoreKVPool::backup(&path.join(BACKUP_KV_PATH)).is_ok()
-                                    && StoreFSTPool::backup(&path.join(BACKUP_FST_PATH)).is_ok()
+                                    Ok(vec![ChannelCommandResponse::Ok])
+                                } else if let Some(schedule) =
+                                    TaskerCompaction::parse_schedule(&expression)
                                 {
+                                    TaskerCompaction::override_schedule(collection, Some(schedule));
+
                                     Ok(vec![ChannelCommandResponse::Ok])
                                 } else {
-                                    Err(ChannelCommandError::InternalError)
+                                    Err(ChannelCommandError::PolicyReject("invalid schedule"))
                                 }
                             }
-                            _ => Err(ChannelCommandError::InvalidFormat("TRIGGER backup <path>")),
+                            _ => Err(ChannelCommandError::InvalidFormat(
+                                "TRIGGER compaction_schedule <collection> \"<cron_expr>\"",
+                            )),
                         }
                     }
-                    "restore" => {
+                    "import_stopwords" => {
+                        match (data_part, last_part, parts.next()) {
+                            (Some(locale), Some(path), None) => {
+                                // Import stopwords for locale from file (this extends built-in \
+                                //   stopwords, until next restart)
+                                if let Some(locale) = Lang::from_code(locale) {
+                                    LexerStopWord::import(locale, Path::new(path))
+                                        .map(|count| {
+                                            vec![ChannelCommandResponse::Result(count.to_string())]
+                                        })
+                                        .or(Err(ChannelCommandError::InternalError))
+                                } else {
+                                    Err(ChannelCommandError::PolicyReject("unknown locale"))
+                                }
+                            }
+                            _ => Err(ChannelCommandError::InvalidFormat(
+                                "TRIGGER import_stopwords <locale> <path>",
+                            )),
+                        }
+                    }
+                    "reload_synonym_map" => {
+                        if data_part.is_none() {
+                            // Reload synonyms from configuration (this replaces the live \
+                            //   synonyms map at once)
+                            LexerSynonyms::reload()
+                                .map(|_| vec![ChannelCommandResponse::Ok])
+                                .or(Err(ChannelCommandError::InternalError))
+                        } else {
+                            Err(ChannelCommandError::InvalidFormat(
+                                "TRIGGER reload_synonym_map",
+                            ))
+                        }
+                    }
+                    "profile" => {
+                        // Re-assemble command string from all remaining parts (it is quoted, and \
+                        //   may thus contain spaces)
+                        let profile_raw = data_part
+                            .into_iter()
+                            .chain(last_part)
+                            .chain(parts)
+                            .collect::<Vec<&str>>()
+                            .join(" ");
+
+                        match ChannelCommandBase::parse_text_parts(
+                            &mut profile_raw.split_whitespace(),
+                        )? {
+                            Some(command) => Self::handle_profile(&command),
+                            _ => Err(ChannelCommandError::InvalidFormat(
+                                "TRIGGER profile \"<command>\"",
+                            )),
+                        }
+                    }
+                    "set_log_level" => {
                         match (data_part, last_part) {
-                            (Some(path), None) => {
-                                // Proceed KV + FST restore
-                                let path = Path::new(path);
+                            (Some(level), None) => {
+                                // Change log level at runtime
+                                if let Ok(level_filter) = LevelFilter::from_str(level) {
+                                    log::set_max_level(level_filter);
+
+                                    info!("changed log level to: {}", level_filter);
 
-                                if StoreKVPool::restore(&path.join(BACKUP_KV_PATH)).is_ok()
-                                    && StoreFSTPool::restore(&path.join(BACKUP_FST_PATH)).is_ok()
-                                {
                                     Ok(vec![ChannelCommandResponse::Ok])
                                 } else {
-                                    Err(ChannelCommandError::InternalError)
+                                    Err(ChannelCommandError::PolicyReject("unknown log level"))
                                 }
                             }
-                            _ => Err(ChannelCommandError::InvalidFormat("TRIGGER restore <path>")),
+                            _ => Err(ChannelCommandError::InvalidFormat(
+                                "TRIGGER set_log_level <level>",
+                            )),
+                        }
+                    }
+                    "kill_connection" => {
+                        match (data_part, last_part) {
+                            (Some(peer_addr), None) => {
+                                // Terminate connection (as listed with 'CHANNEL LIST')
+                                if let Ok(peer_addr) = SocketAddr::from_str(peer_addr) {
+                                    if ChannelConnections::kill(&peer_addr) {
+                                        Ok(vec![ChannelCommandResponse::Ok])
+                                    } else {
+                                        Err(ChannelCommandError::NotFound)
+                                    }
+                                } else {
+                                    Err(ChannelCommandError::PolicyReject("invalid peer address"))
+                                }
+                            }
+                            _ => Err(ChannelCommandError::InvalidFormat(
+                                "TRIGGER kill_connection <peer_addr>",
+                            )),
                         }
                     }
                     _ => Err(ChannelCommandError::NotFound),
@@ -911,7 +3331,8 @@ impl ChannelCommandControl {
                 Ok(vec![ChannelCommandResponse::Result(format!(
                     "uptime({}) clients_connected({}) commands_total({}) \
                      command_latency_best({}) command_latency_worst({}) \
-                     kv_open_count({}) fst_open_count({}) fst_consolidate_count({})",
+                     kv_open_count({}) fst_open_count({}) fst_consolidate_count({}) \
+                     log_level({})",
                     statistics.uptime,
                     statistics.clients_connected,
                     statistics.commands_total,
@@ -919,16 +3340,197 @@ impl ChannelCommandControl {
                     statistics.command_latency_worst,
                     statistics.kv_open_count,
                     statistics.fst_open_count,
-                    statistics.fst_consolidate_count
+                    statistics.fst_consolidate_count,
+                    log::max_level().to_string().to_lowercase()
                 ))])
             }
-            _ => Err(ChannelCommandError::InvalidFormat("INFO")),
+            Some(subcommand) if parts.next().is_none() => {
+                match subcommand.to_lowercase().as_str() {
+                    "kv" => {
+                        let statistics = StoreKVPool::stats();
+
+                        Ok(vec![ChannelCommandResponse::Result(format!(
+                            "kv_open_count({}) kv_disk_bytes({}) kv_keys_estimate({})",
+                            statistics.collections, statistics.disk_bytes, statistics.keys
+                        ))])
+                    }
+                    "fst" => {
+                        let ((open_count, consolidate_count), statistics) =
+                            (StoreFSTPool::count(), StoreFSTPool::stats());
+
+                        Ok(vec![ChannelCommandResponse::Result(format!(
+                            "fst_open_count({}) fst_consolidate_count({}) fst_file_count({})",
+                            open_count, consolidate_count, statistics.disk_files
+                        ))])
+                    }
+                    _ => Err(ChannelCommandError::InvalidFormat("INFO [kv|fst]?")),
+                }
+            }
+            _ => Err(ChannelCommandError::InvalidFormat("INFO [kv|fst]?")),
+        }
+    }
+
+    pub fn dispatch_channel(mut parts: SplitWhitespace) -> ChannelResult {
+        match (parts.next(), parts.next()) {
+            (Some(action), None) if action.to_uppercase() == "LIST" => {
+                // List active connections, with their mode and idle time (connections that are \
+                //   not started yet have no mode)
+                Ok(ChannelConnections::list()
+                    .into_iter()
+                    .map(|connection| {
+                        ChannelCommandResponse::Result(format!(
+                            "{} mode({}) idle({})",
+                            connection
+                                .peer_addr
+                                .map(|peer_addr| peer_addr.to_string())
+                                .unwrap_or_else(|| "unknown".to_string()),
+                            connection.mode.map(|mode| mode.to_str()).unwrap_or("none"),
+                            connection
+                                .last_command_at
+                                .elapsed()
+                                .map(|idle| idle.as_secs())
+                                .unwrap_or(0)
+                        ))
+                    })
+                    .collect())
+            }
+            _ => Err(ChannelCommandError::InvalidFormat("CHANNEL LIST")),
         }
     }
 
     pub fn dispatch_help(parts: SplitWhitespace) -> ChannelResult {
         ChannelCommandBase::generic_dispatch_help(parts, &*MANUAL_MODE_CONTROL)
     }
+
+    fn handle_profile(command: &str) -> ChannelResult {
+        // Only read-only search commands can be profiled (as they get executed for real)
+        let command_name = command
+            .split_whitespace()
+            .next()
+            .unwrap_or("")
+            .to_uppercase();
+
+        if command_name != "QUERY" && command_name != "SUGGEST" {
+            return Err(ChannelCommandError::PolicyReject(
+                "only QUERY and SUGGEST can be profiled",
+            ));
+        }
+
+        debug!("will profile command: {}", command);
+
+        // Execute command through the search mode message handler, and time it
+        let (wall_start, cpu_start) = (Instant::now(), Self::thread_cpu_time());
+
+        let responses = ChannelMessageModeSearch::handle(command)?;
+
+        let (wall_took, cpu_took) = (
+            wall_start.elapsed(),
+            Self::thread_cpu_time().saturating_sub(cpu_start),
+        );
+
+        let result_count: usize = responses
+            .iter()
+            .map(|response| match response {
+                ChannelCommandResponse::Event(_, _, payload) => Self::count_event_results(payload),
+                _ => 0,
+            })
+            .sum();
+
+        info!(
+            "profiled command: {} in {}us wall and {}us cpu, with {} results",
+            command,
+            wall_took.as_micros(),
+            cpu_took.as_micros(),
+            result_count
+        );
+
+        Ok(vec![ChannelCommandResponse::Result(format!(
+            "wall_us({}) cpu_us({}) result_count({})",
+            wall_took.as_micros(),
+            cpu_took.as_micros(),
+            result_count
+        ))])
+    }
+
+    fn count_event_results(payload: &str) -> usize {
+        // Results are separated by spaces, though they may hold quoted text abstracts that \
+        //   contain spaces themselves (quotes in abstracts are escaped)
+        let (mut count, mut in_item, mut in_quote, mut escaped) = (0, false, false, false);
+
+        for character in payload.chars() {
+            if escaped {
+                escaped = false;
+            } else if in_quote {
+                match character {
+                    TEXT_PART_ESCAPE => escaped = true,
+                    TEXT_PART_BOUNDARY => in_quote = false,
+                    _ => {}
+                }
+            } else if character == ' ' {
+                in_item = false;
+            } else {
+                if !in_item {
+                    in_item = true;
+                    count += 1;
+                }
+
+                if character == TEXT_PART_BOUNDARY {
+                    in_quote = true;
+                }
+            }
+        }
+
+        count
+    }
+
+    #[cfg(unix)]
+    fn thread_cpu_time() -> Duration {
+        let mut time = libc::timespec {
+            tv_sec: 0,
+            tv_nsec: 0,
+        };
+
+        // Notice: this reads the CPU time consumed by the current thread only, as commands are \
+        //   executed synchronously on their channel thread.
+        if unsafe { libc::clock_gettime(libc::CLOCK_THREAD_CPUTIME_ID, &mut time) } == 0 {
+            Duration::new(time.tv_sec as u64, time.tv_nsec as u32)
+        } else {
+            Duration::ZERO
+        }
+    }
+
+    #[cfg(not(unix))]
+    fn thread_cpu_time() -> Duration {
+        // Notice: CPU time is not measured on non-UNIX platforms
+        Duration::ZERO
+    }
+
+    fn handle_verify_fst_meta(meta_result: MetaPartsResult) -> Result<bool, ChannelCommandError> {
+        match meta_result {
+            Ok((meta_key, meta_value)) => {
+                debug!("handle verify fst meta: {} = {}", meta_key, meta_value);
+
+                match meta_key {
+                    "REPAIR" => {
+                        // 'REPAIR(<repair>)' where <repair> ∈ {true, false}
+                        if let Ok(verify_repair_parsed) = meta_value.parse::<bool>() {
+                            Ok(verify_repair_parsed)
+                        } else {
+                            Err(ChannelCommandBase::make_error_invalid_meta_value(
+                                meta_key, meta_value,
+                            ))
+                        }
+                    }
+                    _ => Err(ChannelCommandBase::make_error_invalid_meta_key(
+                        meta_key, meta_value,
+                    )),
+                }
+            }
+            Err(err) => Err(ChannelCommandBase::make_error_invalid_meta_key(
+                err.0, err.1,
+            )),
+        }
+    }
 }
 
 impl fmt::Display for ChannelCommandError {
@@ -967,4 +3569,355 @@ mod tests {
             "ERR"
         );
     }
+
+    #[test]
+    fn it_dispatches_info_subcommands() {
+        let parse_info = |subcommand: &str| -> Vec<String> {
+            if let Ok(responses) =
+                ChannelCommandControl::dispatch_info(subcommand.split_whitespace())
+            {
+                if let [ChannelCommandResponse::Result(result)] = responses.as_slice() {
+                    return result
+                        .split_whitespace()
+                        .map(|part| {
+                            let (key, value) = part.trim_end_matches(')').split_once('(').unwrap();
+
+                            assert!(value.parse::<u64>().is_ok());
+
+                            key.to_string()
+                        })
+                        .collect();
+                }
+            }
+
+            panic!("invalid info response");
+        };
+
+        assert_eq!(
+            parse_info("kv"),
+            vec!["kv_open_count", "kv_disk_bytes", "kv_keys_estimate"]
+        );
+        assert_eq!(
+            parse_info("FST"),
+            vec!["fst_open_count", "fst_consolidate_count", "fst_file_count"]
+        );
+        assert!(ChannelCommandControl::dispatch_info("kv fst".split_whitespace()).is_err());
+        assert!(ChannelCommandControl::dispatch_info("unknown".split_whitespace()).is_err());
+    }
+
+    #[test]
+    fn it_parses_mux() {
+        assert_eq!(
+            ChannelCommandBase::parse_mux("req:1"),
+            Some("req:1".to_string())
+        );
+        assert_eq!(ChannelCommandBase::parse_mux(""), None);
+        assert_eq!(ChannelCommandBase::parse_mux(&"x".repeat(33)), None);
+    }
+
+    #[test]
+    fn it_parses_boost() {
+        assert_eq!(
+            ChannelCommandBase::parse_boost("Dog,2.5"),
+            Some(("dog".to_string(), 2.5))
+        );
+        assert_eq!(ChannelCommandBase::parse_boost("dog"), None);
+        assert_eq!(ChannelCommandBase::parse_boost(",2"), None);
+        assert_eq!(ChannelCommandBase::parse_boost("dog,0"), None);
+        assert_eq!(ChannelCommandBase::parse_boost("dog,-1"), None);
+        assert_eq!(ChannelCommandBase::parse_boost("dog,inf"), None);
+    }
+
+    #[test]
+    fn it_parses_term_weights() {
+        assert_eq!(
+            ChannelCommandBase::parse_term_weights("Car:3.0,red:0.5"),
+            Some(vec![("car".to_string(), 3.0), ("red".to_string(), 0.5)])
+        );
+        assert_eq!(ChannelCommandBase::parse_term_weights("car"), None);
+        assert_eq!(ChannelCommandBase::parse_term_weights(":2"), None);
+        assert_eq!(ChannelCommandBase::parse_term_weights("car:0"), None);
+        assert_eq!(ChannelCommandBase::parse_term_weights("car:2,car:3"), None);
+    }
+
+    #[test]
+    fn it_parses_buckets() {
+        assert_eq!(
+            ChannelCommandBase::parse_buckets("title,body"),
+            Some(vec!["title".to_string(), "body".to_string()])
+        );
+        assert_eq!(
+            ChannelCommandBase::parse_buckets("Title"),
+            Some(vec!["Title".to_string()])
+        );
+        assert_eq!(ChannelCommandBase::parse_buckets("title,"), None);
+    }
+
+    #[test]
+    fn it_parses_bucket_weights() {
+        assert_eq!(
+            ChannelCommandBase::parse_bucket_weights("title:3.0,body:0.5"),
+            Some(vec![("title".to_string(), 3.0), ("body".to_string(), 0.5)])
+        );
+        assert_eq!(ChannelCommandBase::parse_bucket_weights("title"), None);
+        assert_eq!(ChannelCommandBase::parse_bucket_weights("title:-1"), None);
+        assert_eq!(
+            ChannelCommandBase::parse_bucket_weights("title:2,title:3"),
+            None
+        );
+    }
+
+    #[test]
+    fn it_parses_checksum() {
+        let checksum = ChannelCommandBase::parse_checksum(
+            "2CF24DBA5FB0A30E26E83B2AC5B9E29E1B161E5C1FA7425E73043362938B9824",
+        );
+
+        assert_eq!(
+            checksum.map(|checksum| checksum[..2].to_vec()),
+            Some(vec![44, 242])
+        );
+        assert_eq!(
+            checksum.map(|checksum| checksum[..] == Sha256::digest(b"hello")[..]),
+            Some(true)
+        );
+        assert_eq!(ChannelCommandBase::parse_checksum("2cf24d"), None);
+        assert_eq!(ChannelCommandBase::parse_checksum(&"zz".repeat(32)), None);
+        assert_eq!(ChannelCommandBase::parse_checksum(&"é".repeat(32)), None);
+    }
+
+    #[test]
+    fn it_limits_batch_oid_length() {
+        assert!(
+            ChannelCommandIngest::dispatch_mpush(
+                format!("messages default {} \"hello\"", "a".repeat(257)).split_whitespace()
+            ) == Err(ChannelCommandError::PolicyReject("oid_too_long"))
+        );
+    }
+
+    #[test]
+    fn it_parses_text_parts_limited() {
+        assert!(
+            ChannelCommandBase::parse_text_parts_limited(
+                &mut format!("\"{}\"", "a".repeat(101)).split_whitespace(),
+                Some(100)
+            ) == Err(ChannelCommandError::PolicyReject("text_too_long"))
+        );
+        assert!(
+            ChannelCommandBase::parse_text_parts_limited(
+                &mut format!("\"{}\"", "a".repeat(99)).split_whitespace(),
+                Some(100)
+            ) == Ok(Some("a".repeat(99)))
+        );
+    }
+
+    #[test]
+    fn it_parses_context() {
+        assert_eq!(
+            ChannelCommandBase::parse_context("Search,engine"),
+            Some(vec!["search".to_string(), "engine".to_string()])
+        );
+        assert_eq!(ChannelCommandBase::parse_context(""), None);
+        assert_eq!(ChannelCommandBase::parse_context("search,,engine"), None);
+    }
+
+    #[test]
+    fn it_parses_geo() {
+        assert_eq!(
+            ChannelCommandBase::parse_geo("48.85,2.35"),
+            Some((48.85, 2.35))
+        );
+        assert_eq!(ChannelCommandBase::parse_geo("48.85"), None);
+        assert_eq!(ChannelCommandBase::parse_geo("91.0,2.35"), None);
+        assert_eq!(ChannelCommandBase::parse_geo("48.85,-181.0"), None);
+    }
+
+    #[test]
+    fn it_parses_within_distance() {
+        assert_eq!(
+            ChannelCommandBase::parse_within_distance("48.85,2.35,10"),
+            Some(((48.85, 2.35), 10.0))
+        );
+        assert_eq!(
+            ChannelCommandBase::parse_within_distance("48.85,2.35"),
+            None
+        );
+        assert_eq!(
+            ChannelCommandBase::parse_within_distance("48.85,2.35,0"),
+            None
+        );
+    }
+
+    #[test]
+    fn it_parses_penalty() {
+        assert_eq!(ChannelCommandBase::parse_penalty("0.25"), Some(0.25));
+        assert_eq!(ChannelCommandBase::parse_penalty("1"), Some(1.0));
+        assert_eq!(ChannelCommandBase::parse_penalty("0"), None);
+        assert_eq!(ChannelCommandBase::parse_penalty("1.5"), None);
+    }
+
+    #[test]
+    fn it_parses_shard() {
+        assert_eq!(ChannelCommandBase::parse_shard("0,4"), Some((0, 4)));
+        assert_eq!(ChannelCommandBase::parse_shard("3,4"), Some((3, 4)));
+        assert_eq!(ChannelCommandBase::parse_shard("4,4"), None);
+        assert_eq!(ChannelCommandBase::parse_shard("0,0"), None);
+        assert_eq!(ChannelCommandBase::parse_shard("0"), None);
+        assert_eq!(ChannelCommandBase::parse_shard("0, 4"), None);
+    }
+
+    #[test]
+    fn it_parses_tags() {
+        assert_eq!(
+            ChannelCommandBase::parse_tags("status:published,category:books,status:published"),
+            Some(vec![
+                "category:books".to_string(),
+                "status:published".to_string()
+            ])
+        );
+        assert_eq!(ChannelCommandBase::parse_tags(""), None);
+        assert_eq!(ChannelCommandBase::parse_tags("category:books,"), None);
+    }
+
+    #[test]
+    fn it_parses_must_not() {
+        assert_eq!(
+            ChannelCommandBase::parse_must_not("\"trucks\""),
+            Some("trucks".to_string())
+        );
+        assert_eq!(
+            ChannelCommandBase::parse_must_not("trucks,vans"),
+            Some("trucks,vans".to_string())
+        );
+        assert_eq!(ChannelCommandBase::parse_must_not("\"\""), None);
+        assert_eq!(ChannelCommandBase::parse_must_not("\"trucks"), None);
+    }
+
+    #[test]
+    fn it_parses_vector() {
+        assert_eq!(
+            ChannelCommandBase::parse_vector("3,4"),
+            Some(vec![0.6, 0.8])
+        );
+        assert_eq!(ChannelCommandBase::parse_vector("0.5"), Some(vec![1.0]));
+        assert_eq!(ChannelCommandBase::parse_vector("0,0"), None);
+        assert_eq!(ChannelCommandBase::parse_vector("0.1,,0.2"), None);
+        assert_eq!(ChannelCommandBase::parse_vector("0.1,inf"), None);
+    }
+
+    #[test]
+    fn it_ensures_pending_item() {
+        assert!(ChannelCommandBase::ensure_pending_item("c:test:1", "b:test:1") == Ok(()));
+        assert!(
+            ChannelCommandBase::ensure_pending_item("", "b:test:1")
+                == Err(ChannelCommandError::QueryError)
+        );
+        assert!(
+            ChannelCommandBase::ensure_pending_item("c:test:1", &"b".repeat(300))
+                == Err(ChannelCommandError::QueryError)
+        );
+    }
+
+    #[test]
+    fn it_counts_event_results() {
+        assert_eq!(ChannelCommandControl::count_event_results(""), 0);
+        assert_eq!(
+            ChannelCommandControl::count_event_results("conversation:1 conversation:2"),
+            2
+        );
+        assert_eq!(
+            ChannelCommandControl::count_event_results(
+                "conversation:1(\"the \\\"quick\\\" fox\") conversation:2(\"lazy dog\")"
+            ),
+            2
+        );
+    }
+
+    #[test]
+    fn it_parses_phrases() {
+        assert_eq!(
+            ChannelCommandSearch::parse_phrases("cat dog".to_string()),
+            ("cat dog".to_string(), vec![])
+        );
+        assert_eq!(
+            ChannelCommandSearch::parse_phrases("the [new york] times".to_string()),
+            (
+                "the new york times".to_string(),
+                vec!["new york".to_string()]
+            )
+        );
+        assert_eq!(
+            ChannelCommandSearch::parse_phrases("'quick brown fox' and dog's [bowl".to_string()),
+            (
+                "quick brown fox and dog's bowl".to_string(),
+                vec!["quick brown fox".to_string()]
+            )
+        );
+    }
+
+    #[test]
+    fn it_parses_operators() {
+        assert_eq!(
+            ChannelCommandSearch::parse_operators("cat dog".to_string()),
+            ("cat dog".to_string(), vec![], vec![])
+        );
+        assert_eq!(
+            ChannelCommandSearch::parse_operators("cat | Dog's bowl".to_string()),
+            (
+                "cat Dog's bowl".to_string(),
+                vec!["dog's".to_string()],
+                vec![]
+            )
+        );
+        assert_eq!(
+            ChannelCommandSearch::parse_operators("| cat | dog |".to_string()),
+            (
+                "cat dog".to_string(),
+                vec!["cat".to_string(), "dog".to_string()],
+                vec![]
+            )
+        );
+        assert_eq!(
+            ChannelCommandSearch::parse_operators("quick !dog ! fox".to_string()),
+            ("quick ! fox".to_string(), vec![], vec!["dog".to_string()])
+        );
+    }
+
+    #[test]
+    fn it_parses_batch_pairs() {
+        let mut parts = "o:1 \"hello world\" o:2 \"bye\" LANG(eng)".split_whitespace();
+
+        assert_eq!(
+            ChannelCommandIngest::parse_batch_pairs(&mut parts).ok(),
+            Some(vec![
+                ("o:1", "hello world".to_string()),
+                ("o:2", "bye".to_string())
+            ])
+        );
+        assert_eq!(parts.next(), Some("LANG(eng)"));
+
+        assert_eq!(
+            ChannelCommandIngest::parse_batch_pairs(&mut "LANG(eng)".split_whitespace()).ok(),
+            Some(vec![])
+        );
+    }
+
+    #[test]
+    fn it_parses_weight() {
+        assert_eq!(ChannelCommandBase::parse_weight("0.9"), Some(0.9));
+        assert_eq!(ChannelCommandBase::parse_weight("1"), Some(1.0));
+        assert_eq!(ChannelCommandBase::parse_weight("1.5"), None);
+        assert_eq!(ChannelCommandBase::parse_weight("-0.1"), None);
+        assert_eq!(ChannelCommandBase::parse_weight("NaN"), None);
+    }
+
+    #[test]
+    fn it_parses_docid() {
+        assert!(matches!(
+            ChannelCommandIngest::handle_push_meta(Ok(("DOCID", "4294967294"))),
+            Ok(PushMetaData::DocID(4294967294))
+        ));
+        assert!(ChannelCommandIngest::handle_push_meta(Ok(("DOCID", "4294967295"))).is_err());
+        assert!(ChannelCommandIngest::handle_push_meta(Ok(("DOCID", "-1"))).is_err());
+    }
 }
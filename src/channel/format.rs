@@ -4,6 +4,9 @@
 // Copyright: 2019, Valerian Saliou <valerian@valeriansaliou.name>
 // License: Mozilla Public License v2.0 (MPL v2.0)
 
+const CURSOR_ALPHABET: &[u8; 64] =
+    b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789-_";
+
 pub fn unescape(text: &str) -> String {
     // Pre-reserve a byte-aware required capacity as to avoid heap resizes (30% performance \
     //   gain relative to initializing this with a zero-capacity)
@@ -26,6 +29,57 @@ pub fn unescape(text: &str) -> String {
     unescaped
 }
 
+pub fn escape(text: &str) -> String {
+    // Escape characters that would otherwise be unescaped, or that would end the response line \
+    //   (this is the reverse of 'unescape')
+    let mut escaped = String::with_capacity(text.len());
+
+    for character in text.chars() {
+        match character {
+            '\\' => escaped.push_str("\\\\"),
+            '"' => escaped.push_str("\\\""),
+            '\n' => escaped.push_str("\\n"),
+            _ => escaped.push(character),
+        }
+    }
+
+    escaped
+}
+
+pub fn encode_cursor(iid: u32) -> String {
+    // Encode IID bytes as URL-safe base64 without padding (4 bytes encode as 6 characters)
+    let (value, mut cursor) = ((iid as u64) << 4, String::with_capacity(6));
+
+    for index in (0..6).rev() {
+        cursor.push(CURSOR_ALPHABET[((value >> (index * 6)) & 0x3f) as usize] as char);
+    }
+
+    cursor
+}
+
+pub fn decode_cursor(cursor: &str) -> Option<u32> {
+    if cursor.len() != 6 {
+        return None;
+    }
+
+    let mut value: u64 = 0;
+
+    for character in cursor.bytes() {
+        let position = CURSOR_ALPHABET
+            .iter()
+            .position(|alphabet_character| *alphabet_character == character)?;
+
+        value = (value << 6) | position as u64;
+    }
+
+    // Ensure padding bits are unset (otherwise, the cursor was not encoded by us)
+    if value & 0xf == 0 {
+        Some((value >> 4) as u32)
+    } else {
+        None
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -42,6 +96,33 @@ mod tests {
             r#"look at \\"\" me i'm \""trying to hack you""#.to_string()
         );
     }
+
+    #[test]
+    fn it_escapes_response_text() {
+        assert_eq!(escape("hello world!"), "hello world!");
+        assert_eq!(escape("say \"hi\"\nto C:\\"), r#"say \"hi\"\nto C:\\"#);
+
+        for text in ["hello world!", "say \"hi\"\nto C:\\", "\\\"\\n"] {
+            assert_eq!(unescape(&escape(text)), text);
+        }
+    }
+
+    #[test]
+    fn it_encodes_cursor() {
+        assert_eq!(encode_cursor(0), "AAAAAA");
+        assert_eq!(encode_cursor(u32::MAX), "_____w");
+        assert_eq!(decode_cursor(&encode_cursor(0)), Some(0));
+        assert_eq!(decode_cursor(&encode_cursor(42)), Some(42));
+        assert_eq!(decode_cursor(&encode_cursor(u32::MAX)), Some(u32::MAX));
+    }
+
+    #[test]
+    fn it_decodes_cursor() {
+        assert_eq!(decode_cursor("AAAAAA"), Some(0));
+        assert_eq!(decode_cursor("AAAAAB"), None);
+        assert_eq!(decode_cursor("AAAA"), None);
+        assert_eq!(decode_cursor("AAAAA="), None);
+    }
 }
 
 #[cfg(all(feature = "benchmark", test))]
@@ -0,0 +1,346 @@
+// Sonic
+//
+// Fast, lightweight and schema-less search backend
+// Copyright: 2019, Valerian Saliou <valerian@valeriansaliou.name>
+// License: Mozilla Public License v2.0 (MPL v2.0)
+
+// Notice: gRPC status errors are large, though this is how the gRPC framework reports errors.
+#![allow(clippy::result_large_err)]
+
+use std::process;
+use std::str::SplitWhitespace;
+use tonic::transport::Server;
+use tonic::{Request, Response, Status};
+
+use super::command::{
+    ChannelCommandBase, ChannelCommandControl, ChannelCommandError, ChannelCommandResponse,
+    ChannelResult,
+};
+use super::listen::CHANNEL_AVAILABLE;
+use super::mode::ChannelMode;
+use crate::config::options::ConfigChannel;
+use crate::query::grpc::{QueryGRPC, QueryGRPCResult};
+use crate::store::operation::StoreOperationDispatch;
+use crate::APP_CONF;
+
+use proto::sonic_server::{Sonic, SonicServer};
+use proto::{
+    CountReply, CountRequest, FlushBucketRequest, FlushCollectionRequest, FlushObjectRequest,
+    InfoReply, InfoRequest, PopRequest, PushReply, PushRequest, QueryReply, QueryRequest,
+    SuggestReply, SuggestRequest, TriggerReply, TriggerRequest,
+};
+
+pub mod proto {
+    #![allow(clippy::all, unused_qualifications)]
+
+    tonic::include_proto!("sonic");
+}
+
+pub struct ChannelGRPCBuilder;
+pub struct ChannelGRPC;
+
+struct ChannelGRPCService;
+
+type ChannelGRPCResult<T> = Result<Response<T>, Status>;
+
+const GRPC_AUTHORIZATION_KEY: &str = "authorization";
+
+impl ChannelGRPCBuilder {
+    pub fn build() -> ChannelGRPC {
+        ChannelGRPC {}
+    }
+}
+
+impl ChannelGRPC {
+    pub fn run(&self) {
+        if let Some(ref grpc) = APP_CONF.channel.grpc {
+            // Notice: the gRPC server runs on its own asynchronous runtime, while executors are \
+            //   blocking; thus each request gets dispatched on the runtime blocking pool.
+            let runtime = tokio::runtime::Builder::new_multi_thread()
+                .enable_all()
+                .build();
+
+            match runtime {
+                Ok(runtime) => {
+                    info!("listening on grpc://{}", grpc.listen_addr);
+
+                    let serve = runtime.block_on(
+                        Server::builder()
+                            .add_service(SonicServer::new(ChannelGRPCService))
+                            .serve(grpc.listen_addr),
+                    );
+
+                    if let Err(err) = serve {
+                        error!("error serving grpc channel: {}", err);
+
+                        // Exit Sonic
+                        process::exit(1);
+                    }
+                }
+                Err(err) => {
+                    error!("error building grpc channel runtime: {}", err);
+
+                    // Exit Sonic
+                    process::exit(1);
+                }
+            }
+        }
+    }
+}
+
+impl ChannelGRPCService {
+    async fn dispatch_query<R: Send + 'static>(
+        request: Request<R>,
+        mode: ChannelMode,
+        fn_build: for<'a> fn(&'a str, &'a R) -> QueryGRPCResult<'a>,
+    ) -> Result<Option<String>, Status> {
+        Self::authenticate(&request, mode)?;
+        Self::ensure_available()?;
+
+        let (request, query_id) = (
+            request.into_inner(),
+            ChannelCommandBase::generate_event_id(),
+        );
+
+        tokio::task::spawn_blocking(move || {
+            let query = fn_build(&query_id, &request).map_err(Status::invalid_argument)?;
+
+            StoreOperationDispatch::dispatch(query).or(Err(Status::internal("query error")))
+        })
+        .await
+        .or(Err(Status::internal("dispatch error")))?
+    }
+
+    async fn dispatch_control(
+        command: String,
+        fn_dispatch: fn(SplitWhitespace) -> ChannelResult,
+    ) -> Result<Option<String>, Status> {
+        Self::ensure_available()?;
+
+        tokio::task::spawn_blocking(move || match fn_dispatch(command.split_whitespace()) {
+            Ok(responses) => Ok(responses.into_iter().find_map(|response| match response {
+                ChannelCommandResponse::Result(result) => Some(result),
+                _ => None,
+            })),
+            Err(err) => Err(Self::make_status(err)),
+        })
+        .await
+        .or(Err(Status::internal("dispatch error")))?
+    }
+
+    fn authenticate<R>(request: &Request<R>, mode: ChannelMode) -> Result<(), Status> {
+        let provided_password = request
+            .metadata()
+            .get(GRPC_AUTHORIZATION_KEY)
+            .and_then(|value| value.to_str().ok());
+
+        if Self::is_authorized(&APP_CONF.channel, mode, provided_password) {
+            Ok(())
+        } else {
+            info!("grpc client provided invalid authentication");
+
+            Err(Status::unauthenticated("authentication failed"))
+        }
+    }
+
+    fn is_authorized(
+        channel: &ConfigChannel,
+        mode: ChannelMode,
+        provided_password: Option<&str>,
+    ) -> bool {
+        // Check provided password against configured password for the mode that the RPC maps \
+        //   to (if any), as Sonic Channel does upon 'START'
+        match mode.auth_password(channel) {
+            Some(auth_password) => provided_password == Some(auth_password),
+            None => true,
+        }
+    }
+
+    fn ensure_available() -> Result<(), Status> {
+        if *CHANNEL_AVAILABLE.read().unwrap() {
+            Ok(())
+        } else {
+            Err(Status::unavailable("shutting down"))
+        }
+    }
+
+    fn make_status(err: ChannelCommandError) -> Status {
+        match err {
+            ChannelCommandError::NotFound => Status::not_found(err.to_string()),
+            ChannelCommandError::ShuttingDown => Status::unavailable(err.to_string()),
+            ChannelCommandError::QueryError | ChannelCommandError::InternalError => {
+                Status::internal(err.to_string())
+            }
+            _ => Status::invalid_argument(err.to_string()),
+        }
+    }
+
+    fn make_count(result: Option<String>) -> CountReply {
+        CountReply {
+            count: result
+                .and_then(|result| result.parse::<u32>().ok())
+                .unwrap_or(0),
+        }
+    }
+
+    fn make_list(result: Option<String>) -> Vec<String> {
+        result
+            .map(|result| result.split_whitespace().map(String::from).collect())
+            .unwrap_or_default()
+    }
+}
+
+#[tonic::async_trait]
+impl Sonic for ChannelGRPCService {
+    async fn query(&self, request: Request<QueryRequest>) -> ChannelGRPCResult<QueryReply> {
+        let result = Self::dispatch_query(request, ChannelMode::Search, QueryGRPC::query).await?;
+
+        Ok(Response::new(QueryReply {
+            objects: Self::make_list(result),
+        }))
+    }
+
+    async fn suggest(&self, request: Request<SuggestRequest>) -> ChannelGRPCResult<SuggestReply> {
+        let result = Self::dispatch_query(request, ChannelMode::Search, QueryGRPC::suggest).await?;
+
+        Ok(Response::new(SuggestReply {
+            words: Self::make_list(result),
+        }))
+    }
+
+    async fn push(&self, request: Request<PushRequest>) -> ChannelGRPCResult<PushReply> {
+        let result = Self::dispatch_query(request, ChannelMode::Ingest, QueryGRPC::push).await?;
+
+        // Notice: a push yields a result only if it got rejected, in which case the result \
+        //   holds the reject reason (eg. its vector dimension does not match)
+        if let Some(reason) = result {
+            Err(Status::invalid_argument(reason))
+        } else {
+            Ok(Response::new(PushReply {}))
+        }
+    }
+
+    async fn pop(&self, request: Request<PopRequest>) -> ChannelGRPCResult<CountReply> {
+        let result = Self::dispatch_query(request, ChannelMode::Ingest, QueryGRPC::pop).await?;
+
+        Ok(Response::new(Self::make_count(result)))
+    }
+
+    async fn count(&self, request: Request<CountRequest>) -> ChannelGRPCResult<CountReply> {
+        let result = Self::dispatch_query(request, ChannelMode::Ingest, QueryGRPC::count).await?;
+
+        Ok(Response::new(Self::make_count(result)))
+    }
+
+    async fn flush_collection(
+        &self,
+        request: Request<FlushCollectionRequest>,
+    ) -> ChannelGRPCResult<CountReply> {
+        let result =
+            Self::dispatch_query(request, ChannelMode::Ingest, QueryGRPC::flush_collection).await?;
+
+        Ok(Response::new(Self::make_count(result)))
+    }
+
+    async fn flush_bucket(
+        &self,
+        request: Request<FlushBucketRequest>,
+    ) -> ChannelGRPCResult<CountReply> {
+        let result =
+            Self::dispatch_query(request, ChannelMode::Ingest, QueryGRPC::flush_bucket).await?;
+
+        Ok(Response::new(Self::make_count(result)))
+    }
+
+    async fn flush_object(
+        &self,
+        request: Request<FlushObjectRequest>,
+    ) -> ChannelGRPCResult<CountReply> {
+        let result =
+            Self::dispatch_query(request, ChannelMode::Ingest, QueryGRPC::flush_object).await?;
+
+        Ok(Response::new(Self::make_count(result)))
+    }
+
+    async fn info(&self, request: Request<InfoRequest>) -> ChannelGRPCResult<InfoReply> {
+        Self::authenticate(&request, ChannelMode::Control)?;
+
+        let result =
+            Self::dispatch_control(String::new(), ChannelCommandControl::dispatch_info).await?;
+
+        Ok(Response::new(InfoReply {
+            info: result.unwrap_or_default(),
+        }))
+    }
+
+    async fn trigger(&self, request: Request<TriggerRequest>) -> ChannelGRPCResult<TriggerReply> {
+        Self::authenticate(&request, ChannelMode::Control)?;
+
+        let request = request.into_inner();
+
+        // Re-use Sonic Channel trigger parser (ie. '<action> [<data>]?')
+        let command = format!("{} {}", request.action, request.data.unwrap_or_default());
+
+        let result =
+            Self::dispatch_control(command, ChannelCommandControl::dispatch_trigger).await?;
+
+        Ok(Response::new(TriggerReply { result }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_authorizes_per_mode() {
+        let mut channel: ConfigChannel = toml::from_str("[search]\n").unwrap();
+
+        assert!(ChannelGRPCService::is_authorized(
+            &channel,
+            ChannelMode::Ingest,
+            None
+        ));
+
+        channel.auth_password_ingest = Some("ingest".to_string());
+
+        assert!(!ChannelGRPCService::is_authorized(
+            &channel,
+            ChannelMode::Ingest,
+            None
+        ));
+        assert!(!ChannelGRPCService::is_authorized(
+            &channel,
+            ChannelMode::Ingest,
+            Some("search")
+        ));
+        assert!(ChannelGRPCService::is_authorized(
+            &channel,
+            ChannelMode::Ingest,
+            Some("ingest")
+        ));
+        assert!(ChannelGRPCService::is_authorized(
+            &channel,
+            ChannelMode::Search,
+            None
+        ));
+
+        channel.auth_password = Some("generic".to_string());
+
+        assert!(!ChannelGRPCService::is_authorized(
+            &channel,
+            ChannelMode::Control,
+            None
+        ));
+        assert!(ChannelGRPCService::is_authorized(
+            &channel,
+            ChannelMode::Control,
+            Some("generic")
+        ));
+        assert!(!ChannelGRPCService::is_authorized(
+            &channel,
+            ChannelMode::Ingest,
+            Some("generic")
+        ));
+    }
+}
@@ -4,24 +4,35 @@
 // Copyright: 2019, Valerian Saliou <valerian@valeriansaliou.name>
 // License: Mozilla Public License v2.0 (MPL v2.0)
 
+use rustls::{ServerConfig, ServerConnection, StreamOwned};
 use std::collections::VecDeque;
-use std::io::{ErrorKind, Read, Write};
+use std::io::{self, ErrorKind, Read, Write};
 use std::net::TcpStream;
+#[cfg(unix)]
+use std::os::unix::net::UnixStream;
 use std::result::Result;
 use std::str;
+use std::sync::Arc;
 use std::time::Duration;
 
+use super::command::ChannelCommandError;
 use super::message::{
     ChannelMessage, ChannelMessageModeControl, ChannelMessageModeIngest, ChannelMessageModeSearch,
     ChannelMessageResult,
 };
 use super::mode::ChannelMode;
-use super::statistics::CLIENTS_CONNECTED;
+use super::ratelimiter::ChannelRateLimiter;
+use super::statistics::{ChannelConnectionGuard, ChannelConnections, CLIENTS_CONNECTED};
 use crate::APP_CONF;
 use crate::LINE_FEED;
 
 pub struct ChannelHandle;
 
+trait ChannelStream: Read + Write {
+    fn set_timeouts(&self, timeout: Duration) -> io::Result<()>;
+    fn try_clone_writer(&self) -> Option<Box<dyn Write + Send>>;
+}
+
 enum ChannelHandleError {
     Closed,
     InvalidMode,
@@ -31,6 +42,7 @@ enum ChannelHandleError {
     TimedOut,
     ConnectionAborted,
     Interrupted,
+    ServerShutdown,
     Unknown,
 }
 
@@ -60,25 +72,105 @@ impl ChannelHandleError {
             ChannelHandleError::TimedOut => "timed_out",
             ChannelHandleError::ConnectionAborted => "connection_aborted",
             ChannelHandleError::Interrupted => "interrupted",
+            ChannelHandleError::ServerShutdown => "server_shutdown",
             ChannelHandleError::Unknown => "unknown",
         }
     }
 }
 
+impl ChannelStream for TcpStream {
+    fn set_timeouts(&self, timeout: Duration) -> io::Result<()> {
+        self.set_read_timeout(Some(timeout))?;
+        self.set_write_timeout(Some(timeout))
+    }
+
+    fn try_clone_writer(&self) -> Option<Box<dyn Write + Send>> {
+        self.try_clone()
+            .ok()
+            .map(|stream| Box::new(stream) as Box<dyn Write + Send>)
+    }
+}
+
+impl ChannelStream for StreamOwned<ServerConnection, TcpStream> {
+    fn set_timeouts(&self, timeout: Duration) -> io::Result<()> {
+        self.get_ref().set_timeouts(timeout)
+    }
+
+    fn try_clone_writer(&self) -> Option<Box<dyn Write + Send>> {
+        // Notice: the TLS session state cannot be shared with another thread, thus search \
+        //   executors run inline on TLS connections.
+        None
+    }
+}
+
+#[cfg(unix)]
+impl ChannelStream for UnixStream {
+    fn set_timeouts(&self, timeout: Duration) -> io::Result<()> {
+        self.set_read_timeout(Some(timeout))?;
+        self.set_write_timeout(Some(timeout))
+    }
+
+    fn try_clone_writer(&self) -> Option<Box<dyn Write + Send>> {
+        self.try_clone()
+            .ok()
+            .map(|stream| Box::new(stream) as Box<dyn Write + Send>)
+    }
+}
+
 impl ChannelHandle {
-    pub fn client(mut stream: TcpStream) {
+    pub fn client(stream: TcpStream, tls_config: Option<Arc<ServerConfig>>) {
+        assert!(stream.set_nodelay(true).is_ok());
+
+        // Register active connection (it gets deregistered once closed)
+        let connection =
+            ChannelConnections::register(stream.peer_addr().ok(), stream.try_clone().ok());
+
+        // Wrap stream in a TLS session? (if enabled; the TLS handshake happens upon first \
+        //   write, that is, when sending the connected banner)
+        if let Some(tls_config) = tls_config {
+            match ServerConnection::new(tls_config) {
+                Ok(tls_session) => Self::serve(StreamOwned::new(tls_session, stream), &connection),
+                Err(err) => error!("could not create channel tls session: {}", err),
+            }
+        } else {
+            Self::serve(stream, &connection);
+        }
+    }
+
+    #[cfg(unix)]
+    pub fn client_unix(stream: UnixStream) {
+        // Register active connection (Unix socket peers have no address, thus they cannot be \
+        //   killed by address)
+        let connection = ChannelConnections::register(None, None);
+
+        Self::serve(stream, &connection);
+    }
+
+    fn serve<S: ChannelStream>(mut stream: S, connection: &ChannelConnectionGuard) {
         // Configure stream (non-established)
         ChannelHandle::configure_stream(&stream, false);
 
-        // Send connected banner
-        write!(stream, "{}{}", *CONNECTED_BANNER, LINE_FEED).expect("write failed");
-
         // Increment connected clients count
         *CLIENTS_CONNECTED.write().unwrap() += 1;
 
+        // Send connected banner
+        // Notice: this fails if the TLS handshake fails (eg. plaintext client), in which case \
+        //   the connection gets closed right away.
+        match write!(stream, "{}{}", *CONNECTED_BANNER, LINE_FEED) {
+            Ok(_) => Self::start_stream(stream, connection),
+            Err(err) => info!("could not send channel connected banner: {}", err),
+        }
+
+        // Decrement connected clients count
+        *CLIENTS_CONNECTED.write().unwrap() -= 1;
+    }
+
+    fn start_stream<S: ChannelStream>(mut stream: S, connection: &ChannelConnectionGuard) {
         // Ensure channel mode is set
-        match Self::ensure_start(&stream) {
+        match Self::ensure_start(&mut stream) {
             Ok(mode) => {
+                connection.set_mode(mode.clone());
+
                 // Configure stream (established)
                 ChannelHandle::configure_stream(&stream, true);
 
@@ -93,46 +185,62 @@ impl ChannelHandle {
                 )
                 .expect("write failed");
 
-                Self::handle_stream(mode, stream);
+                Self::handle_stream(mode, stream, connection);
             }
             Err(err) => {
                 write!(stream, "ENDED {}{}", err.to_str(), LINE_FEED).expect("write failed");
             }
         }
-
-        // Decrement connected clients count
-        *CLIENTS_CONNECTED.write().unwrap() -= 1;
     }
 
-    fn configure_stream(stream: &TcpStream, is_established: bool) {
+    fn configure_stream<S: ChannelStream>(stream: &S, is_established: bool) {
         let tcp_timeout = if is_established {
             APP_CONF.channel.tcp_timeout
         } else {
             TCP_TIMEOUT_NON_ESTABLISHED
         };
 
-        assert!(stream.set_nodelay(true).is_ok());
-
-        assert!(stream
-            .set_read_timeout(Some(Duration::new(tcp_timeout, 0)))
-            .is_ok());
-        assert!(stream
-            .set_write_timeout(Some(Duration::new(tcp_timeout, 0)))
-            .is_ok());
+        assert!(stream.set_timeouts(Duration::new(tcp_timeout, 0)).is_ok());
     }
 
-    fn handle_stream(mode: ChannelMode, mut stream: TcpStream) {
+    fn handle_stream<S: ChannelStream>(
+        mode: ChannelMode,
+        mut stream: S,
+        connection: &ChannelConnectionGuard,
+    ) {
+        // Acquire peer IP address (used for rate limiting)
+        let peer_ip = connection.peer_addr().map(|peer_addr| peer_addr.ip());
+
         // Initialize packet buffer
         let mut buffer: VecDeque<u8> = VecDeque::with_capacity(MAX_LINE_SIZE);
 
+        // Bind event writer? (search executors then run on the executor pool, which sends \
+        //   events to the connection writer thread once done, while further commands get \
+        //   handled meanwhile)
+        if let ChannelMode::Search = mode {
+            ChannelMessage::bind_event_writer(stream.try_clone_writer());
+        }
+
         // Wait for incoming messages
         'handler: loop {
+            // Connection killed? (from a control channel)
+            if connection.is_killed() {
+                ChannelMessage::end(&mut stream, ChannelHandleError::ServerShutdown.to_str());
+
+                break;
+            }
+
             let mut read = [0; MAX_LINE_SIZE];
 
             match stream.read(&mut read) {
                 Ok(n) => {
-                    // Should close?
+                    // Should close? (killed connections get their read half shutdown, thus \
+                    //   they first loop back to notify the client)
                     if n == 0 {
+                        if connection.is_killed() {
+                            continue;
+                        }
+
                         break;
                     }
 
@@ -160,7 +268,15 @@ impl ChannelHandle {
                         while let Some(byte) = buffer.pop_front() {
                             // Commit line and start a new one?
                             if byte == BUFFER_LINE_SEPARATOR {
-                                if Self::on_message(&mode, &stream, &processed_line)
+                                connection.touch();
+
+                                // Rate limit exceeded? Reject command, but keep connection open.
+                                if !ChannelRateLimiter::allow(peer_ip) {
+                                    ChannelMessage::reject(
+                                        &mut stream,
+                                        ChannelCommandError::PolicyReject("rate_limit_exceeded"),
+                                    );
+                                } else if Self::on_message(&mode, &mut stream, &processed_line)
                                     == ChannelMessageResult::Close
                                 {
                                     // Should close?
@@ -183,6 +299,12 @@ impl ChannelHandle {
                     }
                 }
                 Err(err) => {
+                    // Killed connection? (TLS sessions fail reading from the shutdown read \
+                    //   half, thus they also loop back to notify the client)
+                    if connection.is_killed() {
+                        continue;
+                    }
+
                     error!("closing channel thread with traceback: {}", err);
 
                     panic!("closing channel");
@@ -191,7 +313,7 @@ impl ChannelHandle {
         }
     }
 
-    fn ensure_start(mut stream: &TcpStream) -> Result<ChannelMode, ChannelHandleError> {
+    fn ensure_start<S: ChannelStream>(stream: &mut S) -> Result<ChannelMode, ChannelHandleError> {
         #[allow(clippy::never_loop)]
         loop {
             let mut read = [0; MAX_LINE_SIZE];
@@ -211,7 +333,7 @@ impl ChannelHandle {
                             // Extract mode
                             if let Ok(mode) = ChannelMode::from_str(res_mode) {
                                 // Check if authenticated?
-                                if let Some(ref auth_password) = APP_CONF.channel.auth_password {
+                                if let Some(auth_password) = mode.auth_password(&APP_CONF.channel) {
                                     if let Some(provided_auth) = parts.next() {
                                         // Compare provided password with configured password
                                         if provided_auth != auth_password {
@@ -250,20 +372,20 @@ impl ChannelHandle {
         }
     }
 
-    fn on_message(
+    fn on_message<S: ChannelStream>(
         mode: &ChannelMode,
-        stream: &TcpStream,
+        stream: &mut S,
         message_slice: &[u8],
     ) -> ChannelMessageResult {
         match mode {
             ChannelMode::Search => {
-                ChannelMessage::on::<ChannelMessageModeSearch>(stream, message_slice)
+                ChannelMessage::on::<ChannelMessageModeSearch, _>(stream, message_slice)
             }
             ChannelMode::Ingest => {
-                ChannelMessage::on::<ChannelMessageModeIngest>(stream, message_slice)
+                ChannelMessage::on::<ChannelMessageModeIngest, _>(stream, message_slice)
             }
             ChannelMode::Control => {
-                ChannelMessage::on::<ChannelMessageModeControl>(stream, message_slice)
+                ChannelMessage::on::<ChannelMessageModeControl, _>(stream, message_slice)
             }
         }
     }
@@ -4,21 +4,32 @@
 // Copyright: 2019, Valerian Saliou <valerian@valeriansaliou.name>
 // License: Mozilla Public License v2.0 (MPL v2.0)
 
+use rustls::ServerConfig;
+use std::fs::{self, File};
+use std::io::{BufReader, Write};
 use std::net::TcpListener;
+#[cfg(unix)]
+use std::os::unix::net::UnixListener;
+use std::path::Path;
 use std::process;
-use std::sync::RwLock;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, RwLock};
 use std::thread;
 
 use super::handle::ChannelHandle;
-use crate::{APP_CONF, THREAD_NAME_CHANNEL_CLIENT};
+use crate::{APP_CONF, LINE_FEED, THREAD_NAME_CHANNEL_CLIENT};
 
 pub struct ChannelListenBuilder;
 pub struct ChannelListen;
 
+struct ChannelListenSlot;
+
 lazy_static! {
     pub static ref CHANNEL_AVAILABLE: RwLock<bool> = RwLock::new(true);
 }
 
+static CLIENTS_ADMITTED: AtomicUsize = AtomicUsize::new(0);
+
 impl ChannelListenBuilder {
     pub fn build() -> ChannelListen {
         ChannelListen {}
@@ -27,24 +38,117 @@ impl ChannelListenBuilder {
 
 impl ChannelListen {
     pub fn run(&self) {
+        // Listen on Unix domain socket? (instead of TCP)
+        if let Some(ref unix_socket_path) = APP_CONF.channel.unix_socket_path {
+            Self::run_unix(unix_socket_path);
+        } else {
+            Self::run_tcp();
+        }
+    }
+
+    fn run_tcp() {
+        // Acquire TLS configuration? (if enabled, all connections get encrypted)
+        let tls_config = match (&APP_CONF.channel.tls_cert, &APP_CONF.channel.tls_key) {
+            (Some(cert_path), Some(key_path)) => {
+                if let Ok(tls_config) = Self::make_tls_config(cert_path, key_path) {
+                    Some(tls_config)
+                } else {
+                    error!("error loading channel tls certificate or key");
+
+                    // Exit Sonic
+                    process::exit(1);
+                }
+            }
+            (None, None) => None,
+            _ => {
+                error!("channel tls requires both a certificate and a key");
+
+                // Exit Sonic
+                process::exit(1);
+            }
+        };
+
         match TcpListener::bind(APP_CONF.channel.inet) {
             Ok(listener) => {
-                info!("listening on tcp://{}", APP_CONF.channel.inet);
+                info!(
+                    "listening on {}://{}",
+                    if tls_config.is_some() { "tls" } else { "tcp" },
+                    APP_CONF.channel.inet
+                );
+
+                Self::serve_tcp(listener, tls_config, APP_CONF.channel.max_clients);
+            }
+            Err(err) => {
+                error!("error binding channel listener: {}", err);
+
+                // Exit Sonic
+                process::exit(1);
+            }
+        }
+    }
+
+    fn serve_tcp(listener: TcpListener, tls_config: Option<Arc<ServerConfig>>, max_clients: usize) {
+        for stream in listener.incoming() {
+            match stream {
+                Ok(mut stream) => {
+                    // Too many clients? (reject client right away, without spawning a thread)
+                    if let Some(slot) = Self::admit(&mut stream, max_clients) {
+                        let tls_config = tls_config.clone();
+
+                        thread::Builder::new()
+                            .name(THREAD_NAME_CHANNEL_CLIENT.to_string())
+                            .spawn(move || {
+                                let _slot = slot;
+
+                                if let Ok(peer_addr) = stream.peer_addr() {
+                                    debug!("channel client connecting: {}", peer_addr);
+                                }
+
+                                // Create client
+                                ChannelHandle::client(stream, tls_config);
+                            })
+                            .ok();
+                    }
+                }
+                Err(err) => {
+                    warn!("error handling stream: {}", err);
+                }
+            }
+        }
+    }
+
+    #[cfg(unix)]
+    fn run_unix(unix_socket_path: &Path) {
+        // Remove any leftover socket file (eg. if Sonic was not stopped gracefully)
+        if unix_socket_path.exists() {
+            if let Err(err) = fs::remove_file(unix_socket_path) {
+                warn!("could not remove existing unix socket file: {}", err);
+            }
+        }
+
+        match UnixListener::bind(unix_socket_path) {
+            Ok(listener) => {
+                info!("listening on unix://{}", unix_socket_path.display());
 
                 for stream in listener.incoming() {
                     match stream {
-                        Ok(stream) => {
-                            thread::Builder::new()
-                                .name(THREAD_NAME_CHANNEL_CLIENT.to_string())
-                                .spawn(move || {
-                                    if let Ok(peer_addr) = stream.peer_addr() {
-                                        debug!("channel client connecting: {}", peer_addr);
-                                    }
-
-                                    // Create client
-                                    ChannelHandle::client(stream);
-                                })
-                                .ok();
+                        Ok(mut stream) => {
+                            // Too many clients? (reject client right away)
+                            if let Some(slot) =
+                                Self::admit(&mut stream, APP_CONF.channel.max_clients)
+                            {
+                                thread::Builder::new()
+                                    .name(THREAD_NAME_CHANNEL_CLIENT.to_string())
+                                    .spawn(move || {
+                                        let _slot = slot;
+
+                                        debug!("channel client connecting on unix socket");
+
+                                        // Create client
+                                        ChannelHandle::client_unix(stream);
+                                    })
+                                    .ok();
+                            }
                         }
                         Err(err) => {
                             warn!("error handling stream: {}", err);
@@ -53,7 +157,7 @@ impl ChannelListen {
                 }
             }
             Err(err) => {
-                error!("error binding channel listener: {}", err);
+                error!("error binding channel unix socket listener: {}", err);
 
                 // Exit Sonic
                 process::exit(1);
@@ -61,8 +165,253 @@ impl ChannelListen {
         }
     }
 
+    #[cfg(not(unix))]
+    fn run_unix(_unix_socket_path: &Path) {
+        error!("channel unix socket is only supported on unix systems");
+
+        // Exit Sonic
+        process::exit(1);
+    }
+
+    fn admit<W: Write>(stream: &mut W, max_clients: usize) -> Option<ChannelListenSlot> {
+        // Acquire a client slot (it gets released once the slot is dropped, that is, when the \
+        //   client thread ends, even if it panics or if it could not be spawned)
+        let admitted = CLIENTS_ADMITTED.fetch_add(1, Ordering::SeqCst);
+        let slot = ChannelListenSlot;
+
+        if max_clients > 0 && admitted >= max_clients {
+            info!("rejecting channel client, as there are too many clients");
+
+            // Notice: the stream gets closed as it is dropped, and its slot is released.
+            write!(stream, "ERR policy_reject(too_many_clients){}", LINE_FEED).ok();
+
+            None
+        } else {
+            Some(slot)
+        }
+    }
+
+    pub fn make_tls_config(cert_path: &Path, key_path: &Path) -> Result<Arc<ServerConfig>, ()> {
+        let certs = File::open(cert_path)
+            .map_err(|err| error!("could not open tls certificate: {}", err))
+            .and_then(|file| {
+                rustls_pemfile::certs(&mut BufReader::new(file))
+                    .collect::<Result<Vec<_>, _>>()
+                    .map_err(|err| error!("could not read tls certificate: {}", err))
+            })?;
+
+        let key = File::open(key_path)
+            .map_err(|err| error!("could not open tls key: {}", err))
+            .and_then(|file| {
+                rustls_pemfile::private_key(&mut BufReader::new(file))
+                    .map_err(|err| error!("could not read tls key: {}", err))
+            })?
+            .ok_or_else(|| error!("no private key found in tls key file"))?;
+
+        ServerConfig::builder()
+            .with_no_client_auth()
+            .with_single_cert(certs, key)
+            .map(Arc::new)
+            .map_err(|err| error!("invalid tls certificate or key: {}", err))
+    }
+
     pub fn teardown() {
         // Channel cannot be used anymore
         *CHANNEL_AVAILABLE.write().unwrap() = false;
+
+        // Remove Unix domain socket file? (if listening on it)
+        if let Some(ref unix_socket_path) = APP_CONF.channel.unix_socket_path {
+            fs::remove_file(unix_socket_path).ok();
+        }
+    }
+}
+
+impl Drop for ChannelListenSlot {
+    fn drop(&mut self) {
+        CLIENTS_ADMITTED.fetch_sub(1, Ordering::SeqCst);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::channel::mode::ChannelMode;
+    use rustls::pki_types::{CertificateDer, ServerName};
+    use rustls::{ClientConfig, ClientConnection, RootCertStore, StreamOwned};
+    use std::convert::TryFrom;
+    use std::env;
+    use std::fs;
+    use std::io::{BufRead, Write};
+    use std::net::TcpStream;
+
+    #[test]
+    fn it_serves_tls_clients() {
+        let certificate =
+            rcgen::generate_simple_self_signed(vec!["localhost".to_string()]).unwrap();
+
+        let (cert_path, key_path) = (
+            env::temp_dir().join("sonic-test-channel-tls-cert.pem"),
+            env::temp_dir().join("sonic-test-channel-tls-key.pem"),
+        );
+
+        fs::write(&cert_path, certificate.serialize_pem().unwrap()).unwrap();
+        fs::write(&key_path, certificate.serialize_private_key_pem()).unwrap();
+
+        let tls_config = ChannelListen::make_tls_config(&cert_path, &key_path).unwrap();
+
+        // Serve a single TLS client
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let listener_addr = listener.local_addr().unwrap();
+
+        thread::spawn(move || {
+            let (stream, _) = listener.accept().unwrap();
+
+            ChannelHandle::client(stream, Some(tls_config));
+        });
+
+        // Connect with a TLS client, that trusts the self-signed certificate
+        let mut root_store = RootCertStore::empty();
+
+        root_store
+            .add(CertificateDer::from(certificate.serialize_der().unwrap()))
+            .unwrap();
+
+        let client_session = ClientConnection::new(
+            Arc::new(
+                ClientConfig::builder()
+                    .with_root_certificates(root_store)
+                    .with_no_client_auth(),
+            ),
+            ServerName::try_from("localhost").unwrap(),
+        )
+        .unwrap();
+
+        let mut stream = BufReader::new(StreamOwned::new(
+            client_session,
+            TcpStream::connect(listener_addr).unwrap(),
+        ));
+
+        let read_line = |stream: &mut BufReader<_>| {
+            let mut line = String::new();
+
+            stream.read_line(&mut line).unwrap();
+
+            line
+        };
+
+        assert!(read_line(&mut stream).starts_with("CONNECTED"));
+
+        // Notice: commands are sent at once, as each write is sent in its own TLS record.
+        stream
+            .get_mut()
+            .write_all(
+                format!(
+                    "START control {}\n",
+                    ChannelMode::Control
+                        .auth_password(&APP_CONF.channel)
+                        .unwrap_or("")
+                )
+                .as_bytes(),
+            )
+            .unwrap();
+
+        assert!(read_line(&mut stream).starts_with("STARTED control"));
+
+        stream.get_mut().write_all(b"PING\n").unwrap();
+
+        assert_eq!(read_line(&mut stream), "PONG\r\n");
+
+        fs::remove_file(cert_path).unwrap();
+        fs::remove_file(key_path).unwrap();
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn it_serves_unix_clients() {
+        use std::os::unix::net::UnixStream;
+
+        let socket_path = env::temp_dir().join("sonic-test-channel-unix.sock");
+
+        fs::remove_file(&socket_path).ok();
+
+        // Serve a single Unix socket client
+        let listener = UnixListener::bind(&socket_path).unwrap();
+
+        thread::spawn(move || {
+            let (stream, _) = listener.accept().unwrap();
+
+            ChannelHandle::client_unix(stream);
+        });
+
+        let mut stream = BufReader::new(UnixStream::connect(&socket_path).unwrap());
+
+        let read_line = |stream: &mut BufReader<_>| {
+            let mut line = String::new();
+
+            stream.read_line(&mut line).unwrap();
+
+            line
+        };
+
+        assert!(read_line(&mut stream).starts_with("CONNECTED"));
+
+        stream
+            .get_mut()
+            .write_all(
+                format!(
+                    "START ingest {}\n",
+                    ChannelMode::Ingest
+                        .auth_password(&APP_CONF.channel)
+                        .unwrap_or("")
+                )
+                .as_bytes(),
+            )
+            .unwrap();
+
+        assert!(read_line(&mut stream).starts_with("STARTED ingest"));
+
+        stream.get_mut().write_all(b"PING\n").unwrap();
+
+        assert_eq!(read_line(&mut stream), "PONG\r\n");
+
+        fs::remove_file(socket_path).unwrap();
+    }
+
+    #[test]
+    fn it_rejects_too_many_clients() {
+        // Serve clients, with at most 2 clients at once
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let listener_addr = listener.local_addr().unwrap();
+
+        thread::spawn(move || ChannelListen::serve_tcp(listener, None, 2));
+
+        let read_line = |stream: &mut BufReader<TcpStream>| {
+            let mut line = String::new();
+
+            stream.read_line(&mut line).unwrap();
+
+            line
+        };
+
+        let mut streams: Vec<BufReader<TcpStream>> = (0..3)
+            .map(|_| BufReader::new(TcpStream::connect(listener_addr).unwrap()))
+            .collect();
+
+        assert!(read_line(&mut streams[0]).starts_with("CONNECTED"));
+        assert!(read_line(&mut streams[1]).starts_with("CONNECTED"));
+        assert_eq!(
+            read_line(&mut streams[2]),
+            "ERR policy_reject(too_many_clients)\r\n"
+        );
+        assert_eq!(read_line(&mut streams[2]), "");
+    }
+
+    #[test]
+    fn it_fails_making_tls_config() {
+        assert!(ChannelListen::make_tls_config(
+            Path::new("/tmp/sonic-test-channel-tls-missing-cert.pem"),
+            Path::new("/tmp/sonic-test-channel-tls-missing-key.pem"),
+        )
+        .is_err());
     }
 }
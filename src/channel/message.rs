@@ -4,9 +4,11 @@
 // Copyright: 2019, Valerian Saliou <valerian@valeriansaliou.name>
 // License: Mozilla Public License v2.0 (MPL v2.0)
 
-use std::io::Write;
-use std::net::TcpStream;
+use std::cell::RefCell;
+use std::io::{self, Write};
 use std::str::{self, SplitWhitespace};
+use std::sync::mpsc::{self, Sender};
+use std::thread;
 use std::time::Instant;
 
 use super::command::{
@@ -15,15 +17,20 @@ use super::command::{
     COMMANDS_MODE_CONTROL, COMMANDS_MODE_INGEST, COMMANDS_MODE_SEARCH,
 };
 use super::listen::CHANNEL_AVAILABLE;
+use super::slowlog::ChannelSlowLog;
 use super::statistics::{COMMANDS_TOTAL, COMMAND_LATENCY_BEST, COMMAND_LATENCY_WORST};
-use crate::LINE_FEED;
+use crate::{APP_CONF, LINE_FEED, THREAD_NAME_CHANNEL_WRITER};
 
 pub struct ChannelMessage;
 pub struct ChannelMessageModeSearch;
 pub struct ChannelMessageModeIngest;
 pub struct ChannelMessageModeControl;
 
-const COMMAND_ELAPSED_MILLIS_SLOW_WARN: u128 = 50;
+pub type ChannelEventWriter = Sender<Vec<ChannelCommandResponseArgs>>;
+
+thread_local! {
+    static EVENT_WRITER: RefCell<Option<ChannelEventWriter>> = const { RefCell::new(None) };
+}
 
 #[derive(PartialEq)]
 pub enum ChannelMessageResult {
@@ -36,8 +43,8 @@ pub trait ChannelMessageMode {
 }
 
 impl ChannelMessage {
-    pub fn on<M: ChannelMessageMode>(
-        mut stream: &TcpStream,
+    pub fn on<M: ChannelMessageMode, W: Write>(
+        stream: &mut W,
         message_slice: &[u8],
     ) -> ChannelMessageResult {
         let message = str::from_utf8(message_slice).unwrap_or("");
@@ -78,37 +85,22 @@ impl ChannelMessage {
             };
         }
 
-        // Serve response messages on socket
-        for response_args in response_args_groups {
-            if !response_args.0.is_empty() {
-                if let Some(ref values) = response_args.1 {
-                    let values_string = values.join(" ");
-
-                    write!(stream, "{} {}{}", response_args.0, values_string, LINE_FEED)
-                        .expect("write failed");
-
-                    debug!(
-                        "wrote response with values: {} ({})",
-                        response_args.0, values_string
-                    );
-                } else {
-                    write!(stream, "{}{}", response_args.0, LINE_FEED).expect("write failed");
-
-                    debug!("wrote response with no values: {}", response_args.0);
-                }
-            }
-        }
+        // Serve response messages on socket (through the event writer if any, as executor \
+        //   pool workers may send events to the same socket concurrently)
+        Self::serve_responses(stream, response_args_groups).expect("write failed");
 
         // Measure and log time it took to execute command
         // Notice: this is critical as to raise developer awareness on the performance bits when \
         //   altering commands-related code, or when making changes to underlying store executors.
         let command_took = command_start.elapsed();
 
-        if command_took.as_millis() >= COMMAND_ELAPSED_MILLIS_SLOW_WARN {
+        if command_took.as_millis() >= APP_CONF.channel.slow_query_log_ms as u128 {
             warn!(
                 "took a lot of time: {}ms to process channel message",
                 command_took.as_millis(),
             );
+
+            ChannelSlowLog::record(message, command_took);
         } else {
             info!(
                 "took {}ms/{}us/{}ns to process channel message",
@@ -150,6 +142,97 @@ impl ChannelMessage {
         result
     }
 
+    pub fn reject<W: Write>(stream: &mut W, reason: ChannelCommandError) {
+        let response_args_groups = vec![ChannelCommandResponse::Err(reason).to_args()];
+
+        // Serve rejection on socket (through the event writer if any, as for command responses)
+        Self::serve_responses(stream, response_args_groups).expect("write failed");
+    }
+
+    pub fn end<W: Write>(stream: &mut W, reason: &'static str) {
+        let response_args_groups = vec![ChannelCommandResponse::Ended(reason).to_args()];
+
+        // Serve end on socket (through the event writer if any, as events may be written \
+        //   concurrently)
+        Self::serve_responses(stream, response_args_groups).expect("write failed");
+    }
+
+    pub fn bind_event_writer(writer: Option<Box<dyn Write + Send>>) {
+        let event_writer = writer.and_then(|mut writer| {
+            let (sender, receiver) = mpsc::channel::<Vec<ChannelCommandResponseArgs>>();
+
+            // Notice: the connection writer thread is the only one writing to the socket, thus \
+            //   a slow reader only stalls its own connection, and never executor pool workers \
+            //   that send events to it. It stops once all senders are gone, or upon a write \
+            //   failure (eg. write timeout), in which case further sends fail.
+            thread::Builder::new()
+                .name(THREAD_NAME_CHANNEL_WRITER.to_string())
+                .spawn(move || {
+                    for response_args_groups in receiver {
+                        if let Err(err) = Self::write_responses(&mut *writer, &response_args_groups)
+                        {
+                            info!("closing channel writer thread after write failure: {}", err);
+
+                            break;
+                        }
+                    }
+                })
+                .map_err(|err| error!("failed spawning channel writer thread: {}", err))
+                .ok()
+                .map(|_| sender)
+        });
+
+        EVENT_WRITER.with(|bound_writer| *bound_writer.borrow_mut() = event_writer);
+    }
+
+    pub fn event_writer() -> Option<ChannelEventWriter> {
+        EVENT_WRITER.with(|bound_writer| bound_writer.borrow().clone())
+    }
+
+    pub fn write_responses<W: Write + ?Sized>(
+        stream: &mut W,
+        response_args_groups: &[ChannelCommandResponseArgs],
+    ) -> io::Result<()> {
+        for response_args in response_args_groups {
+            if !response_args.0.is_empty() {
+                if let Some(ref values) = response_args.1 {
+                    let values_string = values.join(" ");
+
+                    stream.write_all(
+                        format!("{} {}{}", response_args.0, values_string, LINE_FEED).as_bytes(),
+                    )?;
+
+                    debug!(
+                        "wrote response with values: {} ({})",
+                        response_args.0, values_string
+                    );
+                } else {
+                    stream.write_all(format!("{}{}", response_args.0, LINE_FEED).as_bytes())?;
+
+                    debug!("wrote response with no values: {}", response_args.0);
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    fn serve_responses<W: Write>(
+        stream: &mut W,
+        response_args_groups: Vec<ChannelCommandResponseArgs>,
+    ) -> io::Result<()> {
+        if let Some(event_writer) = Self::event_writer() {
+            event_writer
+                .send(response_args_groups)
+                .or(Err(io::Error::new(
+                    io::ErrorKind::BrokenPipe,
+                    "channel writer thread is gone",
+                )))
+        } else {
+            Self::write_responses(stream, &response_args_groups)
+        }
+    }
+
     fn extract(message: &str) -> (String, SplitWhitespace) {
         // Extract command name and arguments
         let mut parts = message.split_whitespace();
@@ -167,6 +250,8 @@ impl ChannelMessageMode for ChannelMessageModeSearch {
             "QUERY" => ChannelCommandSearch::dispatch_query,
             "SUGGEST" => ChannelCommandSearch::dispatch_suggest,
             "LIST" => ChannelCommandSearch::dispatch_list,
+            "VSEARCH" => ChannelCommandSearch::dispatch_vsearch,
+            "GET" => ChannelCommandSearch::dispatch_get,
             "HELP" => ChannelCommandSearch::dispatch_help,
         })
     }
@@ -176,11 +261,18 @@ impl ChannelMessageMode for ChannelMessageModeIngest {
     fn handle(message: &str) -> Result<Vec<ChannelCommandResponse>, ChannelCommandError> {
         gen_channel_message_mode_handle!(message, COMMANDS_MODE_INGEST, {
             "PUSH" => ChannelCommandIngest::dispatch_push,
+            "MPUSH" => ChannelCommandIngest::dispatch_mpush,
             "POP" => ChannelCommandIngest::dispatch_pop,
             "COUNT" => ChannelCommandIngest::dispatch_count,
             "FLUSHC" => ChannelCommandIngest::dispatch_flushc,
             "FLUSHB" => ChannelCommandIngest::dispatch_flushb,
             "FLUSHO" => ChannelCommandIngest::dispatch_flusho,
+            "FLUSHT" => ChannelCommandIngest::dispatch_flusht,
+            "FLUSH_ORPHANS" => ChannelCommandIngest::dispatch_flush_orphans,
+            "LEARN" => ChannelCommandIngest::dispatch_learn,
+            "INSPECT" => ChannelCommandIngest::dispatch_inspect,
+            "LISTCOLLECTIONS" => ChannelCommandBase::dispatch_listcollections,
+            "LISTBUCKETS" => ChannelCommandBase::dispatch_listbuckets,
             "HELP" => ChannelCommandIngest::dispatch_help,
         })
     }
@@ -191,6 +283,9 @@ impl ChannelMessageMode for ChannelMessageModeControl {
         gen_channel_message_mode_handle!(message, COMMANDS_MODE_CONTROL, {
             "TRIGGER" => ChannelCommandControl::dispatch_trigger,
             "INFO" => ChannelCommandControl::dispatch_info,
+            "CHANNEL" => ChannelCommandControl::dispatch_channel,
+            "LISTCOLLECTIONS" => ChannelCommandBase::dispatch_listcollections,
+            "LISTBUCKETS" => ChannelCommandBase::dispatch_listbuckets,
             "HELP" => ChannelCommandControl::dispatch_help,
         })
     }
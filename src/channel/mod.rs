@@ -8,10 +8,14 @@
 mod macros;
 
 mod command;
-mod format;
+pub mod format;
 mod handle;
 mod message;
 mod mode;
+mod slowlog;
 
+#[cfg(feature = "transport-grpc")]
+pub mod grpc;
 pub mod listen;
+pub mod ratelimiter;
 pub mod statistics;
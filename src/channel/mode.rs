@@ -4,6 +4,9 @@
 // Copyright: 2019, Valerian Saliou <valerian@valeriansaliou.name>
 // License: Mozilla Public License v2.0 (MPL v2.0)
 
+use crate::config::options::ConfigChannel;
+
+#[derive(Clone)]
 pub enum ChannelMode {
     Search,
     Ingest,
@@ -20,6 +23,19 @@ impl ChannelMode {
         }
     }
 
+    pub fn auth_password<'a>(&self, channel: &'a ConfigChannel) -> Option<&'a str> {
+        // Notice: the mode-specific password takes precedence over the generic password, if set.
+        let mode_auth_password = match *self {
+            ChannelMode::Search => &channel.auth_password_search,
+            ChannelMode::Ingest => &channel.auth_password_ingest,
+            ChannelMode::Control => &channel.auth_password_control,
+        };
+
+        mode_auth_password
+            .as_deref()
+            .or(channel.auth_password.as_deref())
+    }
+
     pub fn to_str(&self) -> &'static str {
         match *self {
             ChannelMode::Search => "search",
@@ -28,3 +44,44 @@ impl ChannelMode {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_selects_auth_password() {
+        let mut channel: ConfigChannel = toml::from_str("[search]\n").unwrap();
+
+        assert_eq!(ChannelMode::Search.auth_password(&channel), None);
+        assert_eq!(ChannelMode::Control.auth_password(&channel), None);
+
+        channel.auth_password = Some("generic".to_string());
+
+        assert_eq!(ChannelMode::Search.auth_password(&channel), Some("generic"));
+        assert_eq!(ChannelMode::Ingest.auth_password(&channel), Some("generic"));
+        assert_eq!(
+            ChannelMode::Control.auth_password(&channel),
+            Some("generic")
+        );
+
+        channel.auth_password_search = Some("search".to_string());
+        channel.auth_password_control = Some("control".to_string());
+
+        assert_eq!(ChannelMode::Search.auth_password(&channel), Some("search"));
+        assert_eq!(ChannelMode::Ingest.auth_password(&channel), Some("generic"));
+        assert_eq!(
+            ChannelMode::Control.auth_password(&channel),
+            Some("control")
+        );
+
+        channel.auth_password = None;
+
+        assert_eq!(ChannelMode::Search.auth_password(&channel), Some("search"));
+        assert_eq!(ChannelMode::Ingest.auth_password(&channel), None);
+        assert_eq!(
+            ChannelMode::Control.auth_password(&channel),
+            Some("control")
+        );
+    }
+}
@@ -0,0 +1,114 @@
+// Sonic
+//
+// Fast, lightweight and schema-less search backend
+// Copyright: 2019, Valerian Saliou <valerian@valeriansaliou.name>
+// License: Mozilla Public License v2.0 (MPL v2.0)
+
+use hashbrown::HashMap;
+use std::net::IpAddr;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use crate::APP_CONF;
+
+pub struct ChannelRateLimiter;
+
+struct ChannelRateLimiterBucket {
+    tokens: f64,
+    refilled_at: Instant,
+}
+
+const BUCKET_STALE_AFTER: Duration = Duration::from_secs(60);
+
+lazy_static! {
+    static ref RATE_LIMITER_BUCKETS: Mutex<HashMap<IpAddr, ChannelRateLimiterBucket>> =
+        Mutex::new(HashMap::new());
+}
+
+impl ChannelRateLimiter {
+    pub fn allow(peer_ip: Option<IpAddr>) -> bool {
+        let rate = APP_CONF.channel.rate_limit_commands_per_second;
+
+        // Rate limiting disabled, or peer has no address? (eg. Unix socket peers)
+        if let (true, Some(peer_ip)) = (rate > 0, peer_ip) {
+            let mut buckets = RATE_LIMITER_BUCKETS.lock().unwrap();
+
+            let allowed = buckets
+                .entry(peer_ip)
+                .or_insert_with(|| ChannelRateLimiterBucket::new(rate))
+                .take(rate, Instant::now());
+
+            if !allowed {
+                info!("rate limit exceeded for channel peer: {}", peer_ip);
+            }
+
+            allowed
+        } else {
+            true
+        }
+    }
+
+    pub fn janitor() {
+        debug!("scanning for rate limiter buckets to janitor");
+
+        let now = Instant::now();
+
+        // Notice: buckets left untouched for a while are full again, thus they can be removed \
+        //   as they would get re-created full on the next command.
+        let mut buckets = RATE_LIMITER_BUCKETS.lock().unwrap();
+
+        buckets.retain(|_, bucket| now.duration_since(bucket.refilled_at) < BUCKET_STALE_AFTER);
+
+        debug!(
+            "done scanning for rate limiter buckets to janitor, {} remaining",
+            buckets.len()
+        );
+    }
+}
+
+impl ChannelRateLimiterBucket {
+    fn new(rate: u32) -> Self {
+        ChannelRateLimiterBucket {
+            tokens: rate as f64,
+            refilled_at: Instant::now(),
+        }
+    }
+
+    fn take(&mut self, rate: u32, now: Instant) -> bool {
+        // Refill tokens for the time elapsed since last refill (the bucket capacity is the \
+        //   rate, thus a burst of up to one second worth of commands is allowed)
+        let elapsed = now.saturating_duration_since(self.refilled_at);
+
+        self.tokens = (self.tokens + elapsed.as_secs_f64() * rate as f64).min(rate as f64);
+        self.refilled_at = now;
+
+        if self.tokens >= 1.0 {
+            self.tokens -= 1.0;
+
+            true
+        } else {
+            false
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_takes_bucket_tokens() {
+        let mut bucket = ChannelRateLimiterBucket::new(2);
+        let now = bucket.refilled_at;
+
+        assert!(bucket.take(2, now));
+        assert!(bucket.take(2, now));
+        assert!(!bucket.take(2, now));
+        assert!(!bucket.take(2, now + Duration::from_millis(100)));
+        assert!(bucket.take(2, now + Duration::from_millis(600)));
+        assert!(!bucket.take(2, now + Duration::from_millis(600)));
+        assert!(bucket.take(2, now + Duration::from_secs(10)));
+        assert!(bucket.take(2, now + Duration::from_secs(10)));
+        assert!(!bucket.take(2, now + Duration::from_secs(10)));
+    }
+}
@@ -0,0 +1,149 @@
+// Sonic
+//
+// Fast, lightweight and schema-less search backend
+// Copyright: 2019, Valerian Saliou <valerian@valeriansaliou.name>
+// License: Mozilla Public License v2.0 (MPL v2.0)
+
+use std::fs::{File, OpenOptions};
+use std::io::{self, BufWriter, Write};
+use std::sync::Mutex;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use crate::APP_CONF;
+
+pub struct ChannelSlowLog;
+
+#[derive(Serialize)]
+struct ChannelSlowLogRecord<'a> {
+    query: &'a str,
+    collection: Option<&'a str>,
+    bucket: Option<&'a str>,
+    duration_ms: u64,
+    timestamp: u64,
+}
+
+const COMMANDS_WITH_COLLECTION_BUCKET: &[&str] = &[
+    "QUERY",
+    "SUGGEST",
+    "LIST",
+    "VSEARCH",
+    "PUSH",
+    "MPUSH",
+    "POP",
+    "COUNT",
+    "FLUSHB",
+    "FLUSHO",
+    "FLUSH_ORPHANS",
+    "INSPECT",
+];
+const COMMANDS_WITH_COLLECTION: &[&str] = &["FLUSHC", "FLUSHT", "LEARN", "LISTBUCKETS"];
+
+lazy_static! {
+    static ref SLOW_LOG_WRITER: Option<Mutex<BufWriter<File>>> = APP_CONF
+        .channel
+        .slow_query_log_path
+        .as_ref()
+        .and_then(|path| {
+            OpenOptions::new()
+                .create(true)
+                .append(true)
+                .open(path)
+                .map(|file| Mutex::new(BufWriter::new(file)))
+                .map_err(|err| error!("could not open slow query log file: {}", err))
+                .ok()
+        });
+}
+
+impl ChannelSlowLog {
+    pub fn record(message: &str, took: Duration) {
+        // Slow query log file configured?
+        if let Some(ref writer) = *SLOW_LOG_WRITER {
+            let timestamp = SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map(|duration| duration.as_secs())
+                .unwrap_or(0);
+
+            if let Err(err) = Self::append(&mut *writer.lock().unwrap(), message, took, timestamp) {
+                warn!("could not append to slow query log file: {}", err);
+            }
+        }
+    }
+
+    fn append<W: Write>(
+        writer: &mut W,
+        message: &str,
+        took: Duration,
+        timestamp: u64,
+    ) -> io::Result<()> {
+        // Notice: messages may end with a carriage return, as lines can be CRLF-terminated.
+        let message = message.trim();
+
+        // Acquire collection and bucket (if the command targets any)
+        let mut parts = message.split_whitespace();
+
+        let command = parts.next().unwrap_or("").to_uppercase();
+
+        let (collection, bucket) = if COMMANDS_WITH_COLLECTION_BUCKET.contains(&command.as_str()) {
+            (parts.next(), parts.next())
+        } else if COMMANDS_WITH_COLLECTION.contains(&command.as_str()) {
+            (parts.next(), None)
+        } else {
+            (None, None)
+        };
+
+        let record = serde_json::to_string(&ChannelSlowLogRecord {
+            query: message,
+            collection,
+            bucket,
+            duration_ms: took.as_millis() as u64,
+            timestamp,
+        })?;
+
+        // Notice: each record gets flushed right away, so that the log file can be tailed.
+        writer.write_all(format!("{}\n", record).as_bytes())?;
+        writer.flush()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::env;
+    use std::fs;
+
+    #[test]
+    fn it_appends_slow_queries() {
+        let path = env::temp_dir().join("sonic-test-channel-slowlog.log");
+
+        fs::remove_file(&path).ok();
+
+        {
+            let mut writer = BufWriter::new(File::create(&path).unwrap());
+
+            assert!(ChannelSlowLog::append(
+                &mut writer,
+                "QUERY messages default \"valerian\"\r",
+                Duration::from_millis(120),
+                1600000000,
+            )
+            .is_ok());
+            assert!(ChannelSlowLog::append(
+                &mut writer,
+                "TRIGGER consolidate",
+                Duration::from_millis(75),
+                1600000001,
+            )
+            .is_ok());
+        }
+
+        assert_eq!(
+            fs::read_to_string(&path).unwrap(),
+            "{\"query\":\"QUERY messages default \\\"valerian\\\"\",\"collection\":\"messages\",\
+             \"bucket\":\"default\",\"duration_ms\":120,\"timestamp\":1600000000}\n\
+             {\"query\":\"TRIGGER consolidate\",\"collection\":null,\"bucket\":null,\
+             \"duration_ms\":75,\"timestamp\":1600000001}\n"
+        );
+
+        fs::remove_file(path).unwrap();
+    }
+}
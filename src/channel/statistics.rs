@@ -4,10 +4,13 @@
 // Copyright: 2019, Valerian Saliou <valerian@valeriansaliou.name>
 // License: Mozilla Public License v2.0 (MPL v2.0)
 
+use std::net::{Shutdown, SocketAddr, TcpStream};
 use std::ops::Deref;
-use std::sync::RwLock;
-use std::time::Instant;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, RwLock};
+use std::time::{Instant, SystemTime};
 
+use super::mode::ChannelMode;
 use crate::store::fst::StoreFSTPool;
 use crate::store::kv::StoreKVPool;
 
@@ -17,6 +20,28 @@ lazy_static! {
     pub static ref COMMANDS_TOTAL: RwLock<u64> = RwLock::new(0);
     pub static ref COMMAND_LATENCY_BEST: RwLock<u32> = RwLock::new(0);
     pub static ref COMMAND_LATENCY_WORST: RwLock<u32> = RwLock::new(0);
+    static ref CLIENTS_ACTIVE: RwLock<Vec<ChannelConnection>> = RwLock::new(Vec::new());
+    static ref CLIENTS_ACTIVE_NEXT_ID: RwLock<ChannelConnectionID> = RwLock::new(0);
+}
+
+pub type ChannelConnectionID = u64;
+
+#[derive(Clone)]
+pub struct ChannelConnection {
+    pub id: ChannelConnectionID,
+    pub peer_addr: Option<SocketAddr>,
+    pub mode: Option<ChannelMode>,
+    pub last_command_at: SystemTime,
+    killed: Arc<AtomicBool>,
+    stream: Option<Arc<TcpStream>>,
+}
+
+pub struct ChannelConnections;
+
+pub struct ChannelConnectionGuard {
+    id: ChannelConnectionID,
+    peer_addr: Option<SocketAddr>,
+    killed: Arc<AtomicBool>,
 }
 
 #[derive(Default)]
@@ -33,12 +58,14 @@ pub struct ChannelStatistics {
 
 pub fn ensure_states() {
     // Ensure all statics are initialized (a `deref` is enough to lazily initialize them)
-    let (_, _, _, _, _) = (
+    let (_, _, _, _, _, _, _) = (
         START_TIME.deref(),
         CLIENTS_CONNECTED.deref(),
         COMMANDS_TOTAL.deref(),
         COMMAND_LATENCY_BEST.deref(),
         COMMAND_LATENCY_WORST.deref(),
+        CLIENTS_ACTIVE.deref(),
+        CLIENTS_ACTIVE_NEXT_ID.deref(),
     );
 }
 
@@ -58,3 +85,159 @@ impl ChannelStatistics {
         }
     }
 }
+
+impl ChannelConnections {
+    pub fn register(
+        peer_addr: Option<SocketAddr>,
+        stream: Option<TcpStream>,
+    ) -> ChannelConnectionGuard {
+        let id = {
+            let mut next_id = CLIENTS_ACTIVE_NEXT_ID.write().unwrap();
+
+            *next_id += 1;
+
+            *next_id
+        };
+
+        let killed = Arc::new(AtomicBool::new(false));
+
+        CLIENTS_ACTIVE.write().unwrap().push(ChannelConnection {
+            id,
+            peer_addr,
+            mode: None,
+            last_command_at: SystemTime::now(),
+            killed: killed.clone(),
+            stream: stream.map(Arc::new),
+        });
+
+        // Notice: the connection gets deregistered once the guard is dropped, which also \
+        //   happens if the channel thread panics (eg. when closing on a read error).
+        ChannelConnectionGuard {
+            id,
+            peer_addr,
+            killed,
+        }
+    }
+
+    pub fn list() -> Vec<ChannelConnection> {
+        CLIENTS_ACTIVE.read().unwrap().clone()
+    }
+
+    pub fn kill(peer_addr: &SocketAddr) -> bool {
+        let active = CLIENTS_ACTIVE.read().unwrap();
+
+        if let Some(connection) = active
+            .iter()
+            .find(|connection| connection.peer_addr.as_ref() == Some(peer_addr))
+        {
+            info!("killing channel connection: {}", peer_addr);
+
+            connection.killed.store(true, Ordering::SeqCst);
+
+            // Shutdown the read half of the stream, so that the channel thread does not stay \
+            //   blocked on a pending read (it can still write to the stream before closing)
+            if let Some(ref stream) = connection.stream {
+                if let Err(err) = stream.shutdown(Shutdown::Read) {
+                    warn!("could not shutdown killed channel connection: {}", err);
+                }
+            }
+
+            true
+        } else {
+            false
+        }
+    }
+
+    fn update<F: FnOnce(&mut ChannelConnection)>(id: ChannelConnectionID, updater: F) {
+        if let Some(connection) = CLIENTS_ACTIVE
+            .write()
+            .unwrap()
+            .iter_mut()
+            .find(|connection| connection.id == id)
+        {
+            updater(connection);
+        }
+    }
+
+    fn deregister(id: ChannelConnectionID) {
+        CLIENTS_ACTIVE
+            .write()
+            .unwrap()
+            .retain(|connection| connection.id != id);
+    }
+}
+
+impl ChannelConnectionGuard {
+    pub fn peer_addr(&self) -> Option<SocketAddr> {
+        self.peer_addr
+    }
+
+    pub fn is_killed(&self) -> bool {
+        self.killed.load(Ordering::SeqCst)
+    }
+
+    pub fn set_mode(&self, mode: ChannelMode) {
+        ChannelConnections::update(self.id, |connection| connection.mode = Some(mode));
+    }
+
+    pub fn touch(&self) {
+        ChannelConnections::update(self.id, |connection| {
+            connection.last_command_at = SystemTime::now()
+        });
+    }
+}
+
+impl Drop for ChannelConnectionGuard {
+    fn drop(&mut self) {
+        ChannelConnections::deregister(self.id);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::net::TcpListener;
+
+    #[test]
+    fn it_registers_connections() {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let stream = TcpStream::connect(listener.local_addr().unwrap()).unwrap();
+
+        let connection =
+            ChannelConnections::register(stream.peer_addr().ok(), stream.try_clone().ok());
+        let connection_id = connection.id;
+
+        connection.set_mode(ChannelMode::Control);
+
+        assert!(matches!(
+            ChannelConnections::list()
+                .iter()
+                .find(|active| active.id == connection_id)
+                .and_then(|active| active.mode.as_ref()),
+            Some(&ChannelMode::Control)
+        ));
+
+        drop(connection);
+
+        assert!(!ChannelConnections::list()
+            .iter()
+            .any(|active| active.id == connection_id));
+    }
+
+    #[test]
+    fn it_kills_connections() {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let stream = TcpStream::connect(listener.local_addr().unwrap()).unwrap();
+
+        let connection =
+            ChannelConnections::register(stream.peer_addr().ok(), stream.try_clone().ok());
+
+        assert!(!connection.is_killed());
+        assert!(ChannelConnections::kill(&stream.peer_addr().unwrap()));
+        assert!(connection.is_killed());
+
+        drop(connection);
+
+        assert!(!ChannelConnections::kill(&stream.peer_addr().unwrap()));
+    }
+}
@@ -7,10 +7,16 @@
 use std::net::SocketAddr;
 use std::path::PathBuf;
 
+use super::options::{ConfigChannelSearchRecencyDecayFunction, ConfigServerLogFormat};
+
 pub fn server_log_level() -> String {
     "error".to_string()
 }
 
+pub fn server_log_format() -> ConfigServerLogFormat {
+    ConfigServerLogFormat::Text
+}
+
 pub fn channel_inet() -> SocketAddr {
     "[::1]:1491".parse().unwrap()
 }
@@ -19,6 +25,22 @@ pub fn channel_tcp_timeout() -> u64 {
     300
 }
 
+pub fn channel_max_clients() -> usize {
+    0
+}
+
+pub fn channel_allow_inspect() -> bool {
+    false
+}
+
+pub fn channel_rate_limit_commands_per_second() -> u32 {
+    0
+}
+
+pub fn channel_slow_query_log_ms() -> u64 {
+    50
+}
+
 pub fn channel_search_query_limit_default() -> u16 {
     10
 }
@@ -31,6 +53,10 @@ pub fn channel_search_query_alternates_try() -> usize {
     4
 }
 
+pub fn channel_search_auto_spellcheck() -> bool {
+    false
+}
+
 pub fn channel_search_suggest_limit_default() -> u16 {
     5
 }
@@ -47,6 +73,58 @@ pub fn channel_search_list_limit_maximum() -> u16 {
     500
 }
 
+pub fn channel_search_recency_decay_function() -> ConfigChannelSearchRecencyDecayFunction {
+    ConfigChannelSearchRecencyDecayFunction::Hyperbolic
+}
+
+pub fn channel_search_query_timeout_ms() -> u64 {
+    0
+}
+
+pub fn channel_search_ngram_auto() -> bool {
+    false
+}
+
+pub fn channel_search_ngram_size() -> usize {
+    2
+}
+
+pub fn channel_search_stemming() -> bool {
+    false
+}
+
+pub fn channel_search_min_word_length() -> usize {
+    2
+}
+
+pub fn channel_search_query_cache_enabled() -> bool {
+    false
+}
+
+pub fn channel_search_query_cache_max_entries() -> usize {
+    1000
+}
+
+pub fn channel_search_query_cache_ttl_secs() -> u64 {
+    60
+}
+
+pub fn channel_ingest_max_oid_length() -> usize {
+    256
+}
+
+pub fn channel_ingest_batch_push_limit() -> usize {
+    100
+}
+
+pub fn channel_ingest_max_indexed_words() -> usize {
+    200
+}
+
+pub fn channel_grpc_listen_addr() -> SocketAddr {
+    "[::1]:1492".parse().unwrap()
+}
+
 pub fn store_kv_path() -> PathBuf {
     PathBuf::from("./data/store/kv/")
 }
@@ -55,10 +133,22 @@ pub fn store_kv_retain_word_objects() -> usize {
     1000
 }
 
+pub fn store_kv_store_original_text() -> bool {
+    false
+}
+
+pub fn store_kv_store_term_positions() -> bool {
+    false
+}
+
 pub fn store_kv_pool_inactive_after() -> u64 {
     1800
 }
 
+pub fn store_kv_pool_open_retries() -> u32 {
+    3
+}
+
 pub fn store_kv_database_flush_after() -> u64 {
     900
 }
@@ -95,6 +185,10 @@ pub fn store_fst_pool_inactive_after() -> u64 {
     300
 }
 
+pub fn store_fst_pool_max_open() -> usize {
+    0
+}
+
 pub fn store_fst_graph_consolidate_after() -> u64 {
     180
 }
@@ -106,3 +200,11 @@ pub fn store_fst_graph_max_size() -> usize {
 pub fn store_fst_graph_max_words() -> usize {
     250000
 }
+
+pub fn store_fst_graph_suggest_scan_limit() -> usize {
+    10000
+}
+
+pub fn store_fst_graph_bucket_hash_seed() -> u32 {
+    0
+}
@@ -5,8 +5,28 @@
 // License: Mozilla Public License v2.0 (MPL v2.0)
 
 use log::{Level, LevelFilter, Metadata, Record, SetLoggerError};
+use std::time::{SystemTime, UNIX_EPOCH};
 
-pub struct ConfigLogger;
+use super::options::ConfigServerLogFormat;
+
+pub struct ConfigLogger {
+    format: ConfigServerLogFormat,
+}
+
+#[derive(Serialize)]
+struct ConfigLoggerJSONRecord<'a> {
+    level: &'a str,
+    target: &'a str,
+    message: String,
+    ts: u64,
+}
+
+static LOGGER_TEXT: ConfigLogger = ConfigLogger {
+    format: ConfigServerLogFormat::Text,
+};
+static LOGGER_JSON: ConfigLogger = ConfigLogger {
+    format: ConfigServerLogFormat::Json,
+};
 
 impl log::Log for ConfigLogger {
     fn enabled(&self, metadata: &Metadata) -> bool {
@@ -15,7 +35,19 @@ impl log::Log for ConfigLogger {
 
     fn log(&self, record: &Record) {
         if self.enabled(record.metadata()) {
-            println!("({}) - {}", record.level(), record.args());
+            match self.format {
+                ConfigServerLogFormat::Text => {
+                    println!("({}) - {}", record.level(), record.args())
+                }
+                ConfigServerLogFormat::Json => {
+                    let ts = SystemTime::now()
+                        .duration_since(UNIX_EPOCH)
+                        .map(|duration| duration.as_secs())
+                        .unwrap_or(0);
+
+                    println!("{}", Self::format_json(record, ts))
+                }
+            }
         }
     }
 
@@ -23,8 +55,44 @@ impl log::Log for ConfigLogger {
 }
 
 impl ConfigLogger {
-    pub fn init(level: LevelFilter) -> Result<(), SetLoggerError> {
+    pub fn init(level: LevelFilter, format: ConfigServerLogFormat) -> Result<(), SetLoggerError> {
         log::set_max_level(level);
-        log::set_logger(&ConfigLogger)
+
+        match format {
+            ConfigServerLogFormat::Text => log::set_logger(&LOGGER_TEXT),
+            ConfigServerLogFormat::Json => log::set_logger(&LOGGER_JSON),
+        }
+    }
+
+    fn format_json(record: &Record, ts: u64) -> String {
+        // Notice: serializing a record cannot fail, as all its fields are plain values.
+        serde_json::to_string(&ConfigLoggerJSONRecord {
+            level: record.level().as_str(),
+            target: record.target(),
+            message: record.args().to_string(),
+            ts,
+        })
+        .unwrap_or_default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_formats_json_records() {
+        assert_eq!(
+            ConfigLogger::format_json(
+                &Record::builder()
+                    .args(format_args!("opened \"default\" store"))
+                    .level(Level::Info)
+                    .target("sonic::store::kv")
+                    .build(),
+                1234567890
+            ),
+            "{\"level\":\"INFO\",\"target\":\"sonic::store::kv\",\
+                \"message\":\"opened \\\"default\\\" store\",\"ts\":1234567890}"
+        );
     }
 }
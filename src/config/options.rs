@@ -4,6 +4,7 @@
 // Copyright: 2019, Valerian Saliou <valerian@valeriansaliou.name>
 // License: Mozilla Public License v2.0 (MPL v2.0)
 
+use std::collections::HashMap;
 use std::net::SocketAddr;
 use std::path::PathBuf;
 
@@ -24,6 +25,16 @@ pub struct ConfigServer {
         deserialize_with = "env_var::str"
     )]
     pub log_level: String,
+
+    #[serde(default = "defaults::server_log_format")]
+    pub log_format: ConfigServerLogFormat,
+}
+
+#[derive(Deserialize, Clone, Copy, PartialEq, Debug)]
+#[serde(rename_all = "lowercase")]
+pub enum ConfigServerLogFormat {
+    Text,
+    Json,
 }
 
 #[derive(Deserialize)]
@@ -34,13 +45,57 @@ pub struct ConfigChannel {
     )]
     pub inet: SocketAddr,
 
+    #[serde(default)]
+    pub unix_socket_path: Option<PathBuf>,
+
     #[serde(default = "defaults::channel_tcp_timeout")]
     pub tcp_timeout: u64,
 
+    #[serde(default = "defaults::channel_max_clients")]
+    pub max_clients: usize,
+
     #[serde(default, deserialize_with = "env_var::opt_str")]
     pub auth_password: Option<String>,
 
+    #[serde(default, deserialize_with = "env_var::opt_str")]
+    pub auth_password_search: Option<String>,
+
+    #[serde(default, deserialize_with = "env_var::opt_str")]
+    pub auth_password_ingest: Option<String>,
+
+    #[serde(default, deserialize_with = "env_var::opt_str")]
+    pub auth_password_control: Option<String>,
+
+    #[serde(default)]
+    pub tls_cert: Option<PathBuf>,
+
+    #[serde(default)]
+    pub tls_key: Option<PathBuf>,
+
+    #[serde(default)]
+    pub executor_threads: Option<usize>,
+
+    #[serde(default)]
+    pub max_text_length: Option<usize>,
+
+    #[serde(default = "defaults::channel_allow_inspect")]
+    pub allow_inspect: bool,
+
+    #[serde(default = "defaults::channel_rate_limit_commands_per_second")]
+    pub rate_limit_commands_per_second: u32,
+
+    #[serde(default = "defaults::channel_slow_query_log_ms")]
+    pub slow_query_log_ms: u64,
+
+    #[serde(default)]
+    pub slow_query_log_path: Option<PathBuf>,
+
     pub search: ConfigChannelSearch,
+
+    #[serde(default)]
+    pub ingest: ConfigChannelIngest,
+
+    pub grpc: Option<ConfigChannelGRPC>,
 }
 
 #[derive(Deserialize)]
@@ -54,6 +109,9 @@ pub struct ConfigChannelSearch {
     #[serde(default = "defaults::channel_search_query_alternates_try")]
     pub query_alternates_try: usize,
 
+    #[serde(default = "defaults::channel_search_auto_spellcheck")]
+    pub auto_spellcheck: bool,
+
     #[serde(default = "defaults::channel_search_suggest_limit_default")]
     pub suggest_limit_default: u16,
 
@@ -65,12 +123,104 @@ pub struct ConfigChannelSearch {
 
     #[serde(default = "defaults::channel_search_list_limit_maximum")]
     pub list_limit_maximum: u16,
+
+    #[serde(default = "defaults::channel_search_recency_decay_function")]
+    pub recency_decay_function: ConfigChannelSearchRecencyDecayFunction,
+
+    #[serde(default = "defaults::channel_search_query_timeout_ms")]
+    pub query_timeout_ms: u64,
+
+    #[serde(default = "defaults::channel_search_ngram_auto")]
+    pub ngram_auto: bool,
+
+    #[serde(default = "defaults::channel_search_ngram_size")]
+    pub ngram_size: usize,
+
+    #[serde(default = "defaults::channel_search_stemming")]
+    pub stemming: bool,
+
+    #[serde(default = "defaults::channel_search_min_word_length")]
+    pub min_word_length: usize,
+
+    #[serde(default)]
+    pub query_cache: ConfigChannelSearchQueryCache,
+
+    #[serde(default)]
+    pub synonyms: HashMap<String, Vec<String>>,
+}
+
+#[derive(Deserialize, Clone, Copy, PartialEq, Debug)]
+#[serde(rename_all = "lowercase")]
+pub enum ConfigChannelSearchRecencyDecayFunction {
+    Exponential,
+    Hyperbolic,
+    Linear,
+}
+
+#[derive(Deserialize)]
+pub struct ConfigChannelSearchQueryCache {
+    #[serde(default = "defaults::channel_search_query_cache_enabled")]
+    pub enabled: bool,
+
+    #[serde(default = "defaults::channel_search_query_cache_max_entries")]
+    pub max_entries: usize,
+
+    #[serde(default = "defaults::channel_search_query_cache_ttl_secs")]
+    pub ttl_secs: u64,
+}
+
+impl Default for ConfigChannelSearchQueryCache {
+    fn default() -> Self {
+        ConfigChannelSearchQueryCache {
+            enabled: defaults::channel_search_query_cache_enabled(),
+            max_entries: defaults::channel_search_query_cache_max_entries(),
+            ttl_secs: defaults::channel_search_query_cache_ttl_secs(),
+        }
+    }
+}
+
+#[derive(Deserialize)]
+pub struct ConfigChannelIngest {
+    #[serde(default = "defaults::channel_ingest_max_oid_length")]
+    pub max_oid_length: usize,
+
+    #[serde(default)]
+    pub max_push_text_length: Option<usize>,
+
+    #[serde(default = "defaults::channel_ingest_batch_push_limit")]
+    pub batch_push_limit: usize,
+
+    #[serde(default = "defaults::channel_ingest_max_indexed_words")]
+    pub max_indexed_words: usize,
+}
+
+impl Default for ConfigChannelIngest {
+    fn default() -> Self {
+        ConfigChannelIngest {
+            max_oid_length: defaults::channel_ingest_max_oid_length(),
+            max_push_text_length: None,
+            batch_push_limit: defaults::channel_ingest_batch_push_limit(),
+            max_indexed_words: defaults::channel_ingest_max_indexed_words(),
+        }
+    }
+}
+
+#[derive(Deserialize)]
+pub struct ConfigChannelGRPC {
+    #[serde(
+        default = "defaults::channel_grpc_listen_addr",
+        deserialize_with = "env_var::socket_addr"
+    )]
+    pub listen_addr: SocketAddr,
 }
 
 #[derive(Deserialize)]
 pub struct ConfigStore {
     pub kv: ConfigStoreKV,
     pub fst: ConfigStoreFST,
+
+    #[serde(default)]
+    pub webhooks: ConfigStoreWebhooks,
 }
 
 #[derive(Deserialize)]
@@ -84,6 +234,15 @@ pub struct ConfigStoreKV {
     #[serde(default = "defaults::store_kv_retain_word_objects")]
     pub retain_word_objects: usize,
 
+    #[serde(default = "defaults::store_kv_store_original_text")]
+    pub store_original_text: bool,
+
+    #[serde(default = "defaults::store_kv_store_term_positions")]
+    pub store_term_positions: bool,
+
+    #[serde(default)]
+    pub compaction_schedule: Option<String>,
+
     pub pool: ConfigStoreKVPool,
     pub database: ConfigStoreKVDatabase,
 }
@@ -92,6 +251,9 @@ pub struct ConfigStoreKV {
 pub struct ConfigStoreKVPool {
     #[serde(default = "defaults::store_kv_pool_inactive_after")]
     pub inactive_after: u64,
+
+    #[serde(default = "defaults::store_kv_pool_open_retries")]
+    pub open_retries: u32,
 }
 
 #[derive(Deserialize)]
@@ -136,6 +298,9 @@ pub struct ConfigStoreFST {
 pub struct ConfigStoreFSTPool {
     #[serde(default = "defaults::store_fst_pool_inactive_after")]
     pub inactive_after: u64,
+
+    #[serde(default = "defaults::store_fst_pool_max_open")]
+    pub max_open: usize,
 }
 
 #[derive(Deserialize)]
@@ -148,4 +313,18 @@ pub struct ConfigStoreFSTGraph {
 
     #[serde(default = "defaults::store_fst_graph_max_words")]
     pub max_words: usize,
+
+    pub max_pending_words: Option<usize>,
+
+    #[serde(default = "defaults::store_fst_graph_suggest_scan_limit")]
+    pub suggest_scan_limit: usize,
+
+    #[serde(default = "defaults::store_fst_graph_bucket_hash_seed")]
+    pub bucket_hash_seed: u32,
+}
+
+#[derive(Deserialize, Default)]
+pub struct ConfigStoreWebhooks {
+    #[serde(default, deserialize_with = "env_var::opt_str")]
+    pub on_push: Option<String>,
 }
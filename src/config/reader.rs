@@ -6,6 +6,7 @@
 
 use std::fs::File;
 use std::io::Read;
+use toml::{Table, Value};
 
 use super::options::Config;
 use crate::APP_ARGS;
@@ -14,23 +15,61 @@ pub struct ConfigReader;
 
 impl ConfigReader {
     pub fn make() -> Config {
-        debug!("reading config file: {}", &APP_ARGS.config);
+        let config = Self::read().unwrap_or_else(|err| panic!("{}", err));
 
-        let mut file = File::open(&APP_ARGS.config).expect("cannot find config file");
-        let mut conf = String::new();
+        // Validate configuration
+        Self::validate(&config);
 
-        file.read_to_string(&mut conf)
-            .expect("cannot read config file");
+        config
+    }
 
-        debug!("read config file: {}", &APP_ARGS.config);
+    pub fn reload() -> Result<Config, ()> {
+        // Notice: a reloaded configuration is not validated, as the validated options only \
+        //   apply upon startup; a reload failure must not take the server down either.
+        Self::read().map_err(|err| error!("could not reload configuration: {}", err))
+    }
 
-        // Parse configuration
-        let config = toml::from_str(&conf).expect("syntax error in config file");
+    fn read() -> Result<Config, String> {
+        let mut merged = Table::new();
 
-        // Validate configuration
-        Self::validate(&config);
+        // Read all configuration files in order, and merge them (last file wins)
+        for path in &APP_ARGS.config {
+            debug!("reading config file: {}", path);
 
-        config
+            let mut conf = String::new();
+
+            File::open(path)
+                .map_err(|err| format!("cannot find config file: {}", err))?
+                .read_to_string(&mut conf)
+                .map_err(|err| format!("cannot read config file: {}", err))?;
+
+            debug!("read config file: {}", path);
+
+            Self::merge(
+                &mut merged,
+                toml::from_str(&conf)
+                    .map_err(|err| format!("syntax error in config file: {}", err))?,
+            );
+        }
+
+        // Parse configuration
+        Value::Table(merged)
+            .try_into()
+            .map_err(|err| format!("invalid configuration: {}", err))
+    }
+
+    fn merge(base: &mut Table, overlay: Table) {
+        // Merge tables recursively, while other values from overlay replace those from base
+        for (key, value) in overlay {
+            match (base.get_mut(&key), value) {
+                (Some(Value::Table(base_table)), Value::Table(overlay_table)) => {
+                    Self::merge(base_table, overlay_table)
+                }
+                (_, value) => {
+                    base.insert(key, value);
+                }
+            }
+        }
     }
 
     fn validate(config: &Config) {
@@ -50,3 +89,31 @@ impl ConfigReader {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_merges_tables() {
+        let mut base: Table = toml::from_str(
+            "[server]\nlog_level = \"error\"\n\n[channel]\ninet = \"[::1]:1491\"\n\
+             tcp_timeout = 300\n",
+        )
+        .unwrap();
+
+        ConfigReader::merge(
+            &mut base,
+            toml::from_str("[channel]\ntcp_timeout = 60\n\n[store]\npath = \"./data/\"\n").unwrap(),
+        );
+
+        assert_eq!(
+            base,
+            toml::from_str(
+                "[server]\nlog_level = \"error\"\n\n[channel]\ninet = \"[::1]:1491\"\n\
+                 tcp_timeout = 60\n\n[store]\npath = \"./data/\"\n",
+            )
+            .unwrap()
+        );
+    }
+}
@@ -0,0 +1,75 @@
+// Sonic
+//
+// Fast, lightweight and schema-less search backend
+// Copyright: 2019, Valerian Saliou <valerian@valeriansaliou.name>
+// License: Mozilla Public License v2.0 (MPL v2.0)
+
+use crate::store::fst::{StoreFSTMisc, StoreFSTPool};
+use crate::store::item::StoreItem;
+use crate::store::kv::{StoreKVAcquireMode, StoreKVMisc, StoreKVPool};
+
+pub struct ExecutorClone;
+
+impl ExecutorClone {
+    pub fn execute(source: StoreItem, target: StoreItem) -> Result<Option<(u32, usize)>, ()> {
+        if let (
+            StoreItem(source_collection, None, None),
+            StoreItem(target_collection, None, None),
+        ) = (source, target)
+        {
+            let (source_str, target_str) = (source_collection.as_str(), target_collection.as_str());
+
+            {
+                // Acquire FST lock in write mode, as we will close graphs, we need to prevent \
+                //   any other consumer to use them.
+                general_fst_access_lock_write!();
+
+                // Notice: pending source FST changes are consolidated upon draining, so that \
+                //   they get cloned. Target graphs are drained as well, as any opened empty \
+                //   graph would otherwise hide cloned graphs.
+                StoreFSTPool::drain(source_str);
+                StoreFSTPool::drain(target_str);
+            }
+
+            // Important: acquire database and graph access read locks, and reference them in \
+            //   context. This prevents the database and graph from being erased while using \
+            //   them in this block.
+            general_kv_access_lock_read!();
+            general_fst_access_lock_read!();
+
+            // Target collection must not exist (cloning over existing objects would mix up IIDs)
+            if StoreKVPool::acquire(StoreKVAcquireMode::OpenOnly, target_collection)?.is_some()
+                || StoreFSTMisc::count_collection_buckets(target_str)? > 0
+            {
+                info!(
+                    "clone executor aborted as target collection exists: {}",
+                    target_str
+                );
+
+                return Ok(None);
+            }
+
+            if let (Ok(source_kv_store), Ok(target_kv_store)) = (
+                StoreKVPool::acquire(StoreKVAcquireMode::OpenOnly, source_collection),
+                StoreKVPool::acquire(StoreKVAcquireMode::Any, target_collection),
+            ) {
+                // Important: acquire source store read lock, and target store write lock
+                executor_kv_lock_read!(source_kv_store);
+                executor_kv_lock_write!(target_kv_store);
+
+                let count_objects =
+                    StoreKVMisc::batch_copy_collection(&source_kv_store, &target_kv_store)?;
+                let count_buckets = StoreFSTMisc::copy_collection(source_str, target_str)?;
+
+                info!(
+                    "clone executor cloned collection: {} to: {} ({} objects in {} buckets)",
+                    source_str, target_str, count_objects, count_buckets
+                );
+
+                return Ok(Some((count_objects, count_buckets)));
+            }
+        }
+
+        Err(())
+    }
+}
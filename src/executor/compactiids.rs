@@ -0,0 +1,185 @@
+// Sonic
+//
+// Fast, lightweight and schema-less search backend
+// Copyright: 2019, Valerian Saliou <valerian@valeriansaliou.name>
+// License: Mozilla Public License v2.0 (MPL v2.0)
+
+use hashbrown::HashMap;
+
+use crate::store::identifiers::{StoreMetaKey, StoreMetaValue, StoreObjectIID};
+use crate::store::item::StoreItem;
+use crate::store::kv::{StoreKVAcquireMode, StoreKVActionBuilder, StoreKVPool};
+
+pub struct ExecutorCompactIIDs;
+
+impl ExecutorCompactIIDs {
+    pub fn execute(store: StoreItem) -> Result<u32, ()> {
+        if let StoreItem(collection, Some(bucket), None) = store {
+            // Important: acquire database access read lock, and reference it in context. This \
+            //   prevents the database from being erased while using it in this block.
+            general_kv_access_lock_read!();
+
+            if let Ok(kv_store) = StoreKVPool::acquire(StoreKVAcquireMode::OpenOnly, collection) {
+                // Important: acquire bucket store write lock (all IIDs will be re-written, thus \
+                //   no other executor should be able to read or write the store meanwhile)
+                executor_kv_lock_write!(kv_store);
+
+                let kv_action = StoreKVActionBuilder::access(bucket, kv_store);
+
+                // Step 1: List all IIDs in bucket, and build a dense remapping (IIDs are sorted \
+                //   in ascending order, so that a new IID is always lower or equal to its old \
+                //   IID; this guarantees that no unprocessed IID gets overwritten below)
+                let mut iids_to_oid = kv_action.scan_iid_to_oid()?;
+
+                iids_to_oid.sort_unstable_by_key(|(iid, _)| *iid);
+
+                let iids_remap: HashMap<StoreObjectIID, StoreObjectIID> = iids_to_oid
+                    .iter()
+                    .enumerate()
+                    .map(|(index, (iid, _))| (*iid, index as StoreObjectIID))
+                    .collect();
+
+                info!(
+                    "compact iids executor got {} objects in bucket: {}",
+                    iids_remap.len(),
+                    bucket.as_str()
+                );
+
+                // Step 2: Move objects from their old IID to their new IID
+                let mut count_compacted = 0;
+
+                for (iid, oid) in &iids_to_oid {
+                    let iid_new = iids_remap[iid];
+
+                    if iid_new != *iid {
+                        debug!("compact iids executor moving iid: {} to: {}", iid, iid_new);
+
+                        let iid_terms = kv_action.get_iid_to_terms(*iid)?.unwrap_or_default();
+
+                        executor_ensure_op!(kv_action.set_oid_to_iid(oid, iid_new));
+                        executor_ensure_op!(kv_action.set_iid_to_oid(iid_new, oid));
+                        executor_ensure_op!(kv_action.set_iid_to_terms(iid_new, &iid_terms));
+
+                        executor_ensure_op!(kv_action.delete_iid_to_oid(*iid));
+                        executor_ensure_op!(kv_action.delete_iid_to_terms(*iid));
+
+                        // Move location? (if any)
+                        if let Some(iid_geo) = kv_action.get_iid_to_geo(*iid)? {
+                            executor_ensure_op!(kv_action.set_iid_to_geo(iid_new, iid_geo));
+                            executor_ensure_op!(kv_action.delete_iid_to_geo(*iid));
+                        }
+
+                        // Move weight? (if any)
+                        if let Some(iid_weight) = kv_action.get_iid_to_weight(*iid)? {
+                            executor_ensure_op!(kv_action.set_iid_to_weight(iid_new, iid_weight));
+                            executor_ensure_op!(kv_action.delete_iid_to_weight(*iid));
+                        }
+
+                        // Move original text? (if any)
+                        if let Some(iid_text) = kv_action.get_iid_to_text(*iid)? {
+                            executor_ensure_op!(kv_action.set_iid_to_text(iid_new, &iid_text));
+                            executor_ensure_op!(kv_action.delete_iid_to_text(*iid));
+                        }
+
+                        // Move tags? (if any)
+                        if let Some(iid_tags) = kv_action.get_iid_to_tags(*iid)? {
+                            executor_ensure_op!(kv_action.set_iid_to_tags(iid_new, &iid_tags));
+                            executor_ensure_op!(kv_action.delete_iid_to_tags(*iid));
+                        }
+
+                        // Move vector? (if any)
+                        if let Some(iid_vector) = kv_action.get_iid_to_vector(*iid)? {
+                            executor_ensure_op!(kv_action.set_iid_to_vector(iid_new, &iid_vector));
+                            executor_ensure_op!(kv_action.delete_iid_to_vector(*iid));
+                        }
+
+                        // Move creation time? (if any)
+                        if let Some(iid_created) = kv_action.get_iid_to_created(*iid)? {
+                            executor_ensure_op!(kv_action.set_iid_to_created(iid_new, iid_created));
+                            executor_ensure_op!(kv_action.delete_iid_to_created(*iid));
+                        }
+
+                        // Move segment? (if any)
+                        if let Some(iid_segment) = kv_action.get_iid_to_segment(*iid)? {
+                            executor_ensure_op!(kv_action.set_iid_to_segment(iid_new, iid_segment));
+                            executor_ensure_op!(kv_action.delete_iid_to_segment(*iid));
+                        }
+
+                        // Move priority? (if any)
+                        if let Some(iid_priority) = kv_action.get_iid_to_priority(*iid)? {
+                            executor_ensure_op!(
+                                kv_action.set_iid_to_priority(iid_new, iid_priority)
+                            );
+                            executor_ensure_op!(kv_action.delete_iid_to_priority(*iid));
+                        }
+
+                        // Move term positions? (if any)
+                        if let Some(iid_positions) = kv_action.get_iid_to_positions(*iid)? {
+                            executor_ensure_op!(
+                                kv_action.set_iid_to_positions(iid_new, &iid_positions)
+                            );
+                            executor_ensure_op!(kv_action.delete_iid_to_positions(*iid));
+                        }
+
+                        count_compacted += 1;
+                    }
+                }
+
+                // Step 3: Re-write IIDs linked to terms (dangling IIDs are dropped on the way)
+                for (term_hashed, term_iids) in kv_action.scan_term_to_iids()? {
+                    let term_iids_new: Vec<StoreObjectIID> = term_iids
+                        .iter()
+                        .filter_map(|iid| iids_remap.get(iid).copied())
+                        .collect();
+
+                    if term_iids_new != term_iids {
+                        if term_iids_new.is_empty() {
+                            executor_ensure_op!(kv_action.delete_term_to_iids(term_hashed));
+                        } else {
+                            executor_ensure_op!(
+                                kv_action.set_term_to_iids(term_hashed, &term_iids_new)
+                            );
+                        }
+                    }
+                }
+
+                // Step 4: Re-write IIDs linked to geo cells (dangling IIDs are dropped too)
+                for (cell, cell_iids) in kv_action.scan_geo_cell_to_iids()? {
+                    let cell_iids_new: Vec<StoreObjectIID> = cell_iids
+                        .iter()
+                        .filter_map(|iid| iids_remap.get(iid).copied())
+                        .collect();
+
+                    if cell_iids_new != cell_iids {
+                        if cell_iids_new.is_empty() {
+                            executor_ensure_op!(kv_action.delete_geo_cell_to_iids(cell));
+                        } else {
+                            executor_ensure_op!(
+                                kv_action.set_geo_cell_to_iids(cell, &cell_iids_new)
+                            );
+                        }
+                    }
+                }
+
+                // Step 5: Reset IID increment to the last allocated IID (if bucket is empty, \
+                //   leave the increment as-is; there is nothing to compact)
+                if !iids_remap.is_empty() {
+                    executor_ensure_op!(kv_action.set_meta_to_value(
+                        StoreMetaKey::IIDIncr,
+                        StoreMetaValue::IIDIncr((iids_remap.len() - 1) as StoreObjectIID),
+                    ));
+                }
+
+                info!(
+                    "compact iids executor moved {} objects in bucket: {}",
+                    count_compacted,
+                    bucket.as_str()
+                );
+
+                return Ok(count_compacted);
+            }
+        }
+
+        Err(())
+    }
+}
@@ -0,0 +1,35 @@
+// Sonic
+//
+// Fast, lightweight and schema-less search backend
+// Copyright: 2019, Valerian Saliou <valerian@valeriansaliou.name>
+// License: Mozilla Public License v2.0 (MPL v2.0)
+
+use std::path::Path;
+
+use crate::store::fst::StoreFSTActionBuilder;
+use crate::store::fst::StoreFSTPool;
+use crate::store::item::StoreItem;
+
+pub struct ExecutorDumpFST;
+
+impl ExecutorDumpFST {
+    pub fn execute(store: StoreItem, path: &Path) -> Result<u32, ()> {
+        if let StoreItem(collection, Some(bucket), None) = store {
+            // Important: acquire graph access read lock, and reference it in context. This \
+            //   prevents the graph from being erased while using it in this block.
+            general_fst_access_lock_read!();
+
+            if let Ok(fst_store) = StoreFSTPool::acquire(collection, bucket) {
+                let fst_action = StoreFSTActionBuilder::access(fst_store);
+
+                debug!("running dump fst to path: {:?}", path);
+
+                return fst_action.dump_words(path).map_err(|err| {
+                    error!("failed dumping fst to path: {:?} because: {}", path, err);
+                });
+            }
+        }
+
+        Err(())
+    }
+}
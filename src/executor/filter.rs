@@ -0,0 +1,126 @@
+// Sonic
+//
+// Fast, lightweight and schema-less search backend
+// Copyright: 2019, Valerian Saliou <valerian@valeriansaliou.name>
+// License: Mozilla Public License v2.0 (MPL v2.0)
+
+use hashbrown::HashSet;
+use linked_hash_set::LinkedHashSet;
+
+use crate::query::types::{QueryGenericSegment, QuerySearchRecent, QuerySearchShard};
+use crate::store::identifiers::{StoreObjectIID, StoreTermHashed, StoreTimestamp};
+use crate::store::kv::StoreKVAction;
+
+pub struct ExecutorFilter;
+
+impl ExecutorFilter {
+    pub fn filter_iids(
+        kv_action: &StoreKVAction,
+        found_iids: LinkedHashSet<StoreObjectIID>,
+        tags: &[String],
+    ) -> LinkedHashSet<StoreObjectIID> {
+        // Only keep IIDs that hold all required tags (stored tags are sorted, thus they can be \
+        //   binary-searched; objects without any tag are never kept)
+        found_iids
+            .into_iter()
+            .filter(|iid| {
+                if let Ok(Some(iid_tags)) = kv_action.get_iid_to_tags(*iid) {
+                    tags.iter().all(|tag| iid_tags.binary_search(tag).is_ok())
+                } else {
+                    false
+                }
+            })
+            .collect()
+    }
+
+    pub fn recent_iids(
+        kv_action: &StoreKVAction,
+        found_iids: LinkedHashSet<StoreObjectIID>,
+        recent: QuerySearchRecent,
+    ) -> LinkedHashSet<StoreObjectIID> {
+        // Only keep IIDs created within the recent period (objects without a creation time, ie. \
+        //   that were indexed before creation times were stored, are never kept)
+        let created_after = StoreTimestamp::now().saturating_sub(recent);
+
+        found_iids
+            .into_iter()
+            .filter(|iid| {
+                matches!(
+                    kv_action.get_iid_to_created(*iid),
+                    Ok(Some(iid_created)) if iid_created >= created_after
+                )
+            })
+            .collect()
+    }
+
+    pub fn segment_iids(
+        kv_action: &StoreKVAction,
+        found_iids: LinkedHashSet<StoreObjectIID>,
+        segment: QueryGenericSegment,
+    ) -> LinkedHashSet<StoreObjectIID> {
+        // Only keep IIDs that belong to the segment (objects without any segment are never kept)
+        found_iids
+            .into_iter()
+            .filter(|iid| {
+                matches!(
+                    kv_action.get_iid_to_segment(*iid),
+                    Ok(Some(iid_segment)) if iid_segment == segment
+                )
+            })
+            .collect()
+    }
+
+    pub fn shard_iids(
+        found_iids: LinkedHashSet<StoreObjectIID>,
+        (shard_id, total_shards): QuerySearchShard,
+    ) -> LinkedHashSet<StoreObjectIID> {
+        // Only keep IIDs that belong to the shard (IIDs are partitioned by their remainder, thus \
+        //   each IID belongs to exactly one shard)
+        found_iids
+            .into_iter()
+            .filter(|iid| *iid % total_shards == shard_id)
+            .collect()
+    }
+
+    pub fn exclude_iids(
+        kv_action: &StoreKVAction,
+        found_iids: LinkedHashSet<StoreObjectIID>,
+        must_not_terms: &[Vec<(String, StoreTermHashed)>],
+    ) -> LinkedHashSet<StoreObjectIID> {
+        // Unite IIDs linked to all excluded words (excluded words are lexed as search terms \
+        //   are, thus a word may yield multiple terms, eg. n-grams, which IIDs must then all \
+        //   be linked to)
+        let mut excluded_iids: HashSet<StoreObjectIID> = HashSet::new();
+
+        for must_not_word_terms in must_not_terms {
+            let mut word_iids: Option<HashSet<StoreObjectIID>> = None;
+
+            for (term, term_hashed) in must_not_word_terms {
+                let term_iids: HashSet<StoreObjectIID> = kv_action
+                    .get_term_to_iids(*term_hashed)
+                    .unwrap_or(None)
+                    .unwrap_or_default()
+                    .into_iter()
+                    .collect();
+
+                debug!(
+                    "got search executor excluded iids: {:?} for term: {}",
+                    term_iids, term
+                );
+
+                word_iids = Some(match word_iids {
+                    Some(word_iids) => word_iids.intersection(&term_iids).copied().collect(),
+                    None => term_iids,
+                });
+            }
+
+            excluded_iids.extend(word_iids.unwrap_or_default());
+        }
+
+        // Subtract excluded IIDs from found IIDs
+        found_iids
+            .into_iter()
+            .filter(|iid| !excluded_iids.contains(iid))
+            .collect()
+    }
+}
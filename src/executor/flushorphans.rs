@@ -0,0 +1,78 @@
+// Sonic
+//
+// Fast, lightweight and schema-less search backend
+// Copyright: 2019, Valerian Saliou <valerian@valeriansaliou.name>
+// License: Mozilla Public License v2.0 (MPL v2.0)
+
+use crate::store::identifiers::{StoreMetaKey, StoreMetaValue};
+use crate::store::item::StoreItem;
+use crate::store::kv::{StoreKVAcquireMode, StoreKVActionBuilder, StoreKVPool};
+
+pub struct ExecutorFlushOrphans;
+
+impl ExecutorFlushOrphans {
+    pub fn execute(store: StoreItem) -> Result<u32, ()> {
+        if let StoreItem(collection, Some(bucket), None) = store {
+            // Important: acquire database access read lock, and reference it in context. This \
+            //   prevents the database from being erased while using it in this block.
+            general_kv_access_lock_read!();
+
+            if let Ok(kv_store) = StoreKVPool::acquire(StoreKVAcquireMode::OpenOnly, collection) {
+                // Important: acquire bucket store write lock (held for the whole scan)
+                executor_kv_lock_write!(kv_store);
+
+                if kv_store.is_none() {
+                    // Store does not exist, consider as already clean.
+                    debug!(
+                        "collection store does not exist, consider {} from {} without orphans",
+                        bucket.as_str(),
+                        collection.as_str()
+                    );
+
+                    return Ok(0);
+                }
+
+                let kv_action = StoreKVActionBuilder::access(bucket, kv_store);
+
+                // Acquire last allocated IID (if none, then no IID was ever allocated)
+                let iid_incr = match kv_action.get_meta_to_value(StoreMetaKey::IIDIncr)? {
+                    Some(StoreMetaValue::IIDIncr(iid_incr)) => iid_incr,
+                    _ => return Ok(0),
+                };
+
+                info!(
+                    "flush orphans executor scanning iids up to: {} in bucket: {}",
+                    iid_incr,
+                    bucket.as_str()
+                );
+
+                let mut count_orphans = 0;
+
+                for iid in 0..=iid_incr {
+                    // An orphan IID still has terms linked to it, but is not linked to any OID
+                    if kv_action.get_iid_to_oid(iid)?.is_none() {
+                        if let Some(iid_terms) = kv_action.get_iid_to_terms(iid)? {
+                            debug!("flush orphans executor found orphan iid: {}", iid);
+
+                            // Notice: there is no OID to unlink there, thus pass an empty OID \
+                            //   (an OID can never be empty, so this does not unlink any object)
+                            kv_action.batch_flush_bucket(iid, "", &iid_terms)?;
+
+                            count_orphans += 1;
+                        }
+                    }
+                }
+
+                info!(
+                    "flush orphans executor removed {} orphans in bucket: {}",
+                    count_orphans,
+                    bucket.as_str()
+                );
+
+                return Ok(count_orphans);
+            }
+        }
+
+        Err(())
+    }
+}
@@ -0,0 +1,59 @@
+// Sonic
+//
+// Fast, lightweight and schema-less search backend
+// Copyright: 2019, Valerian Saliou <valerian@valeriansaliou.name>
+// License: Mozilla Public License v2.0 (MPL v2.0)
+
+use crate::lexer::token::TokenLexer;
+use crate::store::fst::{StoreFSTActionBuilder, StoreFSTPool};
+use crate::store::item::StoreItem;
+use crate::store::kv::{StoreKVAcquireMode, StoreKVMisc, StoreKVPool};
+
+pub struct ExecutorFlushT;
+
+impl ExecutorFlushT {
+    pub fn execute<'a>(store: StoreItem<'a>, lexer: TokenLexer<'a>) -> Result<u32, ()> {
+        if let StoreItem(collection, None, None) = store {
+            // Important: acquire database access read lock, and reference it in context. This \
+            //   prevents the database from being erased while using it in this block.
+            general_kv_access_lock_read!();
+            general_fst_access_lock_read!();
+
+            if let Ok(kv_store) = StoreKVPool::acquire(StoreKVAcquireMode::OpenOnly, collection) {
+                // Important: acquire collection store write lock (terms are flushed from all \
+                //   buckets at once)
+                executor_kv_lock_write!(kv_store);
+
+                let mut count = 0;
+
+                for (term, term_hashed) in lexer {
+                    let term_buckets =
+                        StoreKVMisc::batch_flush_collection_term(&kv_store, term_hashed)?;
+
+                    info!(
+                        "flusht executor flushed term: {} from {} buckets",
+                        term,
+                        term_buckets.len()
+                    );
+
+                    for (bucket_atom, term_count) in term_buckets {
+                        count += term_count;
+
+                        // Pop from FST graph (no IID is linked to term anymore)
+                        if let Ok(fst_store) = StoreFSTPool::acquire_atom(collection, bucket_atom) {
+                            let fst_action = StoreFSTActionBuilder::access(fst_store);
+
+                            if fst_action.pop_word(&term) {
+                                debug!("flusht term popped from graph: {}", term);
+                            }
+                        }
+                    }
+                }
+
+                return Ok(count);
+            }
+        }
+
+        Err(())
+    }
+}
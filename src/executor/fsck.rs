@@ -0,0 +1,59 @@
+// Sonic
+//
+// Fast, lightweight and schema-less search backend
+// Copyright: 2019, Valerian Saliou <valerian@valeriansaliou.name>
+// License: Mozilla Public License v2.0 (MPL v2.0)
+
+use hashbrown::HashSet;
+
+use crate::store::fst::StoreFSTMisc;
+use crate::store::kv::StoreKVMisc;
+
+pub struct ExecutorFsck;
+
+impl ExecutorFsck {
+    pub fn execute() -> Result<(usize, usize), ()> {
+        // Important: acquire database access read lock, and reference it in context. This \
+        //   prevents the database from being erased or restored while using it in this block.
+        // Notice: graph locks are acquired while checking graphs, as they must be acquired in a \
+        //   specific order.
+        general_kv_access_lock_read!();
+
+        let (kv_collections, count_kv_invalid) = StoreKVMisc::fsck_collections()?;
+        let (fst_collections, count_fst_leftovers, count_fst_invalid) =
+            StoreFSTMisc::fsck_collections()?;
+
+        // Cross-check collections (each collection should exist in both stores)
+        let kv_collections: HashSet<String> = kv_collections.into_iter().collect();
+        let fst_collections: HashSet<String> = fst_collections.into_iter().collect();
+
+        let mut count_mismatches = 0;
+
+        for collection in kv_collections.difference(&fst_collections) {
+            warn!("fsck found kv collection without fst: {}", collection);
+
+            count_mismatches += 1;
+        }
+
+        for collection in fst_collections.difference(&kv_collections) {
+            warn!("fsck found fst collection without kv: {}", collection);
+
+            count_mismatches += 1;
+        }
+
+        let (count_warnings, count_errors) = (
+            count_fst_leftovers + count_mismatches,
+            count_kv_invalid + count_fst_invalid,
+        );
+
+        info!(
+            "fsck executor checked {} kv and {} fst collections, with {} warnings and {} errors",
+            kv_collections.len(),
+            fst_collections.len(),
+            count_warnings,
+            count_errors
+        );
+
+        Ok((count_warnings, count_errors))
+    }
+}
@@ -0,0 +1,158 @@
+// Sonic
+//
+// Fast, lightweight and schema-less search backend
+// Copyright: 2019, Valerian Saliou <valerian@valeriansaliou.name>
+// License: Mozilla Public License v2.0 (MPL v2.0)
+
+use hashbrown::HashSet;
+use linked_hash_set::LinkedHashSet;
+
+use crate::query::types::QuerySearchWithinDistance;
+use crate::store::identifiers::{StoreGeoCell, StoreGeoCellHash, StoreGeoPoint, StoreObjectIID};
+use crate::store::kv::StoreKVAction;
+
+pub struct ExecutorGeo;
+
+const GEO_EARTH_RADIUS: f64 = 6371.0;
+const GEO_COVER_CELLS_MAXIMUM: usize = 64;
+
+impl ExecutorGeo {
+    pub fn filter_iids(
+        kv_action: &StoreKVAction,
+        found_iids: LinkedHashSet<StoreObjectIID>,
+        within_distance: QuerySearchWithinDistance,
+    ) -> LinkedHashSet<StoreObjectIID> {
+        let (center, distance) = within_distance;
+
+        // Acquire all IIDs located in grid cells that cover the search area (if the area spans \
+        //   too many cells, this is skipped and all found IIDs get their distance checked)
+        let candidate_iids: Option<HashSet<StoreObjectIID>> = Self::cover_cells(center, distance)
+            .map(|cells| {
+                cells
+                    .into_iter()
+                    .flat_map(|cell| {
+                        kv_action
+                            .get_geo_cell_to_iids(cell)
+                            .unwrap_or(None)
+                            .unwrap_or_default()
+                    })
+                    .collect()
+            });
+
+        found_iids
+            .into_iter()
+            .filter(|iid| {
+                if let Some(ref candidate_iids) = candidate_iids {
+                    if !candidate_iids.contains(iid) {
+                        return false;
+                    }
+                }
+
+                if let Ok(Some(point)) = kv_action.get_iid_to_geo(*iid) {
+                    Self::haversine_distance(center, point) <= distance
+                } else {
+                    false
+                }
+            })
+            .collect()
+    }
+
+    pub fn haversine_distance(from: StoreGeoPoint, to: StoreGeoPoint) -> f32 {
+        let (from_latitude, to_latitude) =
+            ((from.0 as f64).to_radians(), (to.0 as f64).to_radians());
+
+        let (delta_latitude, delta_longitude) = (
+            to_latitude - from_latitude,
+            ((to.1 - from.1) as f64).to_radians(),
+        );
+
+        let haversine = (delta_latitude / 2.0).sin().powi(2)
+            + from_latitude.cos() * to_latitude.cos() * (delta_longitude / 2.0).sin().powi(2);
+
+        (2.0 * GEO_EARTH_RADIUS * haversine.sqrt().min(1.0).asin()) as f32
+    }
+
+    fn cover_cells(center: StoreGeoPoint, distance: f32) -> Option<Vec<StoreGeoCell>> {
+        // Compute the bounding box of the search area (see: 'Finding Points Within a Distance \
+        //   of a Latitude/Longitude Using Bounding Coordinates', by Jan Philip Matuschek)
+        let (latitude, angular_distance) = (
+            (center.0 as f64).to_radians(),
+            distance as f64 / GEO_EARTH_RADIUS,
+        );
+
+        let (latitude_min, latitude_max) = (
+            (latitude - angular_distance).to_degrees(),
+            (latitude + angular_distance).to_degrees(),
+        );
+        let longitude_ratio = angular_distance.sin() / latitude.cos();
+
+        // Notice: areas that contain a pole or cross the antimeridian are not covered (they are \
+        //   rare enough to fallback on checking all found IIDs)
+        if latitude_min < -90.0 || latitude_max > 90.0 || longitude_ratio >= 1.0 {
+            return None;
+        }
+
+        let longitude_delta = longitude_ratio.asin().to_degrees();
+
+        let (longitude_min, longitude_max) = (
+            center.1 as f64 - longitude_delta,
+            center.1 as f64 + longitude_delta,
+        );
+
+        if longitude_min < -180.0 || longitude_max > 180.0 {
+            return None;
+        }
+
+        let ((row_min, column_min), (row_max, column_max)) = (
+            StoreGeoCellHash::to_grid((latitude_min as f32, longitude_min as f32)),
+            StoreGeoCellHash::to_grid((latitude_max as f32, longitude_max as f32)),
+        );
+
+        if ((row_max - row_min + 1) * (column_max - column_min + 1)) as usize
+            > GEO_COVER_CELLS_MAXIMUM
+        {
+            return None;
+        }
+
+        Some(
+            (row_min..=row_max)
+                .flat_map(|row| {
+                    (column_min..=column_max)
+                        .map(move |column| StoreGeoCellHash::from_grid(row, column))
+                })
+                .collect(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_computes_haversine_distance() {
+        assert_eq!(
+            ExecutorGeo::haversine_distance((48.85, 2.35), (48.85, 2.35)),
+            0.0
+        );
+
+        let distance = ExecutorGeo::haversine_distance((48.8566, 2.3522), (51.5074, -0.1278));
+
+        assert!(distance > 340.0 && distance < 345.0);
+    }
+
+    #[test]
+    fn it_covers_cells() {
+        assert_eq!(
+            ExecutorGeo::cover_cells((48.85, 2.35), 10.0),
+            Some(vec![StoreGeoCellHash::from((48.85, 2.35))])
+        );
+        assert_eq!(
+            ExecutorGeo::cover_cells((48.5, 2.5), 100.0).map(|cells| cells.len()),
+            Some(9)
+        );
+        assert_eq!(ExecutorGeo::cover_cells((89.9, 2.35), 50.0), None);
+        assert_eq!(ExecutorGeo::cover_cells((0.0, 179.9), 50.0), None);
+        assert_eq!(ExecutorGeo::cover_cells((48.85, 2.35), 5000.0), None);
+    }
+}
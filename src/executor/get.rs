@@ -0,0 +1,40 @@
+// Sonic
+//
+// Fast, lightweight and schema-less search backend
+// Copyright: 2019, Valerian Saliou <valerian@valeriansaliou.name>
+// License: Mozilla Public License v2.0 (MPL v2.0)
+
+use crate::store::item::StoreItem;
+use crate::store::kv::{StoreKVAcquireMode, StoreKVActionBuilder, StoreKVPool};
+
+pub struct ExecutorGet;
+
+impl ExecutorGet {
+    pub fn execute(store: StoreItem) -> Result<Option<String>, ()> {
+        if let StoreItem(collection, Some(bucket), Some(object)) = store {
+            // Important: acquire database access read lock, and reference it in context. This \
+            //   prevents the database from being erased while using it in this block.
+            general_kv_access_lock_read!();
+
+            if let Ok(kv_store) = StoreKVPool::acquire(StoreKVAcquireMode::OpenOnly, collection) {
+                // Important: acquire bucket store read lock
+                executor_kv_lock_read!(kv_store);
+
+                let kv_action = StoreKVActionBuilder::access(bucket, kv_store);
+
+                // Resolve OID to IID, then read original text stored for IID (if any)
+                let oid = object.as_str();
+
+                if let Some(iid) = kv_action.get_oid_to_iid(oid)? {
+                    debug!("get executor resolved oid: {} to iid: {}", oid, iid);
+
+                    return kv_action.get_iid_to_text(iid);
+                }
+
+                return Ok(None);
+            }
+        }
+
+        Err(())
+    }
+}
@@ -0,0 +1,119 @@
+// Sonic
+//
+// Fast, lightweight and schema-less search backend
+// Copyright: 2019, Valerian Saliou <valerian@valeriansaliou.name>
+// License: Mozilla Public License v2.0 (MPL v2.0)
+
+use unicode_segmentation::UnicodeSegmentation;
+
+use crate::query::types::QuerySearchHighlightStyle;
+
+pub struct ExecutorHighlight;
+
+pub type ExecutorHighlightRange = (usize, usize);
+
+impl ExecutorHighlight {
+    pub fn locate(text: &str, terms: &[String]) -> Vec<ExecutorHighlightRange> {
+        // Locate all words in text that match any search term, as byte ranges (search terms are \
+        //   normalized, thus words are compared lower-cased; words are matched as prefixes, as \
+        //   search terms may have been completed with suggested words)
+        text.unicode_word_indices()
+            .filter(|(_, word)| {
+                let word = word.to_lowercase();
+
+                terms.iter().any(|term| word.starts_with(term.as_str()))
+            })
+            .map(|(index, word)| (index, index + word.len()))
+            .collect()
+    }
+
+    pub fn markup(
+        text: &str,
+        ranges: &[ExecutorHighlightRange],
+        style: QuerySearchHighlightStyle,
+    ) -> String {
+        let (mark_open, mark_close) = match style {
+            QuerySearchHighlightStyle::Html => ("<mark>", "</mark>"),
+            QuerySearchHighlightStyle::Markdown => ("**", "**"),
+            QuerySearchHighlightStyle::None => return text.to_string(),
+        };
+
+        // Wrap each matched range in markup (ranges are ordered and do not overlap)
+        let mut marked_text = String::with_capacity(text.len());
+        let mut last_index = 0;
+
+        for (start_index, end_index) in ranges {
+            marked_text.push_str(&text[last_index..*start_index]);
+            marked_text.push_str(mark_open);
+            marked_text.push_str(&text[*start_index..*end_index]);
+            marked_text.push_str(mark_close);
+
+            last_index = *end_index;
+        }
+
+        marked_text.push_str(&text[last_index..]);
+
+        marked_text
+    }
+
+    pub fn format_ranges(ranges: &[ExecutorHighlightRange]) -> String {
+        Self::join_ranges(ranges, ":")
+    }
+
+    pub fn format_offsets(ranges: &[ExecutorHighlightRange]) -> String {
+        Self::join_ranges(ranges, "-")
+    }
+
+    fn join_ranges(ranges: &[ExecutorHighlightRange], separator: &str) -> String {
+        ranges
+            .iter()
+            .map(|(start_index, end_index)| format!("{}{}{}", start_index, separator, end_index))
+            .collect::<Vec<String>>()
+            .join(",")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_locates_terms() {
+        assert_eq!(
+            ExecutorHighlight::locate("The quick brown fox", &["quick".to_string()]),
+            vec![(4, 9)]
+        );
+        assert_eq!(
+            ExecutorHighlight::locate(
+                "Éric runs, Eric ran",
+                &["éric".to_string(), "ru".to_string()]
+            ),
+            vec![(0, 5), (6, 10)]
+        );
+        assert_eq!(
+            ExecutorHighlight::locate("The quick brown fox", &["cat".to_string()]),
+            vec![]
+        );
+    }
+
+    #[test]
+    fn it_marks_up_ranges() {
+        let text = "the quick brown fox";
+        let ranges = [(4, 9), (16, 19)];
+
+        assert_eq!(
+            ExecutorHighlight::markup(text, &ranges, QuerySearchHighlightStyle::Html),
+            "the <mark>quick</mark> brown <mark>fox</mark>"
+        );
+        assert_eq!(
+            ExecutorHighlight::markup(text, &ranges, QuerySearchHighlightStyle::Markdown),
+            "the **quick** brown **fox**"
+        );
+        assert_eq!(
+            ExecutorHighlight::markup(text, &ranges, QuerySearchHighlightStyle::None),
+            text
+        );
+        assert_eq!(ExecutorHighlight::format_ranges(&ranges), "4:9,16:19");
+        assert_eq!(ExecutorHighlight::format_offsets(&ranges), "4-9,16-19");
+    }
+}
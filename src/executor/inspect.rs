@@ -0,0 +1,61 @@
+// Sonic
+//
+// Fast, lightweight and schema-less search backend
+// Copyright: 2019, Valerian Saliou <valerian@valeriansaliou.name>
+// License: Mozilla Public License v2.0 (MPL v2.0)
+
+use crate::query::types::QuerySearchLimit;
+use crate::store::identifiers::{StoreMetaKey, StoreMetaValue};
+use crate::store::item::StoreItem;
+use crate::store::kv::{StoreKVAcquireMode, StoreKVActionBuilder, StoreKVPool};
+
+pub struct ExecutorInspect;
+
+impl ExecutorInspect {
+    pub fn execute(store: StoreItem, limit: QuerySearchLimit) -> Result<Vec<String>, ()> {
+        if let StoreItem(collection, Some(bucket), None) = store {
+            // Important: acquire database access read lock, and reference it in context. This \
+            //   prevents the database from being erased while using it in this block.
+            general_kv_access_lock_read!();
+
+            if let Ok(kv_store) = StoreKVPool::acquire(StoreKVAcquireMode::OpenOnly, collection) {
+                // Important: acquire bucket store read lock
+                executor_kv_lock_read!(kv_store);
+
+                let kv_action = StoreKVActionBuilder::access(bucket, kv_store);
+
+                // Acquire last allocated IID (if none, then no object was ever stored)
+                let iid_incr = match kv_action.get_meta_to_value(StoreMetaKey::IIDIncr)? {
+                    Some(StoreMetaValue::IIDIncr(iid_incr)) => iid_incr,
+                    _ => return Ok(Vec::new()),
+                };
+
+                // List OIDs in ascending IID order, starting from the first IID (flushed IIDs \
+                //   are skipped, as they do not resolve to any OID anymore)
+                let mut oids = Vec::new();
+
+                for iid in 0..=iid_incr {
+                    if oids.len() >= limit as usize {
+                        break;
+                    }
+
+                    if let Some(oid) = kv_action.get_iid_to_oid(iid)? {
+                        debug!("inspect executor got iid: {} with oid: {}", iid, oid);
+
+                        oids.push(oid);
+                    }
+                }
+
+                info!(
+                    "inspect executor listed {} objects in bucket: {}",
+                    oids.len(),
+                    bucket.as_str()
+                );
+
+                return Ok(oids);
+            }
+        }
+
+        Err(())
+    }
+}
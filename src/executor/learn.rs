@@ -0,0 +1,80 @@
+// Sonic
+//
+// Fast, lightweight and schema-less search backend
+// Copyright: 2019, Valerian Saliou <valerian@valeriansaliou.name>
+// License: Mozilla Public License v2.0 (MPL v2.0)
+
+use std::cmp::Ordering;
+
+use crate::lexer::token::TokenLexer;
+use crate::store::item::{StoreItem, StoreItemPart};
+use crate::store::kv::{StoreKVAcquireMode, StoreKVMisc, StoreKVPool};
+
+pub struct ExecutorLearn;
+
+const LEARN_BOOST_STEP: f32 = 0.1;
+const LEARN_BOOST_MAXIMUM: f32 = 2.0;
+const LEARN_BOOSTS_LIMIT: usize = 200;
+
+impl ExecutorLearn {
+    pub fn execute<'a>(
+        store: StoreItem<'a>,
+        user: StoreItemPart<'a>,
+        lexer: TokenLexer<'a>,
+    ) -> Result<u32, ()> {
+        if let StoreItem(collection, None, None) = store {
+            // Important: acquire database access read lock, and reference it in context. This \
+            //   prevents the database from being erased while using it in this block.
+            general_kv_access_lock_read!();
+
+            if let Ok(kv_store) = StoreKVPool::acquire(StoreKVAcquireMode::Any, collection) {
+                // Important: acquire collection store write lock (user boosts are shared by all \
+                //   buckets, and are read then re-written)
+                executor_kv_lock_write!(kv_store);
+
+                let mut boosts =
+                    StoreKVMisc::get_user_to_boosts(&kv_store, user.as_str())?.unwrap_or_default();
+
+                let mut count = 0;
+
+                for (term, term_hashed) in lexer {
+                    // Bump boost factor for term (up to maximum), or insert it in first position \
+                    //   (as to retain the most recently learned terms upon truncation)
+                    if let Some(boost) = boosts.iter_mut().find(|boost| boost.0 == term_hashed) {
+                        boost.1 = (boost.1 + LEARN_BOOST_STEP).min(LEARN_BOOST_MAXIMUM);
+                    } else {
+                        boosts.insert(0, (term_hashed, 1.0 + LEARN_BOOST_STEP));
+                    }
+
+                    debug!(
+                        "learn executor bumped term: {} for user: {}",
+                        term,
+                        user.as_str()
+                    );
+
+                    count += 1;
+                }
+
+                // Truncate boosts? (only the strongest boosts are retained)
+                if boosts.len() > LEARN_BOOSTS_LIMIT {
+                    // Notice: this sort is stable, thus equally-boosted terms keep their order.
+                    boosts.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap_or(Ordering::Equal));
+                    boosts.truncate(LEARN_BOOSTS_LIMIT);
+                }
+
+                StoreKVMisc::set_user_to_boosts(&kv_store, user.as_str(), &boosts)?;
+
+                info!(
+                    "learn executor learned {} terms for user: {}, now {} boosted terms",
+                    count,
+                    user.as_str(),
+                    boosts.len()
+                );
+
+                return Ok(count);
+            }
+        }
+
+        Err(())
+    }
+}
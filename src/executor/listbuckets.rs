@@ -0,0 +1,36 @@
+// Sonic
+//
+// Fast, lightweight and schema-less search backend
+// Copyright: 2019, Valerian Saliou <valerian@valeriansaliou.name>
+// License: Mozilla Public License v2.0 (MPL v2.0)
+
+use crate::store::fst::StoreFSTMisc;
+use crate::store::item::StoreItem;
+
+pub struct ExecutorListBuckets;
+
+impl ExecutorListBuckets {
+    pub fn execute(store: StoreItem) -> Result<Vec<String>, ()> {
+        if let StoreItem(collection, None, None) = store {
+            // Important: acquire database access read lock, and reference it in context. This \
+            //   prevents the database from being erased while using it in this block.
+            general_fst_access_lock_read!();
+
+            // Notice: bucket names cannot be recovered from their on-disk hashes, thus hashes \
+            //   are listed as-is (sorted, as directory listings are unordered).
+            let mut buckets: Vec<String> =
+                StoreFSTMisc::list_collection_buckets(collection.as_str())?
+                    .into_iter()
+                    .map(|bucket_hash| format!("{:x?}", bucket_hash))
+                    .collect();
+
+            buckets.sort();
+
+            info!("list buckets executor found {} buckets", buckets.len());
+
+            return Ok(buckets);
+        }
+
+        Err(())
+    }
+}
@@ -0,0 +1,37 @@
+// Sonic
+//
+// Fast, lightweight and schema-less search backend
+// Copyright: 2019, Valerian Saliou <valerian@valeriansaliou.name>
+// License: Mozilla Public License v2.0 (MPL v2.0)
+
+use std::collections::BTreeSet;
+
+use crate::store::fst::StoreFSTMisc;
+use crate::store::kv::StoreKVMisc;
+
+pub struct ExecutorListCollections;
+
+impl ExecutorListCollections {
+    pub fn execute() -> Result<Vec<String>, ()> {
+        // Important: acquire database access read lock, and reference it in context. This \
+        //   prevents the database from being erased or restored while using it in this block.
+        general_kv_access_lock_read!();
+        general_fst_access_lock_read!();
+
+        // Unite collections from both stores (a collection may only exist in one store, eg. if \
+        //   nothing was consolidated to its FST yet)
+        // Notice: collection names cannot be recovered from their on-disk hashes, thus hashes \
+        //   are listed as-is (sorted, as directory listings are unordered).
+        let collections: BTreeSet<String> = StoreKVMisc::list_collections()?
+            .into_iter()
+            .chain(StoreFSTMisc::list_collections()?)
+            .collect();
+
+        info!(
+            "list collections executor found {} collections",
+            collections.len()
+        );
+
+        Ok(collections.into_iter().collect())
+    }
+}
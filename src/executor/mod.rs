@@ -7,12 +7,37 @@
 #[macro_use]
 mod macros;
 
+pub mod clone;
+pub mod compactiids;
 pub mod count;
+pub mod dumpfst;
+pub mod filter;
 pub mod flushb;
 pub mod flushc;
 pub mod flusho;
+pub mod flushorphans;
+pub mod flusht;
+pub mod fsck;
+pub mod geo;
+pub mod get;
+pub mod highlight;
+pub mod inspect;
+pub mod learn;
 pub mod list;
+pub mod listbuckets;
+pub mod listcollections;
+pub mod multibucketsearch;
 pub mod pop;
+pub mod proximity;
 pub mod push;
+pub mod rebuildreverseindex;
+pub mod reindex;
+pub mod reopen;
 pub mod search;
+pub mod spellcheck;
 pub mod suggest;
+pub mod suggestcontext;
+pub mod vacuum;
+pub mod verifyconsistency;
+pub mod verifyfst;
+pub mod vsearch;
@@ -0,0 +1,173 @@
+// Sonic
+//
+// Fast, lightweight and schema-less search backend
+// Copyright: 2019, Valerian Saliou <valerian@valeriansaliou.name>
+// License: Mozilla Public License v2.0 (MPL v2.0)
+
+use hashbrown::HashMap;
+use std::cmp::Ordering;
+
+use crate::executor::search::ExecutorSearch;
+use crate::query::actions::Query;
+use crate::query::builder::QueryBuilder;
+use crate::query::types::{
+    QuerySearchBucketWeight, QuerySearchID, QuerySearchLimit, QuerySearchOffset, QuerySearchOptions,
+};
+use crate::store::item::StoreItem;
+
+pub struct ExecutorMultiBucketSearch;
+
+impl ExecutorMultiBucketSearch {
+    pub fn execute<'a>(
+        store: StoreItem<'a>,
+        event_id: QuerySearchID,
+        terms: &str,
+        limit: QuerySearchLimit,
+        offset: QuerySearchOffset,
+        mut options: QuerySearchOptions,
+    ) -> Result<Option<Vec<String>>, ()> {
+        if let StoreItem(collection, Some(bucket), None) = store {
+            // Notice: the search bucket always comes first, and buckets are only searched once.
+            let mut buckets = vec![bucket.as_str().to_string()];
+
+            for other_bucket in options.buckets.drain(..) {
+                if !buckets.contains(&other_bucket) {
+                    buckets.push(other_bucket);
+                }
+            }
+
+            let bucket_weights = std::mem::take(&mut options.bucket_weights);
+
+            // Notice: each bucket must return enough results to fill the requested page once \
+            //   merged, thus paging is done after merging.
+            let bucket_limit = (limit as u32)
+                .saturating_add(offset)
+                .min(QuerySearchLimit::MAX as u32)
+                as QuerySearchLimit;
+
+            let mut bucket_results = Vec::with_capacity(buckets.len());
+
+            for bucket in &buckets {
+                // Notice: as bucket options do not list other buckets, a single-bucket search \
+                //   query gets built there.
+                if let Ok(Query::Search(store, query_id, lexer, limit, offset, options)) =
+                    QueryBuilder::search(
+                        event_id,
+                        collection.as_str(),
+                        bucket,
+                        terms,
+                        bucket_limit,
+                        0,
+                        options.clone(),
+                    )
+                {
+                    debug!("searching bucket: {} in multi-bucket search", bucket);
+
+                    let results =
+                        ExecutorSearch::execute(store, query_id, lexer, limit, offset, options)?
+                            .unwrap_or_default();
+
+                    bucket_results.push((Self::bucket_weight(bucket, &bucket_weights), results));
+                } else {
+                    return Err(());
+                }
+            }
+
+            let merged_results: Vec<String> = Self::merge_results(bucket_results)
+                .into_iter()
+                .skip(offset as usize)
+                .take(limit as usize)
+                .collect();
+
+            info!(
+                "got multi-bucket search executor final results: {:?}",
+                merged_results
+            );
+
+            return Ok(if !merged_results.is_empty() {
+                Some(merged_results)
+            } else {
+                None
+            });
+        }
+
+        Err(())
+    }
+
+    fn bucket_weight(bucket: &str, bucket_weights: &[QuerySearchBucketWeight]) -> f32 {
+        bucket_weights
+            .iter()
+            .find(|(weighted_bucket, _)| weighted_bucket == bucket)
+            .map(|(_, weight)| *weight)
+            .unwrap_or(1.0)
+    }
+
+    fn merge_results(bucket_results: Vec<(f32, Vec<String>)>) -> Vec<String> {
+        // Score each result from its rank in each bucket it was found in, multiplied by the \
+        //   bucket weight (results found in multiple buckets sum their scores)
+        let mut scored_results: Vec<(String, f32)> = Vec::new();
+        let mut scored_indexes: HashMap<String, usize> = HashMap::new();
+
+        for (weight, results) in bucket_results {
+            for (rank, result) in results.into_iter().enumerate() {
+                let score = weight / (rank + 1) as f32;
+
+                if let Some(index) = scored_indexes.get(&result) {
+                    scored_results[*index].1 += score;
+                } else {
+                    scored_indexes.insert(result.clone(), scored_results.len());
+                    scored_results.push((result, score));
+                }
+            }
+        }
+
+        // Notice: this sort is stable, thus equally-scored results keep their bucket order.
+        scored_results.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap_or(Ordering::Equal));
+
+        scored_results
+            .into_iter()
+            .map(|(result, _)| result)
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_merges_results() {
+        let to_results = |results: &[&str]| -> Vec<String> {
+            results.iter().map(|result| result.to_string()).collect()
+        };
+
+        assert_eq!(
+            ExecutorMultiBucketSearch::merge_results(vec![
+                (1.0, to_results(&["a", "b", "c"])),
+                (3.0, to_results(&["d", "b"])),
+            ]),
+            to_results(&["d", "b", "a", "c"])
+        );
+        assert_eq!(
+            ExecutorMultiBucketSearch::merge_results(vec![
+                (1.0, to_results(&["a"])),
+                (1.0, to_results(&["b"])),
+            ]),
+            to_results(&["a", "b"])
+        );
+    }
+
+    #[test]
+    fn it_weighs_buckets() {
+        let bucket_weights = vec![("title".to_string(), 3.0)];
+
+        assert_eq!(
+            ExecutorMultiBucketSearch::bucket_weight("title", &bucket_weights),
+            3.0
+        );
+        assert_eq!(
+            ExecutorMultiBucketSearch::bucket_weight("body", &bucket_weights),
+            1.0
+        );
+    }
+}
@@ -5,8 +5,9 @@
 // License: Mozilla Public License v2.0 (MPL v2.0)
 
 use linked_hash_set::LinkedHashSet;
-use std::iter::FromIterator;
+use std::iter::{self, FromIterator};
 
+use crate::lexer::synonyms::LexerSynonyms;
 use crate::lexer::token::TokenLexer;
 use crate::store::fst::{StoreFSTActionBuilder, StoreFSTPool};
 use crate::store::identifiers::StoreTermHashed;
@@ -51,7 +52,15 @@ impl ExecutorPop {
                                 iid_terms_hashed_vec
                             );
 
-                            let pop_terms: Vec<(String, StoreTermHashed)> = lexer.collect();
+                            // Notice: synonyms of popped terms are popped as well, as they \
+                            //   were pushed along with terms.
+                            let pop_terms: Vec<(String, StoreTermHashed)> = lexer
+                                .flat_map(|(term, term_hashed)| {
+                                    let synonyms = LexerSynonyms::expand_hashed(&term);
+
+                                    iter::once((term, term_hashed)).chain(synonyms)
+                                })
+                                .collect();
 
                             let iid_terms_hashed: LinkedHashSet<StoreTermHashed> =
                                 LinkedHashSet::from_iter(iid_terms_hashed_vec.iter().copied());
@@ -129,6 +138,19 @@ impl ExecutorPop {
                                     executor_ensure_op!(
                                         kv_action.set_iid_to_terms(iid, &remaining_terms_vec)
                                     );
+
+                                    // Drop positions of popped terms? (if any is stored)
+                                    if let Ok(Some(mut iid_positions)) =
+                                        kv_action.get_iid_to_positions(iid)
+                                    {
+                                        iid_positions.retain(|(term_hashed, _)| {
+                                            remaining_terms_vec.contains(term_hashed)
+                                        });
+
+                                        executor_ensure_op!(
+                                            kv_action.set_iid_to_positions(iid, &iid_positions)
+                                        );
+                                    }
                                 }
                             }
                         } else {
@@ -0,0 +1,163 @@
+// Sonic
+//
+// Fast, lightweight and schema-less search backend
+// Copyright: 2019, Valerian Saliou <valerian@valeriansaliou.name>
+// License: Mozilla Public License v2.0 (MPL v2.0)
+
+use linked_hash_set::LinkedHashSet;
+
+use crate::query::types::QuerySearchSpan;
+use crate::store::identifiers::{StoreObjectIID, StoreTermHashed, StoreTermPosition};
+use crate::store::kv::StoreKVAction;
+
+pub struct ExecutorProximity;
+
+impl ExecutorProximity {
+    pub fn span_iids(
+        kv_action: &StoreKVAction,
+        found_iids: LinkedHashSet<StoreObjectIID>,
+        terms: &[StoreTermHashed],
+        span: QuerySearchSpan,
+    ) -> LinkedHashSet<StoreObjectIID> {
+        // Only keep IIDs where all terms appear within span of each other (objects without any \
+        //   stored positions are never kept; terms that have no position in an object, ie. that \
+        //   were only found via a completed word, are left out)
+        found_iids
+            .into_iter()
+            .filter(|iid| {
+                if let Ok(Some(iid_positions)) = kv_action.get_iid_to_positions(*iid) {
+                    let terms_positions: Vec<&[StoreTermPosition]> = terms
+                        .iter()
+                        .filter_map(|term_hashed| {
+                            iid_positions
+                                .iter()
+                                .find(|(iid_term_hashed, _)| iid_term_hashed == term_hashed)
+                                .map(|(_, term_positions)| term_positions.as_slice())
+                        })
+                        .collect();
+
+                    matches!(
+                        Self::min_span(&terms_positions),
+                        Some(iid_span) if iid_span <= span
+                    )
+                } else {
+                    false
+                }
+            })
+            .collect()
+    }
+
+    pub fn phrase_iids(
+        kv_action: &StoreKVAction,
+        found_iids: LinkedHashSet<StoreObjectIID>,
+        phrases: &[Vec<StoreTermHashed>],
+    ) -> LinkedHashSet<StoreObjectIID> {
+        // Only keep IIDs where the terms of each phrase appear at consecutive positions, in \
+        //   phrase order (objects without any stored positions are never kept)
+        found_iids
+            .into_iter()
+            .filter(|iid| {
+                if let Ok(Some(iid_positions)) = kv_action.get_iid_to_positions(*iid) {
+                    phrases.iter().all(|phrase| {
+                        let terms_positions: Option<Vec<&[StoreTermPosition]>> = phrase
+                            .iter()
+                            .map(|term_hashed| {
+                                iid_positions
+                                    .iter()
+                                    .find(|(iid_term_hashed, _)| iid_term_hashed == term_hashed)
+                                    .map(|(_, term_positions)| term_positions.as_slice())
+                            })
+                            .collect();
+
+                        terms_positions
+                            .map(|terms_positions| Self::has_phrase(&terms_positions))
+                            .unwrap_or(false)
+                    })
+                } else {
+                    false
+                }
+            })
+            .collect()
+    }
+
+    fn has_phrase(terms_positions: &[&[StoreTermPosition]]) -> bool {
+        // Look for a position of the first term, that all following terms directly follow
+        if let Some((first_positions, next_terms_positions)) = terms_positions.split_first() {
+            first_positions.iter().any(|first_position| {
+                next_terms_positions
+                    .iter()
+                    .enumerate()
+                    .all(|(index, term_positions)| {
+                        term_positions
+                            .binary_search(&(first_position + index as StoreTermPosition + 1))
+                            .is_ok()
+                    })
+            })
+        } else {
+            true
+        }
+    }
+
+    fn min_span(terms_positions: &[&[StoreTermPosition]]) -> Option<StoreTermPosition> {
+        // Nothing to compare? (a single term always is within span)
+        if terms_positions.len() < 2 {
+            return Some(0);
+        }
+
+        // Walk all sorted positions lists at once, always moving the lowest position forward, \
+        //   and measure the narrowest window that holds one position of each term
+        let mut cursors = vec![0; terms_positions.len()];
+        let mut min_span = StoreTermPosition::MAX;
+
+        loop {
+            let mut window = (StoreTermPosition::MAX, StoreTermPosition::MIN, 0);
+
+            for (index, term_positions) in terms_positions.iter().enumerate() {
+                let position = *term_positions.get(cursors[index])?;
+
+                if position < window.0 {
+                    window = (position, window.1, index);
+                }
+                if position > window.1 {
+                    window.1 = position;
+                }
+            }
+
+            min_span = min_span.min(window.1 - window.0);
+
+            cursors[window.2] += 1;
+
+            // Stop there? (the lowest position cannot move forward anymore)
+            if cursors[window.2] >= terms_positions[window.2].len() {
+                return Some(min_span);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_measures_min_span() {
+        assert_eq!(ExecutorProximity::min_span(&[]), Some(0));
+        assert_eq!(ExecutorProximity::min_span(&[&[3]]), Some(0));
+        assert_eq!(ExecutorProximity::min_span(&[&[0, 20], &[9, 22]]), Some(2));
+        assert_eq!(
+            ExecutorProximity::min_span(&[&[0, 10], &[4], &[12, 30]]),
+            Some(8)
+        );
+        assert_eq!(ExecutorProximity::min_span(&[&[1], &[]]), None);
+    }
+
+    #[test]
+    fn it_finds_phrase() {
+        assert!(ExecutorProximity::has_phrase(&[]));
+        assert!(ExecutorProximity::has_phrase(&[&[3]]));
+        assert!(ExecutorProximity::has_phrase(&[&[0, 7], &[4, 8], &[9]]));
+        assert!(!ExecutorProximity::has_phrase(&[&[0, 7], &[4, 8], &[10]]));
+        assert!(!ExecutorProximity::has_phrase(&[&[2], &[1]]));
+        assert!(!ExecutorProximity::has_phrase(&[&[1], &[]]));
+    }
+}
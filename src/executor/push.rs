@@ -5,19 +5,32 @@
 // License: Mozilla Public License v2.0 (MPL v2.0)
 
 use linked_hash_set::LinkedHashSet;
-use std::iter::FromIterator;
+use std::iter::{self, FromIterator};
 
+use crate::lexer::synonyms::LexerSynonyms;
 use crate::lexer::token::TokenLexer;
+use crate::query::types::QueryPushOptions;
 use crate::store::fst::{StoreFSTActionBuilder, StoreFSTPool};
-use crate::store::identifiers::{StoreMetaKey, StoreMetaValue, StoreTermHashed};
+use crate::store::identifiers::{
+    StoreMetaKey, StoreMetaValue, StoreObjectIID, StoreObjectPositions, StoreTermHashed,
+    StoreTermPosition, StoreTimestamp,
+};
 use crate::store::item::StoreItem;
-use crate::store::kv::{StoreKVAcquireMode, StoreKVActionBuilder, StoreKVPool};
+use crate::store::kv::{StoreKVAcquireMode, StoreKVAction, StoreKVActionBuilder, StoreKVPool};
+use crate::tasker::webhook::TaskerWebhook;
 use crate::APP_CONF;
 
 pub struct ExecutorPush;
 
+pub const PUSH_REJECT_DOCID_IN_USE: &str = "docid_in_use";
+pub const PUSH_REJECT_VECTOR_DIMENSION: &str = "vector_dimension_mismatch";
+
 impl ExecutorPush {
-    pub fn execute<'a>(store: StoreItem<'a>, lexer: TokenLexer<'a>) -> Result<(), ()> {
+    pub fn execute<'a>(
+        store: StoreItem<'a>,
+        lexer: TokenLexer<'a>,
+        options: QueryPushOptions,
+    ) -> Result<Option<&'static str>, ()> {
         if let StoreItem(collection, Some(bucket), Some(object)) = store {
             // Important: acquire database access read lock, and reference it in context. This \
             //   prevents the database from being erased while using it in this block.
@@ -36,45 +49,126 @@ impl ExecutorPush {
                     StoreFSTActionBuilder::access(fst_store),
                 );
 
-                // Try to resolve existing OID to IID, otherwise initialize IID (store the \
-                //   bi-directional relationship)
                 let oid = object.as_str();
-                let iid = kv_action.get_oid_to_iid(oid).unwrap_or(None).or_else(|| {
-                    info!("must initialize push executor oid-to-iid and iid-to-oid");
 
-                    if let Ok(iid_incr) = kv_action.get_meta_to_value(StoreMetaKey::IIDIncr) {
-                        let iid_incr = if let Some(iid_incr) = iid_incr {
-                            match iid_incr {
-                                StoreMetaValue::IIDIncr(iid_incr) => iid_incr + 1,
+                // Skip push if OID is already indexed? (when de-duplicating pushes)
+                if options.deduplicate {
+                    if let Ok(Some(_)) = kv_action.get_oid_to_iid(oid) {
+                        info!("push executor skipped already-indexed oid: {}", oid);
+
+                        return Ok(None);
+                    }
+                }
+
+                // Check vector dimension against bucket vector dimension? (if a vector is \
+                //   provided; the bucket vector dimension is declared upon first vector push)
+                let vector_dim_declared = if let Some(ref vector) = options.vector {
+                    match kv_action.get_meta_to_value(StoreMetaKey::VectorDim) {
+                        Ok(Some(StoreMetaValue::VectorDim(vector_dim))) => {
+                            if vector_dim as usize != vector.len() {
+                                info!(
+                                    "push executor rejected vector of dimension: {} (bucket: {})",
+                                    vector.len(),
+                                    vector_dim
+                                );
+
+                                return Ok(Some(PUSH_REJECT_VECTOR_DIMENSION));
                             }
-                        } else {
-                            0
-                        };
 
-                        // Bump last stored increment
-                        if kv_action
-                            .set_meta_to_value(
-                                StoreMetaKey::IIDIncr,
-                                StoreMetaValue::IIDIncr(iid_incr),
-                            )
-                            .is_ok()
-                        {
-                            // Associate OID <> IID (bidirectional)
-                            executor_ensure_op!(kv_action.set_oid_to_iid(oid, iid_incr));
-                            executor_ensure_op!(kv_action.set_iid_to_oid(iid_incr, oid));
+                            true
+                        }
+                        Ok(_) => false,
+                        Err(_) => {
+                            error!("failed getting push executor meta-to-value vector dimension");
 
-                            Some(iid_incr)
-                        } else {
-                            error!("failed updating push executor meta-to-value iid increment");
+                            return Err(());
+                        }
+                    }
+                } else {
+                    true
+                };
+
+                // Use requested IID? (the push is rejected if this IID is already in use by \
+                //   another object, or if the object already uses another IID)
+                let iid_requested = if let Some(docid) = options.docid {
+                    match (
+                        kv_action.get_oid_to_iid(oid),
+                        kv_action.get_iid_to_oid(docid),
+                    ) {
+                        (Ok(Some(oid_iid)), _) if oid_iid == docid => Some(docid),
+                        (Ok(None), Ok(None)) => {
+                            // Notice: do not fall back to an auto-incremented IID if the \
+                            //   requested IID could not be assigned, fail the push instead.
+                            Some(Self::assign_iid(&kv_action, oid, docid).ok_or(())?)
+                        }
+                        (Ok(_), Ok(_)) => {
+                            info!("push executor rejected already-used docid: {}", docid);
 
-                            None
+                            return Ok(Some(PUSH_REJECT_DOCID_IN_USE));
                         }
-                    } else {
-                        error!("failed getting push executor meta-to-value iid increment");
+                        _ => {
+                            error!("failed checking push executor requested docid: {}", docid);
 
-                        None
+                            return Err(());
+                        }
                     }
-                });
+                } else {
+                    None
+                };
+
+                // Try to resolve existing OID to IID, otherwise initialize IID (store the \
+                //   bi-directional relationship)
+                let iid = iid_requested
+                    .or_else(|| kv_action.get_oid_to_iid(oid).unwrap_or(None))
+                    .or_else(|| {
+                        info!("must initialize push executor oid-to-iid and iid-to-oid");
+
+                        if let Ok(iid_incr) = kv_action.get_meta_to_value(StoreMetaKey::IIDIncr) {
+                            let iid_incr = match iid_incr {
+                                Some(StoreMetaValue::IIDIncr(iid_incr)) => {
+                                    // Notice: this guards against the IID space being \
+                                    //   exhausted, as a wrapped IID would overwrite the IID 0 \
+                                    //   object mappings.
+                                    if let Some(iid_incr) = iid_incr.checked_add(1) {
+                                        iid_incr
+                                    } else {
+                                        error!("push executor iid increment space is exhausted");
+
+                                        return None;
+                                    }
+                                }
+                                _ => 0,
+                            };
+
+                            // Bump last stored increment
+                            if kv_action
+                                .set_meta_to_value(
+                                    StoreMetaKey::IIDIncr,
+                                    StoreMetaValue::IIDIncr(iid_incr),
+                                )
+                                .is_ok()
+                            {
+                                // Associate OID <> IID (bidirectional)
+                                executor_ensure_op!(kv_action.set_oid_to_iid(oid, iid_incr));
+                                executor_ensure_op!(kv_action.set_iid_to_oid(iid_incr, oid));
+
+                                // Stamp IID creation time
+                                executor_ensure_op!(
+                                    kv_action.set_iid_to_created(iid_incr, StoreTimestamp::now())
+                                );
+
+                                Some(iid_incr)
+                            } else {
+                                error!("failed updating push executor meta-to-value iid increment");
+
+                                None
+                            }
+                        } else {
+                            error!("failed getting push executor meta-to-value iid increment");
+
+                            None
+                        }
+                    });
 
                 if let Some(iid) = iid {
                     let mut has_commits = false;
@@ -93,7 +187,62 @@ impl ExecutorPush {
                         iid_terms_hashed
                     );
 
-                    for (term, term_hashed) in lexer {
+                    // Acquire term positions for IID? (if term positions are stored; positions of \
+                    //   pushed terms follow previously pushed terms, as pushed texts get appended)
+                    let mut iid_positions = if APP_CONF.store.kv.store_term_positions {
+                        Some(
+                            kv_action
+                                .get_iid_to_positions(iid)
+                                .unwrap_or(None)
+                                .unwrap_or_default(),
+                        )
+                    } else {
+                        None
+                    };
+
+                    let mut next_position = iid_positions
+                        .iter()
+                        .flatten()
+                        .filter_map(|(_, term_positions)| term_positions.last())
+                        .max()
+                        .map(|last_position| last_position + 1)
+                        .unwrap_or(0);
+
+                    // Notice: synonyms of lexed terms are linked to IID as well, though they do \
+                    //   not get any position, as they are not part of pushed text.
+                    let terms = lexer.flat_map(|(term, term_hashed)| {
+                        let synonyms = LexerSynonyms::expand_hashed(&term)
+                            .into_iter()
+                            .map(|(synonym, synonym_hashed)| (synonym, synonym_hashed, false));
+
+                        iter::once((term, term_hashed, true)).chain(synonyms)
+                    });
+
+                    let max_indexed_words = APP_CONF.channel.ingest.max_indexed_words;
+
+                    for (term, term_hashed, is_lexed) in terms {
+                        // Object already has too many terms? (stop indexing there, if limited)
+                        if max_indexed_words > 0
+                            && iid_terms_hashed.len() >= max_indexed_words
+                            && !iid_terms_hashed.contains(&term_hashed)
+                        {
+                            warn!(
+                                "push executor reached indexed words limit ({}) for iid: {}",
+                                max_indexed_words, iid
+                            );
+
+                            break;
+                        }
+
+                        // Record term position? (if term positions are stored)
+                        if let Some(ref mut iid_positions) = iid_positions {
+                            if is_lexed {
+                                Self::record_position(iid_positions, term_hashed, next_position);
+
+                                next_position += 1;
+                            }
+                        }
+
                         // Check that term is not already linked to IID
                         if !iid_terms_hashed.contains(&term_hashed) {
                             if let Ok(term_iids) = kv_action.get_term_to_iids(term_hashed) {
@@ -158,11 +307,176 @@ impl ExecutorPush {
                         executor_ensure_op!(kv_action.set_iid_to_terms(iid, &collected_iids));
                     }
 
-                    return Ok(());
+                    // Commit updated term positions for IID? (if term positions are stored)
+                    if let Some(iid_positions) = iid_positions {
+                        info!("has push executor iid-to-positions commit");
+
+                        executor_ensure_op!(kv_action.set_iid_to_positions(iid, &iid_positions));
+                    }
+
+                    // Locate IID? (if a location is provided; this replaces any previous location)
+                    if let Some(geo) = options.geo {
+                        info!("has push executor iid-to-geo commit: {:?}", geo);
+
+                        executor_ensure_op!(kv_action.batch_link_geo(iid, geo));
+                    }
+
+                    // Weigh IID? (if a weight is provided; this replaces any previous weight)
+                    if let Some(weight) = options.weight {
+                        info!("has push executor iid-to-weight commit: {}", weight);
+
+                        executor_ensure_op!(kv_action.set_iid_to_weight(iid, weight));
+
+                        // Flag bucket as having weights? (if this is the first weight pushed; \
+                        //   searches only read weights in flagged buckets)
+                        if !matches!(
+                            kv_action.get_meta_to_value(StoreMetaKey::HasWeights),
+                            Ok(Some(StoreMetaValue::HasWeights(true)))
+                        ) {
+                            executor_ensure_op!(kv_action.set_meta_to_value(
+                                StoreMetaKey::HasWeights,
+                                StoreMetaValue::HasWeights(true),
+                            ));
+                        }
+                    }
+
+                    // Tag IID? (if tags are provided; this replaces any previous tags)
+                    if let Some(tags) = options.tags {
+                        info!("has push executor iid-to-tags commit: {:?}", tags);
+
+                        executor_ensure_op!(kv_action.set_iid_to_tags(iid, &tags));
+                    }
+
+                    // Segment IID? (if a segment is provided; this replaces any previous segment)
+                    if let Some(segment) = options.segment {
+                        info!("has push executor iid-to-segment commit: {}", segment);
+
+                        executor_ensure_op!(kv_action.set_iid_to_segment(iid, segment));
+                    }
+
+                    // Prioritize IID? (if a priority is provided; this replaces any previous \
+                    //   priority)
+                    if let Some(priority) = options.priority {
+                        info!("has push executor iid-to-priority commit: {}", priority);
+
+                        executor_ensure_op!(kv_action.set_iid_to_priority(iid, priority));
+
+                        // Flag bucket as having priorities? (if this is the first priority \
+                        //   pushed; searches only read priorities in flagged buckets)
+                        if !matches!(
+                            kv_action.get_meta_to_value(StoreMetaKey::HasPriorities),
+                            Ok(Some(StoreMetaValue::HasPriorities(true)))
+                        ) {
+                            executor_ensure_op!(kv_action.set_meta_to_value(
+                                StoreMetaKey::HasPriorities,
+                                StoreMetaValue::HasPriorities(true),
+                            ));
+                        }
+                    }
+
+                    // Alias OID? (if an alias is provided; this replaces any previous alias)
+                    if let Some(alias) = options.alias {
+                        info!("has push executor oid-to-alias commit: {}", alias);
+
+                        executor_ensure_op!(kv_action.set_oid_to_alias(oid, &alias));
+                    }
+
+                    // Embed IID? (if a vector is provided; this replaces any previous vector)
+                    if let Some(vector) = options.vector {
+                        info!("has push executor iid-to-vector commit: {}", vector.len());
+
+                        // Declare bucket vector dimension? (if this is the first vector pushed)
+                        if !vector_dim_declared {
+                            executor_ensure_op!(kv_action.set_meta_to_value(
+                                StoreMetaKey::VectorDim,
+                                StoreMetaValue::VectorDim(vector.len() as u32),
+                            ));
+                        }
+
+                        executor_ensure_op!(kv_action.set_iid_to_vector(iid, &vector));
+                    }
+
+                    // Store original text? (appended to any previously pushed text, as terms are)
+                    if let Some(text) = options.text {
+                        let iid_text = match kv_action.get_iid_to_text(iid).unwrap_or(None) {
+                            Some(iid_text_stored) => format!("{} {}", iid_text_stored, text),
+                            None => text,
+                        };
+
+                        info!("has push executor iid-to-text commit");
+
+                        executor_ensure_op!(kv_action.set_iid_to_text(iid, &iid_text));
+                    }
+
+                    // Notify webhook of successful push (this is asynchronous)
+                    TaskerWebhook::notify_push(
+                        collection.as_str(),
+                        bucket.as_str(),
+                        object.as_str(),
+                    );
+
+                    return Ok(None);
                 }
             }
         }
 
         Err(())
     }
+
+    fn record_position(
+        iid_positions: &mut StoreObjectPositions,
+        term_hashed: StoreTermHashed,
+        position: StoreTermPosition,
+    ) {
+        if let Some((_, term_positions)) = iid_positions
+            .iter_mut()
+            .find(|(iid_term_hashed, _)| *iid_term_hashed == term_hashed)
+        {
+            term_positions.push(position);
+        } else {
+            iid_positions.push((term_hashed, vec![position]));
+        }
+    }
+
+    fn assign_iid(
+        kv_action: &StoreKVAction,
+        oid: &str,
+        iid: StoreObjectIID,
+    ) -> Option<StoreObjectIID> {
+        info!(
+            "must assign push executor oid-to-iid and iid-to-oid to: {}",
+            iid
+        );
+
+        // Bump last stored increment? (if assigned IID is over it, so that further allocated \
+        //   IIDs never collide with the assigned IID)
+        let should_bump = match kv_action.get_meta_to_value(StoreMetaKey::IIDIncr) {
+            Ok(Some(StoreMetaValue::IIDIncr(iid_incr))) => iid > iid_incr,
+            Ok(_) => true,
+            Err(_) => {
+                error!("failed getting push executor meta-to-value iid increment");
+
+                return None;
+            }
+        };
+
+        if should_bump
+            && kv_action
+                .set_meta_to_value(StoreMetaKey::IIDIncr, StoreMetaValue::IIDIncr(iid))
+                .is_err()
+        {
+            error!("failed updating push executor meta-to-value iid increment");
+
+            return None;
+        }
+
+        // Associate OID <> IID (bidirectional)
+        executor_ensure_op!(kv_action.set_oid_to_iid(oid, iid));
+        executor_ensure_op!(kv_action.set_iid_to_oid(iid, oid));
+
+        // Stamp IID creation time
+        executor_ensure_op!(kv_action.set_iid_to_created(iid, StoreTimestamp::now()));
+
+        Some(iid)
+    }
 }
@@ -0,0 +1,83 @@
+// Sonic
+//
+// Fast, lightweight and schema-less search backend
+// Copyright: 2019, Valerian Saliou <valerian@valeriansaliou.name>
+// License: Mozilla Public License v2.0 (MPL v2.0)
+
+use crate::store::fst::{StoreFSTActionBuilder, StoreFSTMisc, StoreFSTPool};
+use crate::store::item::StoreItem;
+use crate::store::kv::{StoreKVAcquireMode, StoreKVMisc, StoreKVPool};
+
+pub struct ExecutorRebuildReverseIndex;
+
+impl ExecutorRebuildReverseIndex {
+    pub fn execute(store: StoreItem) -> Result<(usize, usize), ()> {
+        if let StoreItem(collection, None, None) = store {
+            // Important: acquire database and graph access read locks, and reference them in \
+            //   context. This prevents the database and graph from being erased while using \
+            //   them in this block.
+            general_kv_access_lock_read!();
+            general_fst_access_lock_read!();
+
+            if let Ok(kv_store) = StoreKVPool::acquire(StoreKVAcquireMode::OpenOnly, collection) {
+                // Important: acquire bucket store write lock
+                executor_kv_lock_write!(kv_store);
+
+                // List terms from all buckets in KV (only buckets that also have a FST can have \
+                //   their terms mapped back to words)
+                let kv_buckets = StoreKVMisc::scan_collection_terms(&kv_store)?;
+                let fst_buckets = StoreFSTMisc::list_collection_buckets(collection.as_str())?;
+
+                let (mut count_mapped, mut count_unmapped) = (0, 0);
+
+                for (kv_bucket, kv_terms) in &kv_buckets {
+                    // Notice: the whole FST gets streamed once per bucket, which is way cheaper \
+                    //   than looking up each term hash separately.
+                    let fst_words = if fst_buckets.contains(kv_bucket) {
+                        StoreFSTActionBuilder::access(StoreFSTPool::acquire_atom(
+                            collection.as_str(),
+                            *kv_bucket,
+                        )?)
+                        .map_words()
+                    } else {
+                        Default::default()
+                    };
+
+                    let mappings: Vec<_> = kv_terms
+                        .iter()
+                        .filter_map(|term_hashed| {
+                            fst_words
+                                .get(term_hashed)
+                                .map(|word| (*term_hashed, word.as_str()))
+                        })
+                        .collect();
+
+                    debug!(
+                        "rebuild reverse index executor mapped {} out of {} terms in bucket: \
+                            <{:x?}>",
+                        mappings.len(),
+                        kv_terms.len(),
+                        kv_bucket
+                    );
+
+                    StoreKVMisc::batch_set_terms_to_words(&kv_store, *kv_bucket, &mappings)?;
+
+                    count_mapped += mappings.len();
+                    count_unmapped += kv_terms.len() - mappings.len();
+                }
+
+                info!(
+                    "rebuild reverse index executor mapped {} terms ({} unmapped) in collection: \
+                        {}",
+                    count_mapped,
+                    count_unmapped,
+                    collection.as_str()
+                );
+
+                return Ok((count_mapped, count_unmapped));
+            }
+        }
+
+        Err(())
+    }
+}
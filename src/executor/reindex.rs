@@ -0,0 +1,74 @@
+// Sonic
+//
+// Fast, lightweight and schema-less search backend
+// Copyright: 2019, Valerian Saliou <valerian@valeriansaliou.name>
+// License: Mozilla Public License v2.0 (MPL v2.0)
+
+use crate::store::fst::{StoreFSTActionBuilder, StoreFSTPool};
+use crate::store::item::StoreItem;
+use crate::store::kv::{StoreKVAcquireMode, StoreKVMisc, StoreKVPool};
+
+pub struct ExecutorReindex;
+
+impl ExecutorReindex {
+    pub fn execute(store: StoreItem) -> Result<(usize, usize), ()> {
+        if let StoreItem(collection, bucket, None) = store {
+            // Important: acquire database and graph access read locks, and reference them in \
+            //   context. This prevents the database and graph from being erased while using \
+            //   them in this block.
+            general_kv_access_lock_read!();
+            general_fst_access_lock_read!();
+
+            if let Ok(kv_store) = StoreKVPool::acquire(StoreKVAcquireMode::OpenOnly, collection) {
+                // Important: acquire bucket store read lock
+                executor_kv_lock_read!(kv_store);
+
+                // List terms from all buckets in KV, or from the given bucket, along with their \
+                //   words (words can only be recovered from the term-to-word reverse index)
+                let kv_buckets = StoreKVMisc::scan_collection_words(
+                    &kv_store,
+                    bucket.as_ref().map(|bucket| bucket.as_str()),
+                )?;
+
+                let (mut count_pushed, mut count_unmapped) = (0, 0);
+
+                for (kv_bucket, kv_terms) in &kv_buckets {
+                    info!(
+                        "reindex executor pushing {} terms to graph in bucket: <{:x?}>",
+                        kv_terms.len(),
+                        kv_bucket
+                    );
+
+                    let fst_action = StoreFSTActionBuilder::access(StoreFSTPool::acquire_atom(
+                        collection.as_str(),
+                        *kv_bucket,
+                    )?);
+
+                    for (term_hashed, word) in kv_terms {
+                        if let Some(word) = word {
+                            // Notice: words that are already in the graph are not pushed again.
+                            if fst_action.push_word(word) {
+                                count_pushed += 1;
+                            }
+                        } else {
+                            debug!("reindex executor has no word for term: {}", term_hashed);
+
+                            count_unmapped += 1;
+                        }
+                    }
+                }
+
+                info!(
+                    "reindex executor pushed {} words ({} terms unmapped) in collection: {}",
+                    count_pushed,
+                    count_unmapped,
+                    collection.as_str()
+                );
+
+                return Ok((count_pushed, count_unmapped));
+            }
+        }
+
+        Err(())
+    }
+}
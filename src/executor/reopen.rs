@@ -0,0 +1,63 @@
+// Sonic
+//
+// Fast, lightweight and schema-less search backend
+// Copyright: 2019, Valerian Saliou <valerian@valeriansaliou.name>
+// License: Mozilla Public License v2.0 (MPL v2.0)
+
+use crate::store::fst::{StoreFSTMisc, StoreFSTPool};
+use crate::store::item::StoreItem;
+use crate::store::kv::{StoreKVAcquireMode, StoreKVPool};
+
+pub struct ExecutorReopen;
+
+impl ExecutorReopen {
+    pub fn execute(store: StoreItem) -> Result<(bool, usize), ()> {
+        if let StoreItem(collection, None, None) = store {
+            let collection_str = collection.as_str();
+
+            {
+                // Acquire KV + FST locks in write mode, as we will close them, we need to prevent \
+                //   any other consumer to use them.
+                general_kv_access_lock_write!();
+                general_fst_access_lock_write!();
+
+                // Notice: pending KV writes are flushed and pending FST changes are consolidated \
+                //   upon draining, thus no data gets lost.
+                StoreKVPool::drain(collection_str)?;
+
+                let fst_buckets_drained = StoreFSTPool::drain(collection_str);
+
+                info!(
+                    "reopen executor drained collection: {} (and {} fst buckets)",
+                    collection_str,
+                    fst_buckets_drained.len()
+                );
+            }
+
+            // Important: acquire database access read lock, and reference it in context. This \
+            //   prevents the database from being erased while using it in this block.
+            general_kv_access_lock_read!();
+            general_fst_access_lock_read!();
+
+            // Eagerly reopen KV + all FSTs for collection
+            let kv_reopened = matches!(
+                StoreKVPool::acquire(StoreKVAcquireMode::OpenOnly, collection),
+                Ok(Some(_))
+            );
+
+            let fst_buckets_reopened = StoreFSTMisc::list_collection_buckets(collection_str)?
+                .into_iter()
+                .filter(|bucket_atom| StoreFSTPool::acquire_atom(collection, *bucket_atom).is_ok())
+                .count();
+
+            info!(
+                "reopen executor reopened collection: {} (kv: {}, fst buckets: {})",
+                collection_str, kv_reopened, fst_buckets_reopened
+            );
+
+            return Ok((kv_reopened, fst_buckets_reopened));
+        }
+
+        Err(())
+    }
+}
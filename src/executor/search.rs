@@ -4,19 +4,62 @@
 // Copyright: 2019, Valerian Saliou <valerian@valeriansaliou.name>
 // License: Mozilla Public License v2.0 (MPL v2.0)
 
+use hashbrown::{HashMap, HashSet};
 use linked_hash_set::LinkedHashSet;
+use rand::rngs::SmallRng;
+use rand::seq::SliceRandom;
+use rand::SeedableRng;
+use std::cmp::Ordering;
+use std::fmt;
 use std::iter::FromIterator;
+use std::time::{Duration, Instant};
+use unicode_segmentation::UnicodeSegmentation;
 
+use crate::channel::format::encode_cursor;
+use crate::config::options::ConfigChannelSearchRecencyDecayFunction;
+use crate::executor::filter::ExecutorFilter;
+use crate::executor::geo::ExecutorGeo;
+use crate::executor::highlight::ExecutorHighlight;
+use crate::executor::proximity::ExecutorProximity;
+use crate::lexer::normalize::LexerNormalize;
+use crate::lexer::synonyms::LexerSynonyms;
 use crate::lexer::token::TokenLexer;
-use crate::query::types::{QuerySearchID, QuerySearchLimit, QuerySearchOffset};
+use crate::query::cache::QueryCache;
+use crate::query::types::{
+    QuerySearchAbstract, QuerySearchDecay, QuerySearchHighlightStyle, QuerySearchID,
+    QuerySearchLimit, QuerySearchMustNot, QuerySearchOffset, QuerySearchOptions,
+    QuerySearchPenalty, QuerySearchRandom, QuerySearchSortBy, QuerySearchTimeout,
+};
 use crate::store::fst::{StoreFSTActionBuilder, StoreFSTPool};
-use crate::store::identifiers::{StoreObjectIID, StoreTermHash};
+use crate::store::identifiers::{
+    StoreMetaKey, StoreMetaValue, StoreObjectIID, StoreObjectPriority, StoreTermHash,
+    StoreTermHashed, StoreTimestamp,
+};
 use crate::store::item::StoreItem;
-use crate::store::kv::{StoreKVAcquireMode, StoreKVActionBuilder, StoreKVPool};
+use crate::store::kv::{
+    StoreKVAcquireMode, StoreKVAction, StoreKVActionBuilder, StoreKVMisc, StoreKVPool,
+};
 use crate::APP_CONF;
 
+const SEARCH_PRIORITY_DEFAULT: StoreObjectPriority = 128;
+const SEARCH_RELEVANCE_LENGTH_SATURATION: f32 = 1.2;
+const SEARCH_RELEVANCE_LENGTH_NORMALIZATION: f32 = 0.75;
+
 pub struct ExecutorSearch;
 
+#[derive(Default)]
+struct ExecutorSearchDebug {
+    terms_lexed: usize,
+    terms_found: usize,
+    terms_missed: usize,
+    iids_before_intersect: usize,
+    iids_after_intersect: usize,
+    fst_suggestions_used: usize,
+    kv_reads: usize,
+    fst_reads: usize,
+    duration_us: u128,
+}
+
 impl ExecutorSearch {
     pub fn execute<'a>(
         store: StoreItem<'a>,
@@ -24,8 +67,52 @@ impl ExecutorSearch {
         lexer: TokenLexer<'a>,
         limit: QuerySearchLimit,
         offset: QuerySearchOffset,
+        options: QuerySearchOptions,
     ) -> Result<Option<Vec<String>>, ()> {
         if let StoreItem(collection, Some(bucket), None) = store {
+            let started_at = Instant::now();
+
+            // Notice: words preceded by an OR operator are lexed the same way search terms are \
+            //   (eg. stemmed, or split into n-grams), so that they can be matched against terms.
+            let or_terms_hashed: HashSet<StoreTermHashed> = options
+                .or_terms
+                .iter()
+                .flat_map(|or_term| lexer.sibling(or_term).map(|(_, term_hashed)| term_hashed))
+                .collect();
+
+            // Notice: phrase words and excluded words are lexed the same way as well, as stored \
+            //   terms may be stemmed or split into n-grams.
+            let phrase_terms = Self::lex_phrases(&lexer, &options.phrases);
+            let must_not_terms = Self::lex_exclusions(&lexer, &options.must_not);
+
+            // Notice: terms are lexed upfront, as normalized terms are used to key cached results.
+            let terms: Vec<(String, StoreTermHashed)> = lexer.collect();
+
+            // Serve results from cache? (if enabled, and if results are cached; debugged searches \
+            //   are never cached, as their measurements are only valid for a single execution, \
+            //   and neither are searches shuffled with a random seed)
+            let cache_key = if APP_CONF.channel.search.query_cache.enabled
+                && !options.debug
+                && options.random != Some(0)
+            {
+                let cache_terms: Vec<String> = terms.iter().map(|(term, _)| term.clone()).collect();
+                let cache_key =
+                    QueryCache::make_key(collection, bucket, &cache_terms, limit, offset, &options);
+
+                if let Some(cached_results) = QueryCache::get(cache_key) {
+                    info!(
+                        "got search executor results from cache: {:?}",
+                        cached_results
+                    );
+
+                    return Ok(cached_results);
+                }
+
+                Some(cache_key)
+            } else {
+                None
+            };
+
             // Important: acquire database access read lock, and reference it in context. This \
             //   prevents the database from being erased while using it in this block.
             general_kv_access_lock_read!();
@@ -38,6 +125,15 @@ impl ExecutorSearch {
                 // Important: acquire bucket store read lock
                 executor_kv_lock_read!(kv_store);
 
+                // Acquire user boosts? (if results are to be personalized)
+                let user_boosts = if let Some(ref user) = options.personalize {
+                    StoreKVMisc::get_user_to_boosts(&kv_store, user)
+                        .unwrap_or(None)
+                        .unwrap_or_default()
+                } else {
+                    Vec::new()
+                };
+
                 let (kv_action, fst_action) = (
                     StoreKVActionBuilder::access(bucket, kv_store),
                     StoreFSTActionBuilder::access(fst_store),
@@ -45,9 +141,75 @@ impl ExecutorSearch {
 
                 // Try to resolve existing search terms to IIDs, and perform an algebraic AND on \
                 //   all resulting IIDs for each given term.
+                // Notice: if missing terms are penalized, perform an algebraic OR instead, and \
+                //   count how many terms each IID matches (this is used for scoring afterwards).
                 let mut found_iids: LinkedHashSet<StoreObjectIID> = LinkedHashSet::new();
+                let (mut found_iids_matches, mut count_terms) = (HashMap::new(), 0);
+
+                // Notice: IIDs found via weighted terms get the product of those term weights.
+                let mut found_iids_weights: HashMap<StoreObjectIID, f32> = HashMap::new();
+
+                // Notice: search terms are kept to locate text abstracts afterwards (if asked to)
+                let mut found_terms: Vec<String> = Vec::new();
+
+                // Notice: hashed search terms are kept to check their proximity afterwards (if \
+                //   asked to)
+                let mut span_terms: Vec<StoreTermHashed> = Vec::new();
+
+                // Notice: execution details are always measured, though they are only returned \
+                //   if the search is debugged.
+                let mut debug = ExecutorSearchDebug {
+                    terms_lexed: terms.len(),
+                    ..Default::default()
+                };
+
+                // Notice: IIDs get the sum of approximate inverse document frequencies for all \
+                //   terms they were found via, which is used to rank them by relevance \
+                //   afterwards (if asked to; this requires the count of IIDs in the bucket).
+                let is_sorted_by_relevance =
+                    options.sort == QuerySearchSortBy::Relevance && options.random.is_none();
+
+                let mut found_iids_relevances: HashMap<StoreObjectIID, f32> = HashMap::new();
+
+                let count_iids = if is_sorted_by_relevance {
+                    debug.kv_reads += 1;
+
+                    match kv_action.get_meta_to_value(StoreMetaKey::IIDIncr) {
+                        Ok(Some(StoreMetaValue::IIDIncr(iid_incr))) => iid_incr as usize + 1,
+                        _ => 0,
+                    }
+                } else {
+                    0
+                };
+
+                // Notice: a timeout of zero means that the search is not time-bounded (the \
+                //   timeout applies to IID lookups, which are the costly part of the search).
+                let timeout_ms = options
+                    .timeout_ms
+                    .unwrap_or(APP_CONF.channel.search.query_timeout_ms);
+                let (mut is_timed_out, mut has_found_batch) = (false, false);
+
+                'lexing: for (term, term_hashed) in terms {
+                    // Search took too much time? (stop there, and return partial results)
+                    if Self::is_timed_out(started_at, timeout_ms) {
+                        warn!(
+                            "stop search executor as it timed out after {}ms on term: {}",
+                            timeout_ms, term
+                        );
+
+                        is_timed_out = true;
+
+                        break 'lexing;
+                    }
+
+                    if options.abstract_chars.is_some() || options.highlight {
+                        found_terms.push(term.clone());
+                    }
+
+                    if options.span.is_some() && !span_terms.contains(&term_hashed) {
+                        span_terms.push(term_hashed);
+                    }
 
-                'lexing: for (term, term_hashed) in lexer {
                     let mut iids = LinkedHashSet::from_iter(
                         kv_action
                             .get_term_to_iids(term_hashed)
@@ -56,6 +218,28 @@ impl ExecutorSearch {
                             .into_iter(),
                     );
 
+                    debug.kv_reads += 1;
+
+                    // Unite IIDs found via synonyms of term (if any synonym is configured)
+                    for (synonym, synonym_hashed) in LexerSynonyms::expand_hashed(&term) {
+                        if let Ok(Some(synonym_iids)) = kv_action.get_term_to_iids(synonym_hashed) {
+                            debug!(
+                                "got search executor iids: {:?} for synonym: {} of term: {}",
+                                synonym_iids, synonym, term
+                            );
+
+                            iids.extend(synonym_iids);
+                        }
+
+                        debug.kv_reads += 1;
+                    }
+
+                    if iids.is_empty() {
+                        debug.terms_missed += 1;
+                    } else {
+                        debug.terms_found += 1;
+                    }
+
                     // No IIDs? Try to complete with a suggested alternate word
                     // Notice: this may sound dirty to try generating as many results as the \
                     //   'retain_word_objects' value, but as we do not know if another lexed word \
@@ -78,6 +262,8 @@ impl ExecutorSearch {
                         //   match of term, we can pick next ones to complete search even further.
                         // Notice: we add '1' to the 'alternates_try' number as to account for \
                         //   exact match suggestion that comes as first result and is to be ignored.
+                        debug.fst_reads += 1;
+
                         if let Some(suggested_words) =
                             fst_action.suggest_words(&term, alternates_try + 1, Some(1))
                         {
@@ -93,10 +279,16 @@ impl ExecutorSearch {
 
                                 debug!("got completed word: {} for term: {}", suggested_word, term);
 
+                                debug.kv_reads += 1;
+
                                 if let Some(suggested_iids) = kv_action
-                                    .get_term_to_iids(StoreTermHash::from(&suggested_word))
+                                    .get_term_to_iids(StoreTermHash::from(
+                                        &LexerNormalize::fold_diacritics(&suggested_word),
+                                    ))
                                     .unwrap_or(None)
                                 {
+                                    debug.fst_suggestions_used += 1;
+
                                     for suggested_iid in suggested_iids {
                                         // Do not append the same IID twice (can happen a lot \
                                         //   when completing from suggested results that point \
@@ -132,20 +324,64 @@ impl ExecutorSearch {
 
                     debug!("got search executor iids: {:?} for term: {}", iids, term);
 
-                    // Intersect found IIDs with previous batch
-                    if found_iids.is_empty() {
+                    debug.iids_before_intersect += iids.len();
+
+                    // Accumulate term relevance for IIDs found via this term? (rarer terms are \
+                    //   more relevant)
+                    if is_sorted_by_relevance && !iids.is_empty() {
+                        let relevance = Self::relevance_idf_approx(count_iids, iids.len());
+
+                        for iid in iids.iter() {
+                            *found_iids_relevances.entry(*iid).or_insert(0.0) += relevance;
+                        }
+                    }
+
+                    // Weigh IIDs found via this term? (IIDs found via completed words for this \
+                    //   term get weighted as well)
+                    if let Some((_, weight)) = options
+                        .term_weights
+                        .iter()
+                        .find(|(weighted_term, _)| *weighted_term == term)
+                    {
+                        for iid in iids.iter() {
+                            *found_iids_weights.entry(*iid).or_insert(1.0) *= weight;
+                        }
+                    }
+
+                    // Unite found IIDs with previous batch? (if missing terms are penalized)
+                    if options.penalty_empty.is_some() {
+                        count_terms += 1;
+
+                        for iid in iids {
+                            *found_iids_matches.entry(iid).or_insert(0) += 1;
+
+                            found_iids.insert_if_absent(iid);
+                        }
+
+                        continue 'lexing;
+                    }
+
+                    // Unite found IIDs with previous batch? (if term is preceded by an OR \
+                    //   operator), otherwise intersect found IIDs with previous batch
+                    if or_terms_hashed.contains(&term_hashed) {
+                        for iid in iids {
+                            found_iids.insert_if_absent(iid);
+                        }
+                    } else if !has_found_batch {
                         found_iids = iids;
                     } else {
                         found_iids = found_iids.intersection(&iids).copied().collect();
                     }
 
+                    has_found_batch = true;
+
                     debug!(
                         "got search executor iid intersection: {:?} for term: {}",
                         found_iids, term
                     );
 
-                    // No IID found? (stop there)
-                    if found_iids.is_empty() {
+                    // No IID found? (stop there, unless an upcoming term may unite some IIDs)
+                    if found_iids.is_empty() && or_terms_hashed.is_empty() {
                         info!(
                             "stop search executor as no iid was found in common for term: {}",
                             term
@@ -155,21 +391,181 @@ impl ExecutorSearch {
                     }
                 }
 
+                debug.iids_after_intersect = found_iids.len();
+
+                // Restrict found IIDs to shard? (this is done first, as it is the cheapest filter \
+                //   and it lowers the number of IIDs that other filters have to read from KV)
+                let found_iids = if let Some(shard) = options.shard {
+                    ExecutorFilter::shard_iids(found_iids, shard)
+                } else {
+                    found_iids
+                };
+
+                // Exclude found IIDs linked to excluded terms? (this must be done before paging)
+                let found_iids = if !must_not_terms.is_empty() && !found_iids.is_empty() {
+                    ExecutorFilter::exclude_iids(&kv_action, found_iids, &must_not_terms)
+                } else {
+                    found_iids
+                };
+
+                // Filter found IIDs by creation time? (this must be done before paging)
+                let found_iids = if let Some(recent) = options.recent {
+                    ExecutorFilter::recent_iids(&kv_action, found_iids, recent)
+                } else {
+                    found_iids
+                };
+
+                // Filter found IIDs by segment? (this must be done before paging)
+                let found_iids = if let Some(segment) = options.segment {
+                    ExecutorFilter::segment_iids(&kv_action, found_iids, segment)
+                } else {
+                    found_iids
+                };
+
+                // Filter found IIDs by terms proximity? (this must be done before paging)
+                let found_iids = if let Some(span) = options.span {
+                    ExecutorProximity::span_iids(&kv_action, found_iids, &span_terms, span)
+                } else {
+                    found_iids
+                };
+
+                // Filter found IIDs by phrases? (this must be done before paging)
+                let found_iids = if !phrase_terms.is_empty() {
+                    ExecutorProximity::phrase_iids(&kv_action, found_iids, &phrase_terms)
+                } else {
+                    found_iids
+                };
+
+                // Filter found IIDs by distance? (this must be done before paging)
+                let found_iids = if let Some(within_distance) = options.within_distance {
+                    ExecutorGeo::filter_iids(&kv_action, found_iids, within_distance)
+                } else {
+                    found_iids
+                };
+
+                // Filter found IIDs by tags? (this must be done before paging too)
+                let found_iids = if !options.filter_tags.is_empty() {
+                    ExecutorFilter::filter_iids(&kv_action, found_iids, &options.filter_tags)
+                } else {
+                    found_iids
+                };
+
+                // Penalize found IIDs missing some terms? (each missing term applies a penalty)
+                let found_iids_penalties: HashMap<StoreObjectIID, QuerySearchPenalty> =
+                    if let Some(penalty) = options.penalty_empty {
+                        found_iids_matches
+                            .into_iter()
+                            .filter(|(_, matches)| *matches < count_terms)
+                            .map(|(iid, matches)| (iid, penalty.powi(count_terms - matches)))
+                            .collect()
+                    } else {
+                        HashMap::new()
+                    };
+
+                // Score found IIDs, or shuffle them if randomized (this must be done before \
+                //   paging, as it re-orders results)
+                let found_iids = if let Some(seed) = options.random {
+                    Self::shuffle_iids(found_iids, seed)
+                } else {
+                    Self::score_iids(
+                        &kv_action,
+                        found_iids,
+                        &options,
+                        &user_boosts,
+                        &found_iids_weights,
+                        &found_iids_penalties,
+                        &found_iids_relevances,
+                    )
+                };
+
                 // Resolve OIDs from IIDs
                 // Notice: we also proceed paging from there
-                let (limit_usize, offset_usize) = (limit as usize, offset as usize);
+                let limit_usize = limit as usize;
                 let mut result_oids = Vec::with_capacity(limit_usize);
 
-                'paging: for (index, found_iid) in found_iids.iter().skip(offset_usize).enumerate()
-                {
+                // Notice: a cursor skips found IIDs up to and including the last IID seen on the \
+                //   previous page (if this IID is not found anymore, paging restarts from the \
+                //   first found IID).
+                let skip_usize = match options.cursor {
+                    Some(Some(cursor_iid)) => found_iids
+                        .iter()
+                        .position(|found_iid| *found_iid == cursor_iid)
+                        .map(|position| position + 1)
+                        .unwrap_or(0),
+                    _ => offset as usize,
+                };
+
+                let mut last_iid = None;
+
+                // Notice: aliased OIDs resolve to their canonical OID, which is only returned \
+                //   once per page (thus, duplicates do not count towards the limit).
+                let mut paged_oids: HashSet<String> = HashSet::new();
+
+                'paging: for found_iid in found_iids.iter().skip(skip_usize) {
                     // Stop there?
-                    if index >= limit_usize {
+                    if result_oids.len() >= limit_usize {
                         break 'paging;
                     }
 
+                    last_iid = Some(*found_iid);
+
                     // Read IID-to-OID for this found IID
+                    debug.kv_reads += 1;
+
                     if let Ok(Some(oid)) = kv_action.get_iid_to_oid(*found_iid) {
-                        result_oids.push(oid);
+                        // Resolve OID alias? (if any)
+                        debug.kv_reads += 1;
+
+                        let oid = kv_action
+                            .get_oid_to_alias(&oid)
+                            .unwrap_or(None)
+                            .unwrap_or(oid);
+
+                        if !paged_oids.insert(oid.clone()) {
+                            debug!("skipped search executor duplicate aliased oid: {}", oid);
+
+                            continue 'paging;
+                        }
+
+                        // Append text abstract to OID? (from stored original text, if any)
+                        if let Some(abstract_chars) = options.abstract_chars {
+                            debug.kv_reads += 1;
+
+                            let text = kv_action
+                                .get_iid_to_text(*found_iid)
+                                .unwrap_or(None)
+                                .unwrap_or_default();
+
+                            // Mark up matched words in text abstract? (if asked to)
+                            result_oids.push(
+                                if let Some(highlight_style) = options.highlight_style {
+                                    Self::highlight_abstract(
+                                        &oid,
+                                        &Self::cut_abstract(&text, &found_terms, abstract_chars),
+                                        &found_terms,
+                                        highlight_style,
+                                    )
+                                } else {
+                                    format!(
+                                        "{}(\"{}\")",
+                                        oid,
+                                        Self::make_abstract(&text, &found_terms, abstract_chars)
+                                    )
+                                },
+                            );
+                        } else if options.highlight {
+                            // Append matched term offsets to OID (from stored original text)
+                            debug.kv_reads += 1;
+
+                            let text = kv_action
+                                .get_iid_to_text(*found_iid)
+                                .unwrap_or(None)
+                                .unwrap_or_default();
+
+                            result_oids.push(Self::highlight_oid(&oid, &text, &found_terms));
+                        } else {
+                            result_oids.push(oid);
+                        }
                     } else {
                         error!("failed getting search executor iid-to-oid");
                     }
@@ -177,14 +573,618 @@ impl ExecutorSearch {
 
                 info!("got search executor final oids: {:?}", result_oids);
 
-                return Ok(if !result_oids.is_empty() {
+                // Append cursor to results? (if paging with a cursor, and if the page is full, \
+                //   as there may be more results on the next page)
+                if let (Some(_), Some(last_iid)) = (options.cursor, last_iid) {
+                    if result_oids.len() >= limit_usize {
+                        result_oids.push(format!("cursor({})", encode_cursor(last_iid)));
+                    }
+                }
+
+                // Append shard to results? (this lets the Sonic Channel consumer route results \
+                //   from each shard, when uniting them)
+                if let Some((shard_id, total_shards)) = options.shard {
+                    result_oids.push(format!("shard({}/{})", shard_id, total_shards));
+                }
+
+                // Append execution details to results? (if search is debugged)
+                if options.debug {
+                    debug.duration_us = started_at.elapsed().as_micros();
+
+                    result_oids.push(debug.to_string());
+                }
+
+                let results = if !result_oids.is_empty() {
                     Some(result_oids)
                 } else {
                     None
-                });
+                };
+
+                // Cache results? (this is done while holding the bucket store read lock, so that \
+                //   no change to the bucket can happen before results get cached; partial results \
+                //   from a timed out search are not cached)
+                if let (Some(cache_key), false) = (cache_key, is_timed_out) {
+                    QueryCache::set(cache_key, collection, bucket, results.clone());
+                }
+
+                return Ok(results);
             }
         }
 
         Err(())
     }
+
+    fn score_iids(
+        kv_action: &StoreKVAction,
+        found_iids: LinkedHashSet<StoreObjectIID>,
+        options: &QuerySearchOptions,
+        user_boosts: &[(StoreTermHashed, f32)],
+        weights: &HashMap<StoreObjectIID, f32>,
+        penalties: &HashMap<StoreObjectIID, QuerySearchPenalty>,
+        relevances: &HashMap<StoreObjectIID, f32>,
+    ) -> Vec<StoreObjectIID> {
+        // Notice: user boosts are already hashed, and combine with query boosts.
+        let boosts_hashed: Vec<(StoreTermHashed, f32)> = options
+            .boosts
+            .iter()
+            .map(|(word, factor)| {
+                (
+                    StoreTermHash::from(&LexerNormalize::fold_diacritics(word)),
+                    *factor,
+                )
+            })
+            .chain(user_boosts.iter().copied())
+            .collect();
+
+        // Notice: the current time is acquired once, so that all IIDs decay relative to the same \
+        //   point in time.
+        let now = StoreTimestamp::now();
+
+        // Notice: weights and priorities are only read if an object was ever pushed with a \
+        //   weight or a priority in the bucket, which saves a read per found IID otherwise.
+        let has_weights = matches!(
+            kv_action.get_meta_to_value(StoreMetaKey::HasWeights),
+            Ok(Some(StoreMetaValue::HasWeights(true)))
+        );
+        let has_priorities = matches!(
+            kv_action.get_meta_to_value(StoreMetaKey::HasPriorities),
+            Ok(Some(StoreMetaValue::HasPriorities(true)))
+        );
+
+        // Acquire terms for all IIDs? (if boosted words are to be looked up, or if IIDs are to \
+        //   be ranked by relevance, which requires their length relative to the average length)
+        let iids_terms: HashMap<StoreObjectIID, Vec<StoreTermHashed>> =
+            if !boosts_hashed.is_empty() || !relevances.is_empty() {
+                found_iids
+                    .iter()
+                    .map(|iid| {
+                        (
+                            *iid,
+                            kv_action
+                                .get_iid_to_terms(*iid)
+                                .unwrap_or(None)
+                                .unwrap_or_default(),
+                        )
+                    })
+                    .collect()
+            } else {
+                HashMap::new()
+            };
+
+        let average_length = if !iids_terms.is_empty() {
+            iids_terms.values().map(Vec::len).sum::<usize>() as f32 / iids_terms.len() as f32
+        } else {
+            0.0
+        };
+
+        // Score each IID with its document weight, multiplied by the product of factors for all \
+        //   boosted words it contains, by the product of weights for all weighted terms it was \
+        //   found via, by its missing terms penalty, by its age decay and recency boost, and by \
+        //   its relevance (an IID that has no weight, that contains no boosted word, that was \
+        //   not found via a weighted term, that is not penalized, that does not decay and that \
+        //   is not ranked by relevance gets a neutral score)
+        let mut scored_iids: Vec<(StoreObjectIID, f32, StoreObjectPriority)> = found_iids
+            .into_iter()
+            .map(|iid| {
+                let weight = if has_weights {
+                    kv_action
+                        .get_iid_to_weight(iid)
+                        .unwrap_or(None)
+                        .unwrap_or(1.0)
+                } else {
+                    1.0
+                };
+
+                let iid_terms = iids_terms.get(&iid);
+
+                let boost = match iid_terms {
+                    Some(iid_terms) if !boosts_hashed.is_empty() => boosts_hashed
+                        .iter()
+                        .filter(|(term_hashed, _)| iid_terms.contains(term_hashed))
+                        .map(|(_, factor)| factor)
+                        .product::<f32>(),
+                    _ => 1.0,
+                };
+
+                let relevance = match (relevances.get(&iid), iid_terms) {
+                    (Some(relevance), Some(iid_terms)) => {
+                        relevance
+                            * Self::relevance_length_heuristic(iid_terms.len(), average_length)
+                    }
+                    _ => 1.0,
+                };
+
+                let term_weight = weights.get(&iid).copied().unwrap_or(1.0);
+                let penalty = penalties.get(&iid).copied().unwrap_or(1.0);
+
+                // Decay IID score by half for each elapsed half-life since its creation, and \
+                //   boost it by its recency? (IIDs without a creation time do not decay)
+                let iid_age = if options.decay.is_some() || options.boost_recency.is_some() {
+                    kv_action
+                        .get_iid_to_created(iid)
+                        .unwrap_or(None)
+                        .map(|iid_created| now.saturating_sub(iid_created))
+                } else {
+                    None
+                };
+
+                let decay = match (options.decay, iid_age) {
+                    (Some(halflife), Some(iid_age)) => Self::decay_factor(iid_age, halflife),
+                    _ => 1.0,
+                };
+
+                let recency = match (options.boost_recency, iid_age) {
+                    (Some(halflife), Some(iid_age)) => Self::recency_factor(
+                        iid_age,
+                        halflife,
+                        APP_CONF.channel.search.recency_decay_function,
+                    ),
+                    _ => 1.0,
+                };
+
+                let priority = if has_priorities {
+                    kv_action
+                        .get_iid_to_priority(iid)
+                        .unwrap_or(None)
+                        .unwrap_or(SEARCH_PRIORITY_DEFAULT)
+                } else {
+                    SEARCH_PRIORITY_DEFAULT
+                };
+
+                (
+                    iid,
+                    weight * boost * term_weight * penalty * decay * recency * relevance,
+                    priority,
+                )
+            })
+            .collect();
+
+        debug!("got search executor scored iids: {:?}", scored_iids);
+
+        // Notice: this sort is stable, thus equally-scored IIDs are ordered by priority, and \
+        //   equally-prioritized IIDs keep their natural order (ie. most recently pushed first, \
+        //   rather than by ascending IID, so that results without priorities keep their order).
+        scored_iids.sort_by(|a, b| {
+            b.1.partial_cmp(&a.1)
+                .unwrap_or(Ordering::Equal)
+                .then(b.2.cmp(&a.2))
+        });
+
+        scored_iids.into_iter().map(|(iid, _, _)| iid).collect()
+    }
+
+    fn lex_phrases(lexer: &TokenLexer, phrases: &[String]) -> Vec<Vec<StoreTermHashed>> {
+        // Notice: words that get cleaned up (eg. stop words) are left out of phrases, as they do \
+        //   not get any stored position either. A phrase left without any word is no phrase, \
+        //   rather than a phrase that any object would match.
+        phrases
+            .iter()
+            .map(|phrase| {
+                lexer
+                    .sibling_words(phrase)
+                    .map(|(_, term_hashed)| term_hashed)
+                    .collect::<Vec<StoreTermHashed>>()
+            })
+            .filter(|phrase_terms| !phrase_terms.is_empty())
+            .collect()
+    }
+
+    fn lex_exclusions(
+        lexer: &TokenLexer,
+        must_not: &[QuerySearchMustNot],
+    ) -> Vec<Vec<(String, StoreTermHashed)>> {
+        // Notice: terms are grouped per excluded word, as a single word may yield multiple \
+        //   terms (ie. its n-grams), which must then all be found to exclude an object.
+        must_not
+            .iter()
+            .flat_map(|must_not_text| {
+                must_not_text.split(|character: char| character == ',' || character.is_whitespace())
+            })
+            .map(|must_not_word| {
+                lexer
+                    .sibling(must_not_word)
+                    .collect::<Vec<(String, StoreTermHashed)>>()
+            })
+            .filter(|must_not_word_terms| !must_not_word_terms.is_empty())
+            .collect()
+    }
+
+    fn shuffle_iids(
+        found_iids: LinkedHashSet<StoreObjectIID>,
+        seed: QuerySearchRandom,
+    ) -> Vec<StoreObjectIID> {
+        // Notice: a zero seed picks a random seed, thus the order is not reproducible.
+        let mut rng = if seed == 0 {
+            SmallRng::from_entropy()
+        } else {
+            SmallRng::seed_from_u64(seed)
+        };
+
+        let mut shuffled_iids: Vec<StoreObjectIID> = found_iids.into_iter().collect();
+
+        shuffled_iids.shuffle(&mut rng);
+
+        shuffled_iids
+    }
+
+    fn is_timed_out(started_at: Instant, timeout_ms: QuerySearchTimeout) -> bool {
+        timeout_ms > 0 && started_at.elapsed() >= Duration::from_millis(timeout_ms)
+    }
+
+    fn relevance_idf_approx(count_iids: usize, count_term_iids: usize) -> f32 {
+        // Notice: this approximates an inverse document frequency (in its BM25 form, that never \
+        //   gets negative). The count of IIDs in the bucket is derived from the IID increment, \
+        //   which includes flushed IIDs, thus rarer terms may be slightly over-rated.
+        let count_iids = count_iids.max(count_term_iids) as f64;
+        let count_term_iids = count_term_iids as f64;
+
+        ((count_iids - count_term_iids + 0.5) / (count_term_iids + 0.5) + 1.0).ln() as f32
+    }
+
+    fn relevance_length_heuristic(length: usize, average_length: f32) -> f32 {
+        // Notice: this is a length heuristic shaped as the BM25 term frequency factor, though \
+        //   it is not BM25: term frequencies are not stored (terms are stored once per IID, \
+        //   thus a term frequency of 1 is assumed), and the average length is that of the \
+        //   found IIDs rather than that of all IIDs in the bucket. It favors IIDs shorter than \
+        //   the average of found IIDs.
+        let relative_length = if average_length > 0.0 {
+            length as f32 / average_length
+        } else {
+            1.0
+        };
+
+        (SEARCH_RELEVANCE_LENGTH_SATURATION + 1.0)
+            / (1.0
+                + SEARCH_RELEVANCE_LENGTH_SATURATION
+                    * (1.0 - SEARCH_RELEVANCE_LENGTH_NORMALIZATION
+                        + SEARCH_RELEVANCE_LENGTH_NORMALIZATION * relative_length))
+    }
+
+    fn decay_factor(age_secs: u64, halflife_secs: QuerySearchDecay) -> f32 {
+        (-(age_secs as f64) / halflife_secs as f64).exp2() as f32
+    }
+
+    fn recency_factor(
+        age_secs: u64,
+        halflife_secs: QuerySearchDecay,
+        function: ConfigChannelSearchRecencyDecayFunction,
+    ) -> f32 {
+        // Notice: all functions halve the factor once aged one half-life.
+        let halflives = age_secs as f64 / halflife_secs as f64;
+
+        let factor = match function {
+            ConfigChannelSearchRecencyDecayFunction::Exponential => (-halflives).exp2(),
+            ConfigChannelSearchRecencyDecayFunction::Hyperbolic => 1.0 / (1.0 + halflives),
+            ConfigChannelSearchRecencyDecayFunction::Linear => (1.0 - halflives / 2.0).max(0.0),
+        };
+
+        factor as f32
+    }
+
+    fn highlight_abstract(
+        oid: &str,
+        text_abstract: &str,
+        terms: &[String],
+        style: QuerySearchHighlightStyle,
+    ) -> String {
+        let ranges = ExecutorHighlight::locate(text_abstract, terms);
+
+        // Mark up matched words in abstract, or append their byte offsets? (offsets refer to \
+        //   the abstract before it gets escaped)
+        if style == QuerySearchHighlightStyle::None {
+            format!(
+                "{}(\"{}\",{})",
+                oid,
+                Self::escape_abstract(text_abstract),
+                ExecutorHighlight::format_ranges(&ranges)
+            )
+        } else {
+            format!(
+                "{}(\"{}\")",
+                oid,
+                Self::escape_abstract(&ExecutorHighlight::markup(text_abstract, &ranges, style))
+            )
+        }
+    }
+
+    fn highlight_oid(oid: &str, text: &str, terms: &[String]) -> String {
+        let ranges = ExecutorHighlight::locate(text, terms);
+
+        // Notice: OIDs with no matched term in text are returned as-is (eg. if they were found \
+        //   via a synonym, or if their original text was pushed before it got stored).
+        if ranges.is_empty() {
+            oid.to_string()
+        } else {
+            format!("{}:{}", oid, ExecutorHighlight::format_offsets(&ranges))
+        }
+    }
+
+    fn make_abstract(text: &str, terms: &[String], max_chars: QuerySearchAbstract) -> String {
+        Self::escape_abstract(&Self::cut_abstract(text, terms, max_chars))
+    }
+
+    fn cut_abstract(text: &str, terms: &[String], max_chars: QuerySearchAbstract) -> String {
+        // Locate the first word in text that matches any search term (search terms are \
+        //   normalized, thus words are compared lower-cased; words are matched as prefixes, as \
+        //   search terms may have been completed with suggested words)
+        let match_index = text
+            .unicode_word_indices()
+            .find(|(_, word)| {
+                let word = word.to_lowercase();
+
+                terms.iter().any(|term| word.starts_with(term.as_str()))
+            })
+            .map(|(index, _)| text[..index].chars().count())
+            .unwrap_or(0);
+
+        // Center abstract on matched word (shifting it if it overflows text boundaries)
+        let count_chars = text.chars().count();
+        let start_index = match_index
+            .saturating_sub(max_chars / 2)
+            .min(count_chars.saturating_sub(max_chars));
+
+        text.chars().skip(start_index).take(max_chars).collect()
+    }
+
+    fn escape_abstract(text_abstract: &str) -> String {
+        // Escape abstract, as it gets wrapped in quotes and sent over a line-based protocol
+        let mut abstract_text = String::with_capacity(text_abstract.len());
+
+        for character in text_abstract.chars() {
+            match character {
+                '"' | '\\' => {
+                    abstract_text.push('\\');
+                    abstract_text.push(character);
+                }
+                '\r' | '\n' | '\t' => abstract_text.push(' '),
+                _ => abstract_text.push(character),
+            }
+        }
+
+        abstract_text
+    }
+}
+
+impl fmt::Display for ExecutorSearchDebug {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "debug(terms_lexed={} terms_found={} terms_missed={} iids_before_intersect={} \
+                iids_after_intersect={} fst_suggestions_used={} kv_reads={} fst_reads={} \
+                duration_us={})",
+            self.terms_lexed,
+            self.terms_found,
+            self.terms_missed,
+            self.iids_before_intersect,
+            self.iids_after_intersect,
+            self.fst_suggestions_used,
+            self.kv_reads,
+            self.fst_reads,
+            self.duration_us
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::lexer::token::{TokenLexerBuilder, TokenLexerMode};
+    use whatlang::Lang;
+
+    #[test]
+    fn it_formats_debug() {
+        let debug = ExecutorSearchDebug {
+            terms_lexed: 3,
+            terms_found: 2,
+            terms_missed: 1,
+            iids_before_intersect: 150,
+            iids_after_intersect: 12,
+            fst_suggestions_used: 1,
+            kv_reads: 8,
+            fst_reads: 3,
+            duration_us: 847,
+        };
+
+        assert_eq!(
+            debug.to_string(),
+            "debug(terms_lexed=3 terms_found=2 terms_missed=1 iids_before_intersect=150 \
+                iids_after_intersect=12 fst_suggestions_used=1 kv_reads=8 fst_reads=3 \
+                duration_us=847)"
+        );
+    }
+
+    #[test]
+    fn it_highlights_oids() {
+        assert_eq!(
+            ExecutorSearch::highlight_oid("doc", "hello world", &["hello".to_string()]),
+            "doc:0-5"
+        );
+        assert_eq!(
+            ExecutorSearch::highlight_oid(
+                "doc",
+                "Hello world, hello",
+                &["hello".to_string(), "world".to_string()]
+            ),
+            "doc:0-5,6-11,13-18"
+        );
+        assert_eq!(
+            ExecutorSearch::highlight_oid("doc", "hello world", &["cat".to_string()]),
+            "doc"
+        );
+    }
+
+    #[test]
+    fn it_computes_decay_factor() {
+        assert_eq!(ExecutorSearch::decay_factor(0, 3600), 1.0);
+        assert_eq!(ExecutorSearch::decay_factor(3600, 3600), 0.5);
+        assert_eq!(ExecutorSearch::decay_factor(7200, 3600), 0.25);
+    }
+
+    #[test]
+    fn it_lexes_phrases() {
+        let lexer = TokenLexerBuilder::from(
+            TokenLexerMode::NormalizeAndCleanup(Some(Lang::Eng)),
+            "the quick fox jumps",
+        )
+        .unwrap();
+
+        let phrases =
+            ExecutorSearch::lex_phrases(&lexer, &["the quick fox".to_string(), "the".to_string()]);
+
+        assert_eq!(
+            phrases,
+            vec![vec![
+                StoreTermHash::from("quick"),
+                StoreTermHash::from("fox")
+            ]]
+        );
+    }
+
+    #[test]
+    fn it_lexes_exclusions() {
+        let lexer =
+            TokenLexerBuilder::from(TokenLexerMode::NgramAndCleanup(2), "東京 trucks").unwrap();
+
+        let exclusions = ExecutorSearch::lex_exclusions(&lexer, &["trucks,東京,".to_string()]);
+
+        assert_eq!(exclusions.len(), 2);
+        assert_eq!(
+            exclusions[0],
+            vec![("trucks".to_string(), StoreTermHash::from("trucks"))]
+        );
+        assert_eq!(
+            exclusions[1]
+                .iter()
+                .map(|(term, _)| term.as_str())
+                .collect::<Vec<&str>>(),
+            vec!["東", "京", "東京"]
+        );
+    }
+
+    #[test]
+    fn it_shuffles_iids() {
+        let found_iids: LinkedHashSet<StoreObjectIID> = (1..=20).collect();
+
+        let shuffled_iids = ExecutorSearch::shuffle_iids(found_iids.clone(), 42);
+
+        assert_eq!(
+            shuffled_iids,
+            ExecutorSearch::shuffle_iids(found_iids.clone(), 42)
+        );
+        assert_ne!(shuffled_iids, (1..=20).collect::<Vec<StoreObjectIID>>());
+
+        let mut sorted_iids = ExecutorSearch::shuffle_iids(found_iids, 0);
+
+        sorted_iids.sort_unstable();
+
+        assert_eq!(sorted_iids, (1..=20).collect::<Vec<StoreObjectIID>>());
+    }
+
+    #[test]
+    fn it_times_out() {
+        let started_at = Instant::now() - Duration::from_millis(10);
+
+        assert!(ExecutorSearch::is_timed_out(started_at, 1));
+        assert!(!ExecutorSearch::is_timed_out(started_at, 0));
+        assert!(!ExecutorSearch::is_timed_out(started_at, 60000));
+    }
+
+    #[test]
+    fn it_computes_relevance() {
+        // Rarer terms are more relevant
+        assert!(
+            ExecutorSearch::relevance_idf_approx(100, 1)
+                > ExecutorSearch::relevance_idf_approx(100, 50)
+        );
+        assert!(ExecutorSearch::relevance_idf_approx(100, 100) > 0.0);
+        assert!(ExecutorSearch::relevance_idf_approx(0, 10) > 0.0);
+
+        // Shorter objects are more relevant
+        assert_eq!(ExecutorSearch::relevance_length_heuristic(10, 10.0), 1.0);
+        assert!(
+            ExecutorSearch::relevance_length_heuristic(5, 10.0)
+                > ExecutorSearch::relevance_length_heuristic(20, 10.0)
+        );
+
+        // Objects sharing more terms with the query are more relevant (3 objects of the same \
+        //   length, the first one being found via 2 terms, the other ones via 1 term each)
+        let idf = ExecutorSearch::relevance_idf_approx(3, 2);
+        let length_factor = ExecutorSearch::relevance_length_heuristic(4, 4.0);
+
+        let (score_both, score_one) = ((idf + idf) * length_factor, idf * length_factor);
+
+        assert!(score_both > score_one);
+    }
+
+    #[test]
+    fn it_computes_recency_factor() {
+        let (exponential, hyperbolic, linear) = (
+            ConfigChannelSearchRecencyDecayFunction::Exponential,
+            ConfigChannelSearchRecencyDecayFunction::Hyperbolic,
+            ConfigChannelSearchRecencyDecayFunction::Linear,
+        );
+
+        assert_eq!(ExecutorSearch::recency_factor(0, 3600, hyperbolic), 1.0);
+        assert_eq!(ExecutorSearch::recency_factor(3600, 3600, hyperbolic), 0.5);
+        assert_eq!(
+            ExecutorSearch::recency_factor(10800, 3600, hyperbolic),
+            0.25
+        );
+        assert_eq!(
+            ExecutorSearch::recency_factor(7200, 3600, exponential),
+            0.25
+        );
+        assert_eq!(ExecutorSearch::recency_factor(3600, 3600, linear), 0.5);
+        assert_eq!(ExecutorSearch::recency_factor(10800, 3600, linear), 0.0);
+    }
+
+    #[test]
+    fn it_makes_abstract() {
+        let text = "The quick brown fox jumps over the lazy dog";
+
+        assert_eq!(
+            ExecutorSearch::make_abstract(text, &["jumps".to_string()], 10),
+            " fox jumps"
+        );
+        assert_eq!(
+            ExecutorSearch::make_abstract(text, &["quick".to_string()], 12),
+            "The quick br"
+        );
+        assert_eq!(
+            ExecutorSearch::make_abstract(text, &["laz".to_string()], 12),
+            "r the lazy d"
+        );
+        assert_eq!(
+            ExecutorSearch::make_abstract(text, &["dog".to_string()], 12),
+            "the lazy dog"
+        );
+        assert_eq!(
+            ExecutorSearch::make_abstract(text, &["cat".to_string()], 9),
+            "The quick"
+        );
+        assert_eq!(
+            ExecutorSearch::make_abstract("Say \"hi\"\nnow", &["now".to_string()], 20),
+            "Say \\\"hi\\\" now"
+        );
+    }
 }
@@ -0,0 +1,59 @@
+// Sonic
+//
+// Fast, lightweight and schema-less search backend
+// Copyright: 2019, Valerian Saliou <valerian@valeriansaliou.name>
+// License: Mozilla Public License v2.0 (MPL v2.0)
+
+use crate::lexer::token::TokenLexer;
+use crate::query::types::QuerySearchID;
+use crate::store::fst::{StoreFSTActionBuilder, StoreFSTPool};
+use crate::store::item::StoreItem;
+
+pub struct ExecutorSpellcheck;
+
+impl ExecutorSpellcheck {
+    pub fn execute<'a>(
+        store: StoreItem<'a>,
+        _event_id: QuerySearchID,
+        lexer: TokenLexer<'a>,
+    ) -> Result<Option<Vec<String>>, ()> {
+        if let StoreItem(collection, Some(bucket), None) = store {
+            // Important: acquire graph access read lock, and reference it in context. This \
+            //   prevents the graph from being erased while using it in this block.
+            general_fst_access_lock_read!();
+
+            if let Ok(fst_store) = StoreFSTPool::acquire(collection, bucket) {
+                let fst_action = StoreFSTActionBuilder::access(fst_store);
+
+                let mut has_corrections = false;
+                let mut corrected_words = Vec::new();
+
+                // Correct each term with the top suggested word (a term that is already in the \
+                //   graph comes first in suggestions, thus it is left as-is)
+                for (term, _) in lexer {
+                    debug!("running spellcheck on word: {}", term);
+
+                    let corrected_word = fst_action
+                        .suggest_words(&term, 1, Some(1))
+                        .and_then(|mut words| words.pop())
+                        .unwrap_or_else(|| term.clone());
+
+                    if corrected_word != term {
+                        has_corrections = true;
+                    }
+
+                    corrected_words.push(corrected_word);
+                }
+
+                // Only return corrected words if at least one word was corrected
+                return Ok(if has_corrections {
+                    Some(corrected_words)
+                } else {
+                    None
+                });
+            }
+        }
+
+        Err(())
+    }
+}
@@ -4,23 +4,32 @@
 // Copyright: 2019, Valerian Saliou <valerian@valeriansaliou.name>
 // License: Mozilla Public License v2.0 (MPL v2.0)
 
+use crate::lexer::normalize::LexerNormalize;
 use crate::lexer::token::TokenLexer;
-use crate::query::types::{QuerySearchID, QuerySearchLimit};
+use crate::query::types::{QuerySearchID, QuerySearchLimit, QuerySuggestMinFreq};
 use crate::store::fst::{StoreFSTActionBuilder, StoreFSTPool};
+use crate::store::identifiers::StoreTermHash;
 use crate::store::item::StoreItem;
+use crate::store::kv::{StoreKVAcquireMode, StoreKVActionBuilder, StoreKVPool};
 
 pub struct ExecutorSuggest;
 
+const SUGGEST_MIN_FREQ_CANDIDATES_FACTOR: usize = 10;
+
 impl ExecutorSuggest {
     pub fn execute<'a>(
         store: StoreItem<'a>,
         _event_id: QuerySearchID,
         mut lexer: TokenLexer<'a>,
         limit: QuerySearchLimit,
+        expand: bool,
+        min_freq: Option<QuerySuggestMinFreq>,
     ) -> Result<Option<Vec<String>>, ()> {
         if let StoreItem(collection, Some(bucket), None) = store {
-            // Important: acquire graph access read lock, and reference it in context. This \
-            //   prevents the graph from being erased while using it in this block.
+            // Important: acquire database and graph access read locks, and reference them in \
+            //   context. This prevents the database and graph from being erased while using \
+            //   them in this block.
+            general_kv_access_lock_read!();
             general_fst_access_lock_read!();
 
             if let Ok(fst_store) = StoreFSTPool::acquire(collection, bucket) {
@@ -29,7 +38,63 @@ impl ExecutorSuggest {
                 if let (Some(word), None) = (lexer.next(), lexer.next()) {
                     debug!("running suggest on word: {}", word.0);
 
-                    return Ok(fst_action.suggest_words(&word.0, limit as usize, None));
+                    // Suggest more words than requested when filtering on frequency, as some \
+                    //   get dropped
+                    let suggest_limit = if min_freq.is_some() {
+                        limit as usize * SUGGEST_MIN_FREQ_CANDIDATES_FACTOR
+                    } else {
+                        limit as usize
+                    };
+
+                    let found_words = fst_action.suggest_words(&word.0, suggest_limit, None);
+
+                    // Expand suggested words with the count of objects they appear in, or \
+                    //   filter out words that appear in too few objects?
+                    if let (true, Some(found_words)) = (expand || min_freq.is_some(), &found_words)
+                    {
+                        let kv_store =
+                            StoreKVPool::acquire(StoreKVAcquireMode::OpenOnly, collection)?;
+
+                        // Important: acquire bucket store read lock
+                        executor_kv_lock_read!(kv_store);
+
+                        let kv_action = StoreKVActionBuilder::access(bucket, kv_store);
+
+                        let counted_words: Vec<String> = found_words
+                            .iter()
+                            .filter_map(|found_word| {
+                                let count = kv_action
+                                    .get_term_to_iids(StoreTermHash::from(
+                                        &LexerNormalize::fold_diacritics(found_word),
+                                    ))
+                                    .unwrap_or(None)
+                                    .map(|iids| iids.len())
+                                    .unwrap_or(0);
+
+                                if count < min_freq.unwrap_or(0) as usize {
+                                    debug!(
+                                        "suggest dropped infrequent word: {} ({})",
+                                        found_word, count
+                                    );
+
+                                    None
+                                } else if expand {
+                                    Some(format!("{}({})", found_word, count))
+                                } else {
+                                    Some(found_word.to_owned())
+                                }
+                            })
+                            .take(limit as usize)
+                            .collect();
+
+                        return Ok(if !counted_words.is_empty() {
+                            Some(counted_words)
+                        } else {
+                            None
+                        });
+                    }
+
+                    return Ok(found_words);
                 }
             }
         }
@@ -0,0 +1,154 @@
+// Sonic
+//
+// Fast, lightweight and schema-less search backend
+// Copyright: 2019, Valerian Saliou <valerian@valeriansaliou.name>
+// License: Mozilla Public License v2.0 (MPL v2.0)
+
+use hashbrown::HashSet;
+use std::cmp::Reverse;
+
+use crate::lexer::normalize::LexerNormalize;
+use crate::lexer::token::TokenLexer;
+use crate::query::types::{
+    QuerySearchID, QuerySearchLimit, QuerySuggestContext, QuerySuggestMinFreq,
+};
+use crate::store::fst::{StoreFSTActionBuilder, StoreFSTPool};
+use crate::store::identifiers::{StoreObjectIID, StoreTermHash};
+use crate::store::item::StoreItem;
+use crate::store::kv::{StoreKVAcquireMode, StoreKVActionBuilder, StoreKVPool};
+
+pub struct ExecutorSuggestContext;
+
+const SUGGEST_CONTEXT_CANDIDATES_FACTOR: usize = 10;
+
+impl ExecutorSuggestContext {
+    pub fn execute<'a>(
+        store: StoreItem<'a>,
+        _event_id: QuerySearchID,
+        mut lexer: TokenLexer<'a>,
+        limit: QuerySearchLimit,
+        context: QuerySuggestContext,
+        expand: bool,
+        min_freq: Option<QuerySuggestMinFreq>,
+    ) -> Result<Option<Vec<String>>, ()> {
+        if let StoreItem(collection, Some(bucket), None) = store {
+            // Important: acquire database and graph access read locks, and reference them in \
+            //   context. This prevents the database and graph from being erased while using \
+            //   them in this block.
+            general_kv_access_lock_read!();
+            general_fst_access_lock_read!();
+
+            if let (Ok(kv_store), Ok(fst_store)) = (
+                StoreKVPool::acquire(StoreKVAcquireMode::OpenOnly, collection),
+                StoreFSTPool::acquire(collection, bucket),
+            ) {
+                // Important: acquire bucket store read lock
+                executor_kv_lock_read!(kv_store);
+
+                let (kv_action, fst_action) = (
+                    StoreKVActionBuilder::access(bucket, kv_store),
+                    StoreFSTActionBuilder::access(fst_store),
+                );
+
+                if let (Some(word), None) = (lexer.next(), lexer.next()) {
+                    debug!(
+                        "running suggest on word: {} with context: {:?}",
+                        word.0, context
+                    );
+
+                    // Acquire IIDs of objects that contain all context words
+                    let mut context_iids: Option<HashSet<StoreObjectIID>> = None;
+
+                    for context_word in &context {
+                        let word_iids: HashSet<StoreObjectIID> = kv_action
+                            .get_term_to_iids(StoreTermHash::from(
+                                &LexerNormalize::fold_diacritics(context_word),
+                            ))
+                            .unwrap_or(None)
+                            .unwrap_or_default()
+                            .into_iter()
+                            .collect();
+
+                        context_iids = Some(if let Some(context_iids) = context_iids {
+                            context_iids.intersection(&word_iids).copied().collect()
+                        } else {
+                            word_iids
+                        });
+                    }
+
+                    let context_iids = context_iids.unwrap_or_default();
+
+                    debug!("got suggest context iids: {:?}", context_iids);
+
+                    // No object matches context? (nothing can be suggested)
+                    if context_iids.is_empty() {
+                        return Ok(None);
+                    }
+
+                    // Rank completed words by the number of context objects they appear in \
+                    //   (more completions than requested are looked up, as some get dropped)
+                    let mut ranked_words: Vec<(String, usize)> = fst_action
+                        .suggest_words(
+                            &word.0,
+                            limit as usize * SUGGEST_CONTEXT_CANDIDATES_FACTOR,
+                            None,
+                        )
+                        .unwrap_or_default()
+                        .into_iter()
+                        .filter_map(|suggested_word| {
+                            let word_iids = kv_action
+                                .get_term_to_iids(StoreTermHash::from(
+                                    &LexerNormalize::fold_diacritics(&suggested_word),
+                                ))
+                                .unwrap_or(None)
+                                .unwrap_or_default();
+
+                            // Drop word if it appears in too few objects overall? (frequency \
+                            //   is not restricted to objects matching context)
+                            if word_iids.len() < min_freq.unwrap_or(0) as usize {
+                                return None;
+                            }
+
+                            let occurrences = word_iids
+                                .iter()
+                                .filter(|iid| context_iids.contains(*iid))
+                                .count();
+
+                            if occurrences > 0 {
+                                Some((suggested_word, occurrences))
+                            } else {
+                                None
+                            }
+                        })
+                        .collect();
+
+                    // Notice: this sort is stable, thus equally-ranked words keep their FST order.
+                    ranked_words.sort_by_key(|ranked_word| Reverse(ranked_word.1));
+
+                    debug!("got suggest context ranked words: {:?}", ranked_words);
+
+                    // Notice: when expanded, words are counted against objects matching context.
+                    let found_words: Vec<String> = ranked_words
+                        .into_iter()
+                        .take(limit as usize)
+                        .map(|(suggested_word, occurrences)| {
+                            if expand {
+                                format!("{}({})", suggested_word, occurrences)
+                            } else {
+                                suggested_word
+                            }
+                        })
+                        .collect();
+
+                    return Ok(if !found_words.is_empty() {
+                        Some(found_words)
+                    } else {
+                        None
+                    });
+                }
+            }
+        }
+
+        Err(())
+    }
+}
@@ -0,0 +1,37 @@
+// Sonic
+//
+// Fast, lightweight and schema-less search backend
+// Copyright: 2019, Valerian Saliou <valerian@valeriansaliou.name>
+// License: Mozilla Public License v2.0 (MPL v2.0)
+
+use crate::store::item::StoreItem;
+use crate::store::kv::{StoreKVAcquireMode, StoreKVMisc, StoreKVPool};
+
+pub struct ExecutorVacuum;
+
+impl ExecutorVacuum {
+    pub fn execute(store: StoreItem) -> Result<u32, ()> {
+        if let StoreItem(collection, None, None) = store {
+            // Important: acquire database access read lock, and reference it in context. This \
+            //   prevents the database from being erased while using it in this block.
+            general_kv_access_lock_read!();
+
+            if let Ok(kv_store) = StoreKVPool::acquire(StoreKVAcquireMode::OpenOnly, collection) {
+                // Important: acquire bucket store write lock
+                executor_kv_lock_write!(kv_store);
+
+                let count = StoreKVMisc::batch_vacuum_collection(&kv_store)?;
+
+                info!(
+                    "vacuum executor deleted {} keys in collection: {}",
+                    count,
+                    collection.as_str()
+                );
+
+                return Ok(count);
+            }
+        }
+
+        Err(())
+    }
+}
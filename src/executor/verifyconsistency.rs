@@ -0,0 +1,78 @@
+// Sonic
+//
+// Fast, lightweight and schema-less search backend
+// Copyright: 2019, Valerian Saliou <valerian@valeriansaliou.name>
+// License: Mozilla Public License v2.0 (MPL v2.0)
+
+use hashbrown::HashSet;
+
+use crate::store::fst::{StoreFSTActionBuilder, StoreFSTMisc, StoreFSTPool};
+use crate::store::item::StoreItem;
+use crate::store::kv::{StoreKVAcquireMode, StoreKVMisc, StoreKVPool};
+
+pub struct ExecutorVerifyConsistency;
+
+impl ExecutorVerifyConsistency {
+    pub fn execute(store: StoreItem) -> Result<(usize, usize, usize), ()> {
+        if let StoreItem(collection, None, None) = store {
+            // Important: acquire database and graph access read locks, and reference them in \
+            //   context. This prevents the database and graph from being erased while using \
+            //   them in this block.
+            general_kv_access_lock_read!();
+            general_fst_access_lock_read!();
+
+            if let Ok(kv_store) = StoreKVPool::acquire(StoreKVAcquireMode::OpenOnly, collection) {
+                // Important: acquire bucket store read lock
+                executor_kv_lock_read!(kv_store);
+
+                // List terms from all buckets in KV, and buckets in FST (buckets may exist in \
+                //   only one of the two stores; they are all to be compared)
+                let mut kv_buckets = StoreKVMisc::scan_collection_terms(&kv_store)?;
+                let fst_buckets = StoreFSTMisc::list_collection_buckets(collection.as_str())?;
+
+                let (mut count_kv_only, mut count_fst_only, mut count_common) = (0, 0, 0);
+
+                for fst_bucket in &fst_buckets {
+                    let kv_terms = kv_buckets.remove(fst_bucket).unwrap_or_default();
+
+                    let fst_terms = StoreFSTActionBuilder::access(StoreFSTPool::acquire_atom(
+                        collection.as_str(),
+                        *fst_bucket,
+                    )?)
+                    .hash_words();
+
+                    let count_bucket_common = kv_terms.intersection(&fst_terms).count();
+
+                    debug!(
+                        "verify consistency executor compared bucket: <{:x?}> with {} kv terms \
+                            and {} fst terms ({} common)",
+                        fst_bucket,
+                        kv_terms.len(),
+                        fst_terms.len(),
+                        count_bucket_common
+                    );
+
+                    count_kv_only += kv_terms.len() - count_bucket_common;
+                    count_fst_only += fst_terms.len() - count_bucket_common;
+                    count_common += count_bucket_common;
+                }
+
+                // Remaining KV buckets have no FST (all their terms are KV-only)
+                count_kv_only += kv_buckets.values().map(HashSet::len).sum::<usize>();
+
+                info!(
+                    "verify consistency executor found {} kv-only, {} fst-only and {} common \
+                        terms in collection: {}",
+                    count_kv_only,
+                    count_fst_only,
+                    count_common,
+                    collection.as_str()
+                );
+
+                return Ok((count_kv_only, count_fst_only, count_common));
+            }
+        }
+
+        Err(())
+    }
+}
@@ -0,0 +1,59 @@
+// Sonic
+//
+// Fast, lightweight and schema-less search backend
+// Copyright: 2019, Valerian Saliou <valerian@valeriansaliou.name>
+// License: Mozilla Public License v2.0 (MPL v2.0)
+
+use crate::store::fst::{StoreFSTActionBuilder, StoreFSTMisc};
+use crate::store::item::StoreItem;
+
+pub struct ExecutorVerifyFST;
+
+impl ExecutorVerifyFST {
+    pub fn execute(store: StoreItem, repair: bool) -> Result<Option<String>, ()> {
+        if let StoreItem(collection, Some(bucket), None) = store {
+            let verify_result = {
+                // Important: acquire graph access read lock, and reference it in context. This \
+                //   prevents the graph from being erased while using it in this block.
+                general_fst_access_lock_read!();
+
+                StoreFSTMisc::verify_bucket(collection, bucket)
+            };
+
+            return match verify_result {
+                Ok(count_words) => {
+                    info!(
+                        "verify fst executor found {} valid words in bucket: {}",
+                        count_words,
+                        bucket.as_str()
+                    );
+
+                    Ok(None)
+                }
+                Err(verify_error) => {
+                    warn!(
+                        "verify fst executor found corrupted bucket: {} because: {}",
+                        bucket.as_str(),
+                        verify_error
+                    );
+
+                    // Repair graph? (erase it; as graph words cannot be recovered from hashed \
+                    //   terms in KV, the graph gets re-populated by subsequent pushes)
+                    if repair {
+                        // Acquire FST lock in write mode, as we will erase it, we need to \
+                        //   prevent any other consumer to use it.
+                        general_fst_access_lock_write!();
+
+                        StoreFSTActionBuilder::erase(collection, Some(bucket))?;
+
+                        info!("verify fst executor erased bucket: {}", bucket.as_str());
+                    }
+
+                    Ok(Some(verify_error))
+                }
+            };
+        }
+
+        Err(())
+    }
+}
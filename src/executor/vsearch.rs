@@ -0,0 +1,83 @@
+// Sonic
+//
+// Fast, lightweight and schema-less search backend
+// Copyright: 2019, Valerian Saliou <valerian@valeriansaliou.name>
+// License: Mozilla Public License v2.0 (MPL v2.0)
+
+use std::cmp::Ordering;
+
+use crate::query::types::{QueryGenericVector, QuerySearchID, QuerySearchLimit};
+use crate::store::identifiers::StoreObjectIID;
+use crate::store::item::StoreItem;
+use crate::store::kv::{StoreKVAcquireMode, StoreKVActionBuilder, StoreKVPool};
+
+pub struct ExecutorVsearch;
+
+impl ExecutorVsearch {
+    pub fn execute(
+        store: StoreItem,
+        _event_id: QuerySearchID,
+        vector: QueryGenericVector,
+        limit: QuerySearchLimit,
+    ) -> Result<Vec<String>, ()> {
+        if let StoreItem(collection, Some(bucket), None) = store {
+            // Important: acquire database access read lock, and reference it in context. This \
+            //   prevents the database from being erased while using it in this block.
+            general_kv_access_lock_read!();
+
+            if let Ok(kv_store) = StoreKVPool::acquire(StoreKVAcquireMode::OpenOnly, collection) {
+                // Important: acquire bucket store read lock
+                executor_kv_lock_read!(kv_store);
+
+                let kv_action = StoreKVActionBuilder::access(bucket, kv_store);
+
+                // Score all vectors in bucket against searched vector (this is a flat scan, \
+                //   which yields exact nearest neighbors); as vectors are normalized upon being \
+                //   stored, their cosine similarity is their dot product
+                let mut scored_iids: Vec<(StoreObjectIID, f32)> = kv_action
+                    .scan_iid_to_vector()?
+                    .into_iter()
+                    .filter(|(_, iid_vector)| iid_vector.len() == vector.len())
+                    .map(|(iid, iid_vector)| (iid, Self::similarity(&vector, &iid_vector)))
+                    .collect();
+
+                debug!(
+                    "vsearch executor scored {} vectors in bucket: {}",
+                    scored_iids.len(),
+                    bucket.as_str()
+                );
+
+                // Rank IIDs by descending similarity, and keep nearest ones
+                scored_iids.sort_unstable_by(|(_, score_a), (_, score_b)| {
+                    score_b.partial_cmp(score_a).unwrap_or(Ordering::Equal)
+                });
+                scored_iids.truncate(limit as usize);
+
+                // Resolve OIDs from IIDs
+                let mut result_oids = Vec::with_capacity(scored_iids.len());
+
+                for (iid, _) in scored_iids {
+                    if let Ok(Some(oid)) = kv_action.get_iid_to_oid(iid) {
+                        result_oids.push(oid);
+                    } else {
+                        error!("failed getting vsearch executor iid-to-oid: {}", iid);
+                    }
+                }
+
+                info!("got vsearch executor result oids: {:?}", result_oids);
+
+                return Ok(result_oids);
+            }
+        }
+
+        Err(())
+    }
+
+    fn similarity(vector_a: &[f32], vector_b: &[f32]) -> f32 {
+        vector_a
+            .iter()
+            .zip(vector_b.iter())
+            .map(|(component_a, component_b)| component_a * component_b)
+            .sum()
+    }
+}
@@ -4,7 +4,10 @@
 // Copyright: 2019, Valerian Saliou <valerian@valeriansaliou.name>
 // License: Mozilla Public License v2.0 (MPL v2.0)
 
-mod stopwords;
+pub mod stopwords;
 
+pub mod normalize;
 pub mod ranges;
+pub mod stemmer;
+pub mod synonyms;
 pub mod token;
@@ -0,0 +1,58 @@
+// Sonic
+//
+// Fast, lightweight and schema-less search backend
+// Copyright: 2019, Valerian Saliou <valerian@valeriansaliou.name>
+// License: Mozilla Public License v2.0 (MPL v2.0)
+
+use unicode_normalization::UnicodeNormalization;
+
+pub struct LexerNormalize;
+
+const RANGE_DIACRITICS: &[(char, char)] = &[
+    ('\u{0300}', '\u{036F}'),
+    ('\u{1AB0}', '\u{1AFF}'),
+    ('\u{1DC0}', '\u{1DFF}'),
+    ('\u{20D0}', '\u{20FF}'),
+    ('\u{FE20}', '\u{FE2F}'),
+];
+
+impl LexerNormalize {
+    pub fn fold_diacritics(word: &str) -> String {
+        // Nothing to fold? (ASCII words never hold any diacritic)
+        if word.is_ascii() {
+            return word.to_string();
+        }
+
+        // Decompose word characters to their base character followed by combining marks, strip \
+        //   diacritic marks, and then re-compose remaining characters.
+        // Notice: only marks from the combining diacritical blocks are stripped, as marks from \
+        //   other scripts (eg. Devanagari vowel signs or Japanese voicing marks) are part of the \
+        //   spelling of words, and thus must be kept.
+        word.nfd()
+            .filter(|character| !Self::is_diacritic(*character))
+            .nfc()
+            .collect()
+    }
+
+    fn is_diacritic(character: char) -> bool {
+        RANGE_DIACRITICS
+            .iter()
+            .any(|(range_from, range_to)| character >= *range_from && character <= *range_to)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_folds_diacritics() {
+        assert_eq!(LexerNormalize::fold_diacritics("cafe"), "cafe");
+        assert_eq!(LexerNormalize::fold_diacritics("café"), "cafe");
+        assert_eq!(LexerNormalize::fold_diacritics("crème"), "creme");
+        assert_eq!(LexerNormalize::fold_diacritics("übergrößen"), "ubergroßen");
+        assert_eq!(LexerNormalize::fold_diacritics("ελληνικά"), "ελληνικα");
+        assert_eq!(LexerNormalize::fold_diacritics("が"), "が");
+        assert_eq!(LexerNormalize::fold_diacritics("한국어"), "한국어");
+    }
+}
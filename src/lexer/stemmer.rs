@@ -0,0 +1,70 @@
+// Sonic
+//
+// Fast, lightweight and schema-less search backend
+// Copyright: 2019, Valerian Saliou <valerian@valeriansaliou.name>
+// License: Mozilla Public License v2.0 (MPL v2.0)
+
+use rust_stemmers::{Algorithm, Stemmer};
+use whatlang::Lang;
+
+pub struct LexerStemmer {
+    stemmer: Stemmer,
+}
+
+impl LexerStemmer {
+    pub fn from(locale: Lang) -> Option<LexerStemmer> {
+        // Notice: not all languages have a stemming algorithm, in which case words are left \
+        //   as-is.
+        Self::algorithm(locale).map(|algorithm| LexerStemmer {
+            stemmer: Stemmer::create(algorithm),
+        })
+    }
+
+    pub fn stem(&self, word: &str) -> String {
+        self.stemmer.stem(word).into_owned()
+    }
+
+    fn algorithm(locale: Lang) -> Option<Algorithm> {
+        match locale {
+            Lang::Ara => Some(Algorithm::Arabic),
+            Lang::Dan => Some(Algorithm::Danish),
+            Lang::Nld => Some(Algorithm::Dutch),
+            Lang::Eng => Some(Algorithm::English),
+            Lang::Fin => Some(Algorithm::Finnish),
+            Lang::Fra => Some(Algorithm::French),
+            Lang::Deu => Some(Algorithm::German),
+            Lang::Ell => Some(Algorithm::Greek),
+            Lang::Hun => Some(Algorithm::Hungarian),
+            Lang::Ita => Some(Algorithm::Italian),
+            Lang::Nob => Some(Algorithm::Norwegian),
+            Lang::Por => Some(Algorithm::Portuguese),
+            Lang::Ron => Some(Algorithm::Romanian),
+            Lang::Rus => Some(Algorithm::Russian),
+            Lang::Spa => Some(Algorithm::Spanish),
+            Lang::Swe => Some(Algorithm::Swedish),
+            Lang::Tam => Some(Algorithm::Tamil),
+            Lang::Tur => Some(Algorithm::Turkish),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_stems_words() {
+        let stemmer = LexerStemmer::from(Lang::Eng).unwrap();
+
+        assert_eq!(stemmer.stem("running"), stemmer.stem("run"));
+        assert_eq!(stemmer.stem("quickly"), stemmer.stem("quick"));
+        assert_eq!(stemmer.stem("run"), "run");
+    }
+
+    #[test]
+    fn it_does_not_stem_unsupported_languages() {
+        assert!(LexerStemmer::from(Lang::Fra).is_some());
+        assert!(LexerStemmer::from(Lang::Jpn).is_none());
+    }
+}
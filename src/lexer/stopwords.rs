@@ -4,7 +4,11 @@
 // Copyright: 2019, Valerian Saliou <valerian@valeriansaliou.name>
 // License: Mozilla Public License v2.0 (MPL v2.0)
 
-use hashbrown::HashSet;
+use hashbrown::{HashMap, HashSet};
+use std::fs::File;
+use std::io::{BufRead, BufReader};
+use std::path::Path;
+use std::sync::RwLock;
 use whatlang::{Lang, Script};
 
 use crate::stopwords::*;
@@ -108,6 +112,12 @@ lazy_static! {
     static ref STOPWORDS_HYE: HashSet<&'static str> = make(hye::STOPWORDS_HYE);
 }
 
+// Runtime-imported stopwords (these extend built-in stopwords)
+lazy_static! {
+    static ref STOPWORDS_IMPORTED: RwLock<HashMap<Lang, HashSet<String>>> =
+        RwLock::new(HashMap::new());
+}
+
 fn make<'a>(words: &[&'a str]) -> HashSet<&'a str> {
     words.iter().copied().collect()
 }
@@ -119,12 +129,60 @@ impl LexerStopWord {
             if Self::lang_stopwords(locale).contains(word) {
                 return true;
             }
+
+            // Word is an imported stopword (given locale)
+            if let Some(imported_stopwords) = STOPWORDS_IMPORTED.read().unwrap().get(&locale) {
+                if imported_stopwords.contains(word) {
+                    return true;
+                }
+            }
         }
 
         // Not a stopword, or may not be (default)
         false
     }
 
+    pub fn import(locale: Lang, path: &Path) -> Result<usize, ()> {
+        debug!(
+            "importing stopwords for locale: {} from: {:?}",
+            locale, path
+        );
+
+        let file = File::open(path).or(Err(()))?;
+
+        // Read all words first, so that a read failure does not leave a partial import behind
+        // Notice: words are normalized the same way the lexer normalizes words, and blank \
+        //   lines are skipped.
+        let mut words = Vec::new();
+
+        for line in BufReader::new(file).lines() {
+            let word = line.or(Err(()))?.trim().to_lowercase();
+
+            if !word.is_empty() {
+                words.push(word);
+            }
+        }
+
+        let mut imported_write = STOPWORDS_IMPORTED.write().unwrap();
+        let imported_stopwords = imported_write.entry(locale).or_default();
+
+        // Only count words that were not stopwords yet
+        let built_in_stopwords = Self::lang_stopwords(locale);
+
+        let count_imported = words
+            .into_iter()
+            .filter(|word| !built_in_stopwords.contains(word.as_str()))
+            .filter(|word| imported_stopwords.insert(word.to_owned()))
+            .count();
+
+        info!(
+            "imported {} stopwords for locale: {} from: {:?}",
+            count_imported, locale, path
+        );
+
+        Ok(count_imported)
+    }
+
     pub fn guess_lang(text: &str, script: Script) -> Option<Lang> {
         debug!(
             "guessing locale from stopwords for script: {} and text: {}",
@@ -343,6 +401,24 @@ mod tests {
         assert!(LexerStopWord::is("adéu", Some(Lang::Cat)));
     }
 
+    #[test]
+    fn it_imports_stopwords() {
+        let path = std::env::temp_dir().join("sonic-test-import-stopwords.txt");
+
+        std::fs::write(&path, "Foobar\n\net\n  bazqux  \nfoobar\n").unwrap();
+
+        assert!(!LexerStopWord::is("foobar", Some(Lang::Lat)));
+        assert_eq!(LexerStopWord::import(Lang::Lat, &path), Ok(2));
+        assert!(LexerStopWord::is("foobar", Some(Lang::Lat)));
+        assert!(LexerStopWord::is("bazqux", Some(Lang::Lat)));
+        assert!(!LexerStopWord::is("foobar", Some(Lang::Eng)));
+        assert_eq!(LexerStopWord::import(Lang::Lat, &path), Ok(0));
+
+        std::fs::remove_file(&path).unwrap();
+
+        assert_eq!(LexerStopWord::import(Lang::Lat, &path), Err(()));
+    }
+
     #[test]
     fn it_guesses_language() {
         assert_eq!(
@@ -0,0 +1,115 @@
+// Sonic
+//
+// Fast, lightweight and schema-less search backend
+// Copyright: 2019, Valerian Saliou <valerian@valeriansaliou.name>
+// License: Mozilla Public License v2.0 (MPL v2.0)
+
+use hashbrown::HashMap;
+use std::sync::RwLock;
+
+use super::normalize::LexerNormalize;
+use crate::config::reader::ConfigReader;
+use crate::query::cache::QueryCache;
+use crate::store::identifiers::{StoreTermHash, StoreTermHashed};
+use crate::APP_CONF;
+
+pub struct LexerSynonyms;
+
+// Synonyms map (it gets replaced as a whole upon reload)
+lazy_static! {
+    static ref SYNONYMS: RwLock<HashMap<String, Vec<String>>> =
+        RwLock::new(LexerSynonyms::make(APP_CONF.channel.search.synonyms.iter()));
+}
+
+impl LexerSynonyms {
+    pub fn expand(term: &str) -> Vec<String> {
+        Self::expand_from(&SYNONYMS.read().unwrap(), term)
+    }
+
+    pub fn reload() -> Result<usize, ()> {
+        // Re-read synonyms from configuration files (other configuration changes are ignored, \
+        //   as they only apply upon restart)
+        let config = ConfigReader::reload()?;
+        let synonyms_map = Self::make(config.channel.search.synonyms.iter());
+        let count = synonyms_map.len();
+
+        *SYNONYMS.write().unwrap() = synonyms_map;
+
+        // Cached search results may have been expanded with former synonyms
+        QueryCache::clear();
+
+        info!("reloaded synonyms map with: {} terms", count);
+
+        Ok(count)
+    }
+
+    pub fn expand_hashed(term: &str) -> Vec<(String, StoreTermHashed)> {
+        // Notice: synonyms are hashed the same way lexed terms are.
+        Self::expand(term)
+            .into_iter()
+            .map(|synonym| {
+                let synonym_hashed =
+                    StoreTermHash::from(&LexerNormalize::fold_diacritics(&synonym));
+
+                (synonym, synonym_hashed)
+            })
+            .collect()
+    }
+
+    fn make<'a, I>(synonyms: I) -> HashMap<String, Vec<String>>
+    where
+        I: Iterator<Item = (&'a String, &'a Vec<String>)>,
+    {
+        let mut synonyms_map: HashMap<String, Vec<String>> = HashMap::new();
+
+        // Normalize terms and their synonyms (ie. lower-case them, as lexed terms are)
+        for (term, term_synonyms) in synonyms {
+            let term = term.to_lowercase();
+            let term_synonyms_map = synonyms_map.entry(term.clone()).or_default();
+
+            for synonym in term_synonyms {
+                let synonym = synonym.to_lowercase();
+
+                if synonym != term && !term_synonyms_map.contains(&synonym) {
+                    term_synonyms_map.push(synonym);
+                }
+            }
+        }
+
+        synonyms_map
+    }
+
+    fn expand_from(synonyms: &HashMap<String, Vec<String>>, term: &str) -> Vec<String> {
+        synonyms.get(term).cloned().unwrap_or_default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_expands_synonyms() {
+        let synonyms = [(
+            "Car".to_string(),
+            vec![
+                "automobile".to_string(),
+                "CAR".to_string(),
+                "Automobile".to_string(),
+            ],
+        )];
+
+        let synonyms_map = LexerSynonyms::make(synonyms.iter().map(|(term, terms)| (term, terms)));
+
+        assert_eq!(
+            LexerSynonyms::expand_from(&synonyms_map, "car"),
+            vec!["automobile".to_string()]
+        );
+        assert!(LexerSynonyms::expand_from(&synonyms_map, "automobile").is_empty());
+    }
+
+    #[test]
+    fn it_reloads_synonyms() {
+        assert!(LexerSynonyms::reload().is_ok());
+    }
+}
@@ -6,17 +6,19 @@
 
 use hashbrown::HashSet;
 use std::time::Instant;
+use std::vec::IntoIter;
 use unicode_segmentation::{UnicodeSegmentation, UnicodeWords};
 use whatlang::{
     detect as lang_detect_all, detect_lang as lang_detect, detect_script as script_detect, Lang,
+    Script,
 };
 
-#[cfg(feature = "tokenizer-chinese")]
-use std::vec::IntoIter;
-
+use super::normalize::LexerNormalize;
+use super::stemmer::LexerStemmer;
 use super::stopwords::LexerStopWord;
 use crate::query::types::QueryGenericLang;
 use crate::store::identifiers::{StoreTermHash, StoreTermHashed};
+use crate::APP_CONF;
 
 pub struct TokenLexerBuilder;
 
@@ -24,17 +26,20 @@ pub struct TokenLexer<'a> {
     mode: TokenLexerMode,
     locale: Option<Lang>,
     words: TokenLexerWords<'a>,
+    stemmer: Option<LexerStemmer>,
     yields: HashSet<StoreTermHashed>,
 }
 
-#[derive(PartialEq)]
+#[derive(PartialEq, Clone)]
 pub enum TokenLexerMode {
     NormalizeAndCleanup(Option<Lang>),
+    NgramAndCleanup(usize),
     NormalizeOnly,
 }
 
 enum TokenLexerWords<'a> {
     UAX29(UnicodeWords<'a>),
+    Ngram(IntoIter<&'a str>),
 
     #[cfg(feature = "tokenizer-chinese")]
     JieBa(IntoIter<&'a str>),
@@ -47,6 +52,15 @@ const TEXT_LANG_TRUNCATE_OVER_CHARS: usize = 200;
 const TEXT_LANG_DETECT_PROCEED_OVER_CHARS: usize = 20;
 const TEXT_LANG_DETECT_NGRAM_UNDER_CHARS: usize = 60;
 
+const TEXT_NGRAM_SCRIPTS: &[Script] = &[
+    Script::Mandarin,
+    Script::Hiragana,
+    Script::Katakana,
+    Script::Thai,
+    Script::Khmer,
+    Script::Myanmar,
+];
+
 #[cfg(feature = "tokenizer-chinese")]
 lazy_static! {
     static ref TOKENIZER_JIEBA: jieba_rs::Jieba = jieba_rs::Jieba::new();
@@ -70,8 +84,21 @@ lazy_static! {
 
 impl TokenLexerBuilder {
     pub fn from(mode: TokenLexerMode, text: &str) -> Result<TokenLexer, ()> {
+        // Switch to n-gram mode? (if enabled, and if text script does not separate words \
+        //   reliably, eg. Japanese or Thai)
+        let mode = match mode {
+            TokenLexerMode::NormalizeAndCleanup(_)
+                if APP_CONF.channel.search.ngram_auto && Self::warrants_ngram(text) =>
+            {
+                debug!("switching to ngram mode for lexer text: {}", text);
+
+                TokenLexerMode::NgramAndCleanup(APP_CONF.channel.search.ngram_size)
+            }
+            _ => mode,
+        };
+
         let locale = match mode {
-            TokenLexerMode::NormalizeAndCleanup(None) => {
+            TokenLexerMode::NormalizeAndCleanup(None) | TokenLexerMode::NgramAndCleanup(_) => {
                 // Detect text language (current lexer mode asks for a cleanup)
                 debug!("detecting locale from lexer text: {}", text);
 
@@ -95,6 +122,10 @@ impl TokenLexerBuilder {
         Ok(TokenLexer::new(mode, text, locale))
     }
 
+    fn warrants_ngram(text: &str) -> bool {
+        matches!(script_detect(text), Some(script) if TEXT_NGRAM_SCRIPTS.contains(&script))
+    }
+
     fn detect_lang(text: &str) -> Option<Lang> {
         // Detect only if text is long-enough to allow the text locale detection system to \
         //   function properly
@@ -247,12 +278,17 @@ impl TokenLexerBuilder {
 
 impl<'a> TokenLexer<'a> {
     fn new(mode: TokenLexerMode, text: &'a str, locale: Option<Lang>) -> TokenLexer<'a> {
-        // Tokenize words (depending on the locale)
-        let words = match locale {
+        // Tokenize words (depending on the locale, or split into n-grams if asked to)
+        let words = match (&mode, locale) {
+            (TokenLexerMode::NgramAndCleanup(ngram_size), _) => {
+                TokenLexerWords::Ngram(Self::split_ngrams(text, *ngram_size).into_iter())
+            }
             #[cfg(feature = "tokenizer-chinese")]
-            Some(Lang::Cmn) => TokenLexerWords::JieBa(TOKENIZER_JIEBA.cut(text, false).into_iter()),
+            (_, Some(Lang::Cmn)) => {
+                TokenLexerWords::JieBa(TOKENIZER_JIEBA.cut(text, false).into_iter())
+            }
             #[cfg(feature = "tokenizer-japanese")]
-            Some(Lang::Jpn) => match TOKENIZER_LINDERA.tokenize(text) {
+            (_, Some(Lang::Jpn)) => match TOKENIZER_LINDERA.tokenize(text) {
                 Ok(tokens) => TokenLexerWords::Lindera(tokens.into_iter()),
                 Err(err) => {
                     warn!("unable to tokenize japanese, falling back: {}", err);
@@ -263,13 +299,88 @@ impl<'a> TokenLexer<'a> {
             _ => TokenLexerWords::UAX29(text.unicode_words()),
         };
 
+        // Stem words? (if enabled, and if there is a stemming algorithm for the locale)
+        let stemmer = if APP_CONF.channel.search.stemming {
+            locale.and_then(LexerStemmer::from)
+        } else {
+            None
+        };
+
         TokenLexer {
             mode,
             locale,
             words,
+            stemmer,
             yields: HashSet::new(),
         }
     }
+
+    pub fn sibling<'b>(&self, text: &'b str) -> TokenLexer<'b> {
+        self.sibling_as(self.mode.clone(), text)
+    }
+
+    pub fn sibling_words<'b>(&self, text: &'b str) -> TokenLexer<'b> {
+        // Notice: this sibling lexer does not split words into n-grams (an n-gram size of zero \
+        //   yields words only), which is used to match words at consecutive positions, as the \
+        //   n-grams of a pushed text get positions after all its words.
+        let mode = match self.mode {
+            TokenLexerMode::NgramAndCleanup(_) => TokenLexerMode::NgramAndCleanup(0),
+            ref mode => mode.clone(),
+        };
+
+        self.sibling_as(mode, text)
+    }
+
+    fn sibling_as<'b>(&self, mode: TokenLexerMode, text: &'b str) -> TokenLexer<'b> {
+        // Notice: the sibling lexer uses the resolved mode and locale of this lexer (and stems \
+        //   words only if this lexer does), so that it yields the same terms that this lexer \
+        //   yields for the same words, regardless of the surrounding text.
+        let mut sibling = TokenLexer::new(mode, text, self.locale);
+
+        sibling.stemmer = self
+            .stemmer
+            .as_ref()
+            .and(self.locale)
+            .and_then(LexerStemmer::from);
+
+        sibling
+    }
+
+    fn is_too_short(word: &str, min_length: usize) -> bool {
+        // Notice: only words made of cased letters or of digits are discarded when too short, \
+        //   as a single character may well be a whole word in other scripts (eg. Chinese).
+        word.chars().count() < min_length
+            && word
+                .chars()
+                .all(|character| character.is_lowercase() || character.is_numeric())
+    }
+
+    fn split_ngrams(text: &'a str, ngram_size: usize) -> Vec<&'a str> {
+        // Notice: n-grams come in addition to words, and are cut from all non-ASCII runs of \
+        //   alphanumeric characters that are at least as long as the n-gram size (ASCII runs \
+        //   are already split on word boundaries).
+        let mut words: Vec<&'a str> = text.unicode_words().collect();
+
+        if ngram_size > 0 {
+            for run in text.split(|character: char| !character.is_alphanumeric()) {
+                if !run.is_ascii() {
+                    let boundaries: Vec<usize> = run
+                        .char_indices()
+                        .map(|(index, _)| index)
+                        .chain(std::iter::once(run.len()))
+                        .collect();
+
+                    if boundaries.len() > ngram_size {
+                        for window in boundaries.windows(ngram_size + 1) {
+                            words.push(&run[window[0]..window[ngram_size]]);
+                        }
+                    }
+                }
+            }
+        }
+
+        words
+    }
 }
 
 impl TokenLexerMode {
@@ -283,6 +394,11 @@ impl TokenLexerMode {
                 // Normalize only (language purposefully set to 'none')
                 TokenLexerMode::NormalizeOnly
             }
+            Some(QueryGenericLang::Detect) => {
+                // Auto-detect language and cleanup (language purposefully set to 'auto'; this \
+                //   forces detection, even if a default language would otherwise apply)
+                TokenLexerMode::NormalizeAndCleanup(None)
+            }
             None => {
                 // Auto-detect language and cleanup (this is the default behavior)
                 TokenLexerMode::NormalizeAndCleanup(None)
@@ -298,7 +414,8 @@ impl<'a> Iterator for TokenLexer<'a> {
     //   - Text is split per-word in a script-aware way \
     //   - Words are normalized (ie. lower-case) \
     //   - Gibberish words are removed (ie. words that may just be junk) \
-    //   - Stop-words are removed
+    //   - Stop-words are removed \
+    //   - Words are stemmed (if enabled)
     fn next(&mut self) -> Option<Self::Item> {
         for word in &mut self.words {
             // Lower-case word
@@ -306,13 +423,34 @@ impl<'a> Iterator for TokenLexer<'a> {
             //   to a heap-indexed String; as lower-cased characters may change in bit size.
             let word = word.to_lowercase();
 
+            // Check if normalized word is too short? (this is applied in all modes)
+            if Self::is_too_short(&word, APP_CONF.channel.search.min_word_length) {
+                debug!(
+                    "lexer did not yield word: {} because: word is too short",
+                    word
+                );
+
+                continue;
+            }
+
             // Check if normalized word is a stop-word? (if should normalize and cleanup)
             if self.mode == TokenLexerMode::NormalizeOnly || !LexerStopWord::is(&word, self.locale)
             {
+                // Stem word? (this is done after checking for stop-words, as stop-words are not \
+                //   stemmed; the stemmed word is what gets indexed and searched for)
+                let word = if let Some(ref stemmer) = self.stemmer {
+                    stemmer.stem(&word)
+                } else {
+                    word
+                };
+
                 // Hash the term (this is used by all iterator consumers, as well as internally \
                 //   in the iterator to keep track of already-yielded words in a space-optimized \
                 //   manner, ie. by using 32-bit unsigned integer hashes)
-                let term_hash = StoreTermHash::from(&word);
+                // Notice: the term is hashed from its diacritic-folded form, so that eg. 'café' \
+                //   and 'cafe' match each other, while the yielded word is left unfolded, as it \
+                //   gets stored in the FST, where it is used to display suggested words.
+                let term_hash = StoreTermHash::from(&LexerNormalize::fold_diacritics(&word));
 
                 // Check if word was not already yielded? (we return unique words)
                 if !self.yields.contains(&term_hash) {
@@ -345,6 +483,7 @@ impl<'a> Iterator for TokenLexerWords<'a> {
     fn next(&mut self) -> Option<Self::Item> {
         match self {
             TokenLexerWords::UAX29(token) => token.next(),
+            TokenLexerWords::Ngram(token) => token.next(),
 
             #[cfg(feature = "tokenizer-chinese")]
             TokenLexerWords::JieBa(token) => token.next(),
@@ -386,6 +525,161 @@ mod tests {
         assert_eq!(token_cleaner.next(), None);
     }
 
+    #[test]
+    fn it_lexes_sibling_tokens_stemmed() {
+        let mut token_cleaner = TokenLexerBuilder::from(
+            TokenLexerMode::NormalizeAndCleanup(Some(Lang::Eng)),
+            "cats dogs",
+        )
+        .unwrap();
+
+        token_cleaner.stemmer = LexerStemmer::from(Lang::Eng);
+
+        let sibling_terms: Vec<(String, StoreTermHashed)> = token_cleaner.sibling("Dogs").collect();
+        let terms: Vec<(String, StoreTermHashed)> = token_cleaner.collect();
+
+        assert_eq!(terms[0].0, "cat");
+        assert_eq!(terms[1].0, "dog");
+        assert_eq!(sibling_terms, vec![terms[1].clone()]);
+    }
+
+    #[test]
+    fn it_lexes_sibling_phrases_stemmed() {
+        let mut token_pusher = TokenLexerBuilder::from(
+            TokenLexerMode::NormalizeAndCleanup(Some(Lang::Eng)),
+            "running trucks",
+        )
+        .unwrap();
+        let mut token_searcher = TokenLexerBuilder::from(
+            TokenLexerMode::NormalizeAndCleanup(Some(Lang::Eng)),
+            "fast running trucks",
+        )
+        .unwrap();
+
+        token_pusher.stemmer = LexerStemmer::from(Lang::Eng);
+        token_searcher.stemmer = LexerStemmer::from(Lang::Eng);
+
+        // Phrase words and excluded words match stored terms (which are stemmed)
+        let phrase_terms: Vec<StoreTermHashed> = token_searcher
+            .sibling("Running Trucks")
+            .map(|(_, term_hashed)| term_hashed)
+            .collect();
+        let excluded_terms: Vec<(String, StoreTermHashed)> =
+            token_searcher.sibling("trucks").collect();
+        let stored_terms: Vec<(String, StoreTermHashed)> = token_pusher.collect();
+
+        assert_eq!(stored_terms[0].0, "run");
+        assert_eq!(stored_terms[1].0, "truck");
+        assert_eq!(phrase_terms, vec![stored_terms[0].1, stored_terms[1].1]);
+        assert_eq!(excluded_terms, vec![stored_terms[1].clone()]);
+    }
+
+    #[test]
+    fn it_lexes_sibling_words_ngrams() {
+        let token_pusher =
+            TokenLexerBuilder::from(TokenLexerMode::NgramAndCleanup(2), "東京タワー").unwrap();
+        let token_searcher =
+            TokenLexerBuilder::from(TokenLexerMode::NgramAndCleanup(2), "東京").unwrap();
+
+        let sibling_terms: Vec<String> = token_searcher
+            .sibling("東京")
+            .map(|(term, _)| term)
+            .collect();
+        let sibling_words: Vec<(String, StoreTermHashed)> =
+            token_searcher.sibling_words("東京").collect();
+        let stored_terms: Vec<(String, StoreTermHashed)> = token_pusher.collect();
+
+        // Words come first in stored terms (thus, they get consecutive positions)
+        assert_eq!(sibling_terms, vec!["東", "京", "東京"]);
+        assert_eq!(sibling_words, stored_terms[0..2].to_vec());
+    }
+
+    #[test]
+    fn it_discards_short_tokens() {
+        assert!(TokenLexer::is_too_short("a", 2));
+        assert!(TokenLexer::is_too_short("5", 2));
+        assert!(!TokenLexer::is_too_short("am", 2));
+        assert!(!TokenLexer::is_too_short("a", 1));
+        assert!(!TokenLexer::is_too_short("東", 2));
+
+        let mut token_cleaner =
+            TokenLexerBuilder::from(TokenLexerMode::NormalizeOnly, "I am a developer").unwrap();
+
+        assert_eq!(
+            token_cleaner.next().map(|(term, _)| term),
+            Some("am".to_string())
+        );
+        assert_eq!(
+            token_cleaner.next().map(|(term, _)| term),
+            Some("developer".to_string())
+        );
+        assert_eq!(token_cleaner.next(), None);
+    }
+
+    #[test]
+    fn it_splits_token_ngrams() {
+        assert_eq!(
+            TokenLexer::split_ngrams("東京タワー", 2),
+            vec!["東", "京", "タワー", "東京", "京タ", "タワ", "ワー"]
+        );
+        assert_eq!(
+            TokenLexer::split_ngrams("東京 tower", 3),
+            vec!["東", "京", "tower"]
+        );
+        assert_eq!(TokenLexer::split_ngrams("東京", 0), vec!["東", "京"]);
+    }
+
+    #[test]
+    fn it_cleans_token_ngrams() {
+        let pushed_terms: Vec<StoreTermHashed> =
+            TokenLexerBuilder::from(TokenLexerMode::NgramAndCleanup(2), "東京タワー")
+                .unwrap()
+                .map(|(_, term_hashed)| term_hashed)
+                .collect();
+
+        let mut searched_terms =
+            TokenLexerBuilder::from(TokenLexerMode::NgramAndCleanup(2), "東京").unwrap();
+
+        assert_eq!(
+            searched_terms.next().map(|(term, _)| term),
+            Some("東".to_string())
+        );
+        assert_eq!(
+            searched_terms.next().map(|(term, _)| term),
+            Some("京".to_string())
+        );
+        assert_eq!(
+            searched_terms.next().map(|(term, _)| term),
+            Some("東京".to_string())
+        );
+        assert!(
+            TokenLexerBuilder::from(TokenLexerMode::NgramAndCleanup(2), "東京")
+                .unwrap()
+                .all(|(_, term_hashed)| pushed_terms.contains(&term_hashed))
+        );
+    }
+
+    #[test]
+    fn it_folds_token_diacritics() {
+        let pushed_terms: Vec<(String, StoreTermHashed)> = TokenLexerBuilder::from(
+            TokenLexerMode::NormalizeAndCleanup(Some(Lang::Fra)),
+            "Café au lait",
+        )
+        .unwrap()
+        .collect();
+        let searched_terms: Vec<(String, StoreTermHashed)> = TokenLexerBuilder::from(
+            TokenLexerMode::NormalizeAndCleanup(Some(Lang::Fra)),
+            "cafe lait",
+        )
+        .unwrap()
+        .collect();
+
+        assert_eq!(pushed_terms[0].0, "café");
+        assert_eq!(searched_terms[0].0, "cafe");
+        assert_eq!(pushed_terms[0].1, searched_terms[0].1);
+        assert_eq!(pushed_terms[1], searched_terms[1]);
+    }
+
     #[test]
     fn it_cleans_token_french() {
         let mut token_cleaner = TokenLexerBuilder::from(
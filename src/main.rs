@@ -31,6 +31,8 @@ use std::time::Duration;
 use clap::{App, Arg};
 use log::LevelFilter;
 
+#[cfg(feature = "transport-grpc")]
+use channel::grpc::ChannelGRPCBuilder;
 use channel::listen::{ChannelListen, ChannelListenBuilder};
 use channel::statistics::ensure_states as ensure_states_channel_statistics;
 use config::logger::ConfigLogger;
@@ -38,11 +40,13 @@ use config::options::Config;
 use config::reader::ConfigReader;
 use store::fst::StoreFSTPool;
 use store::kv::StoreKVPool;
+use tasker::executor_pool::TaskerExecutorPoolBuilder;
 use tasker::runtime::TaskerBuilder;
 use tasker::shutdown::ShutdownSignal;
+use tasker::webhook::TaskerWebhookBuilder;
 
 struct AppArgs {
-    config: String,
+    config: Vec<String>,
 }
 
 #[cfg(unix)]
@@ -54,7 +58,13 @@ pub static LINE_FEED: &str = "\r\n";
 
 pub static THREAD_NAME_CHANNEL_MASTER: &str = "sonic-channel-master";
 pub static THREAD_NAME_CHANNEL_CLIENT: &str = "sonic-channel-client";
+pub static THREAD_NAME_CHANNEL_WRITER: &str = "sonic-channel-writer";
+pub static THREAD_NAME_CHANNEL_GRPC: &str = "sonic-channel-grpc";
 pub static THREAD_NAME_TASKER: &str = "sonic-tasker";
+pub static THREAD_NAME_WEBHOOK: &str = "sonic-webhook";
+pub static THREAD_NAME_CONSOLIDATE: &str = "sonic-consolidate";
+pub static THREAD_NAME_EXECUTOR_POOL: &str = "sonic-executor-pool";
+pub static THREAD_NAME_EXECUTOR: &str = "sonic-executor";
 
 macro_rules! gen_spawn_managed {
     ($name:expr, $method:ident, $thread_name:ident, $managed_fn:ident) => {
@@ -96,7 +106,26 @@ gen_spawn_managed!(
     THREAD_NAME_CHANNEL_MASTER,
     ChannelListenBuilder
 );
+#[cfg(feature = "transport-grpc")]
+gen_spawn_managed!(
+    "channel-grpc",
+    spawn_channel_grpc,
+    THREAD_NAME_CHANNEL_GRPC,
+    ChannelGRPCBuilder
+);
 gen_spawn_managed!("tasker", spawn_tasker, THREAD_NAME_TASKER, TaskerBuilder);
+gen_spawn_managed!(
+    "executor-pool",
+    spawn_executor_pool,
+    THREAD_NAME_EXECUTOR_POOL,
+    TaskerExecutorPoolBuilder
+);
+gen_spawn_managed!(
+    "webhook",
+    spawn_webhook,
+    THREAD_NAME_WEBHOOK,
+    TaskerWebhookBuilder
+);
 
 fn make_app_args() -> AppArgs {
     let matches = App::new(clap::crate_name!())
@@ -107,15 +136,20 @@ fn make_app_args() -> AppArgs {
             Arg::new("config")
                 .short('c')
                 .long("config")
-                .help("Path to configuration file")
+                .help("Path to configuration file (can be repeated; later files override earlier ones)")
                 .default_value("./config.cfg")
-                .takes_value(true),
+                .takes_value(true)
+                .multiple_occurrences(true),
         )
         .get_matches();
 
     // Generate owned app arguments
     AppArgs {
-        config: String::from(matches.value_of("config").expect("invalid config value")),
+        config: matches
+            .values_of("config")
+            .expect("invalid config value")
+            .map(String::from)
+            .collect(),
     }
 }
 
@@ -130,6 +164,7 @@ fn ensure_states() {
 fn main() {
     let _logger = ConfigLogger::init(
         LevelFilter::from_str(&APP_CONF.server.log_level).expect("invalid log level"),
+        APP_CONF.server.log_format,
     );
 
     let shutdown_signal = ShutdownSignal::new();
@@ -142,9 +177,40 @@ fn main() {
     // Spawn tasker (background thread)
     thread::spawn(spawn_tasker);
 
+    // Spawn executor pool (background threads)
+    thread::spawn(spawn_executor_pool);
+
+    // Spawn webhook (background thread; only if any webhook is configured)
+    if APP_CONF.store.webhooks.on_push.is_some() {
+        thread::spawn(spawn_webhook);
+    }
+
+    // Generic channel password set along with mode-specific passwords? (deprecated)
+    if APP_CONF.channel.auth_password.is_some()
+        && (APP_CONF.channel.auth_password_search.is_some()
+            || APP_CONF.channel.auth_password_ingest.is_some()
+            || APP_CONF.channel.auth_password_control.is_some())
+    {
+        warn!(
+            "channel auth_password is deprecated when mode-specific passwords are set, it only \
+             applies to modes that have no mode-specific password"
+        );
+    }
+
     // Spawn channel (foreground thread)
     thread::spawn(spawn_channel);
 
+    // Spawn gRPC channel (foreground thread; only if configured)
+    if let Some(ref grpc) = APP_CONF.channel.grpc {
+        debug!("grpc channel is configured on: {}", grpc.listen_addr);
+
+        #[cfg(feature = "transport-grpc")]
+        thread::spawn(spawn_channel_grpc);
+
+        #[cfg(not(feature = "transport-grpc"))]
+        warn!("grpc channel is configured, but sonic was built without grpc support");
+    }
+
     info!("started");
 
     shutdown_signal.at_exit(move |signal| {
@@ -6,7 +6,7 @@
 
 use super::types::*;
 use crate::lexer::token::TokenLexer;
-use crate::store::item::StoreItem;
+use crate::store::item::{StoreItem, StoreItemPart};
 
 pub enum Query<'a> {
     Search(
@@ -15,23 +15,47 @@ pub enum Query<'a> {
         TokenLexer<'a>,
         QuerySearchLimit,
         QuerySearchOffset,
+        QuerySearchOptions,
+    ),
+    MultiBucketSearch(
+        StoreItem<'a>,
+        QuerySearchID<'a>,
+        &'a str,
+        QuerySearchLimit,
+        QuerySearchOffset,
+        QuerySearchOptions,
     ),
     Suggest(
         StoreItem<'a>,
         QuerySearchID<'a>,
         TokenLexer<'a>,
         QuerySearchLimit,
+        QuerySuggestOptions,
     ),
+    Spellcheck(StoreItem<'a>, QuerySearchID<'a>, TokenLexer<'a>),
     List(
         StoreItem<'a>,
         QuerySearchID<'a>,
         QuerySearchLimit,
         QuerySearchOffset,
     ),
-    Push(StoreItem<'a>, TokenLexer<'a>),
+    Vsearch(
+        StoreItem<'a>,
+        QuerySearchID<'a>,
+        QueryGenericVector,
+        QuerySearchLimit,
+    ),
+    Get(StoreItem<'a>),
+    Push(StoreItem<'a>, TokenLexer<'a>, QueryPushOptions),
     Pop(StoreItem<'a>, TokenLexer<'a>),
     Count(StoreItem<'a>),
     FlushC(StoreItem<'a>),
     FlushB(StoreItem<'a>),
     FlushO(StoreItem<'a>),
+    FlushT(StoreItem<'a>, TokenLexer<'a>),
+    FlushOrphans(StoreItem<'a>),
+    Learn(StoreItem<'a>, StoreItemPart<'a>, TokenLexer<'a>),
+    Inspect(StoreItem<'a>, QuerySearchLimit),
+    ListCollections,
+    ListBuckets(StoreItem<'a>),
 }
@@ -5,9 +5,13 @@
 // License: Mozilla Public License v2.0 (MPL v2.0)
 
 use super::actions::Query;
-use super::types::{QueryGenericLang, QuerySearchLimit, QuerySearchOffset};
+use super::types::{
+    QueryGenericVector, QueryPushOptions, QuerySearchLimit, QuerySearchOffset, QuerySearchOptions,
+    QuerySuggestOptions,
+};
 use crate::lexer::token::{TokenLexerBuilder, TokenLexerMode};
-use crate::store::item::StoreItemBuilder;
+use crate::store::item::{StoreItemBuilder, StoreItemPart};
+use crate::APP_CONF;
 
 pub struct QueryBuilder;
 
@@ -21,15 +25,24 @@ impl QueryBuilder {
         terms: &'a str,
         limit: QuerySearchLimit,
         offset: QuerySearchOffset,
-        lang: Option<QueryGenericLang>,
+        mut options: QuerySearchOptions,
     ) -> QueryBuilderResult<'a> {
+        // Search multiple buckets? (terms get lexed for each bucket separately)
+        if !options.buckets.is_empty() {
+            return StoreItemBuilder::from_depth_2(collection, bucket)
+                .map(|store| {
+                    Query::MultiBucketSearch(store, query_id, terms, limit, offset, options)
+                })
+                .or(Err(()));
+        }
+
         match (
             StoreItemBuilder::from_depth_2(collection, bucket),
-            TokenLexerBuilder::from(TokenLexerMode::from_query_lang(lang), terms),
+            TokenLexerBuilder::from(TokenLexerMode::from_query_lang(options.lang.take()), terms),
         ) {
-            (Ok(store), Ok(text_lexed)) => {
-                Ok(Query::Search(store, query_id, text_lexed, limit, offset))
-            }
+            (Ok(store), Ok(text_lexed)) => Ok(Query::Search(
+                store, query_id, text_lexed, limit, offset, options,
+            )),
             _ => Err(()),
         }
     }
@@ -40,12 +53,30 @@ impl QueryBuilder {
         bucket: &'a str,
         terms: &'a str,
         limit: QuerySearchLimit,
+        options: QuerySuggestOptions,
     ) -> QueryBuilderResult<'a> {
         match (
             StoreItemBuilder::from_depth_2(collection, bucket),
             TokenLexerBuilder::from(TokenLexerMode::NormalizeOnly, terms),
         ) {
-            (Ok(store), Ok(text_lexed)) => Ok(Query::Suggest(store, query_id, text_lexed, limit)),
+            (Ok(store), Ok(text_lexed)) => {
+                Ok(Query::Suggest(store, query_id, text_lexed, limit, options))
+            }
+            _ => Err(()),
+        }
+    }
+
+    pub fn spellcheck<'a>(
+        query_id: &'a str,
+        collection: &'a str,
+        bucket: &'a str,
+        terms: &'a str,
+    ) -> QueryBuilderResult<'a> {
+        match (
+            StoreItemBuilder::from_depth_2(collection, bucket),
+            TokenLexerBuilder::from(TokenLexerMode::NormalizeOnly, terms),
+        ) {
+            (Ok(store), Ok(text_lexed)) => Ok(Query::Spellcheck(store, query_id, text_lexed)),
             _ => Err(()),
         }
     }
@@ -63,18 +94,47 @@ impl QueryBuilder {
         }
     }
 
+    pub fn vsearch<'a>(
+        query_id: &'a str,
+        collection: &'a str,
+        bucket: &'a str,
+        vector: QueryGenericVector,
+        limit: QuerySearchLimit,
+    ) -> QueryBuilderResult<'a> {
+        match StoreItemBuilder::from_depth_2(collection, bucket) {
+            Ok(store) => Ok(Query::Vsearch(store, query_id, vector, limit)),
+            _ => Err(()),
+        }
+    }
+
+    pub fn get<'a>(
+        collection: &'a str,
+        bucket: &'a str,
+        object: &'a str,
+    ) -> QueryBuilderResult<'a> {
+        match StoreItemBuilder::from_depth_3(collection, bucket, object) {
+            Ok(store) => Ok(Query::Get(store)),
+            _ => Err(()),
+        }
+    }
+
     pub fn push<'a>(
         collection: &'a str,
         bucket: &'a str,
         object: &'a str,
         text: &'a str,
-        lang: Option<QueryGenericLang>,
+        mut options: QueryPushOptions,
     ) -> QueryBuilderResult<'a> {
+        // Keep original text? (it gets stored along with object terms)
+        if APP_CONF.store.kv.store_original_text {
+            options.text = Some(text.to_string());
+        }
+
         match (
             StoreItemBuilder::from_depth_3(collection, bucket, object),
-            TokenLexerBuilder::from(TokenLexerMode::from_query_lang(lang), text),
+            TokenLexerBuilder::from(TokenLexerMode::from_query_lang(options.lang.take()), text),
         ) {
-            (Ok(store), Ok(text_lexed)) => Ok(Query::Push(store, text_lexed)),
+            (Ok(store), Ok(text_lexed)) => Ok(Query::Push(store, text_lexed, options)),
             _ => Err(()),
         }
     }
@@ -137,6 +197,56 @@ impl QueryBuilder {
             _ => Err(()),
         }
     }
+
+    pub fn flusht<'a>(collection: &'a str, text: &'a str) -> QueryBuilderResult<'a> {
+        match (
+            StoreItemBuilder::from_depth_1(collection),
+            TokenLexerBuilder::from(TokenLexerMode::NormalizeOnly, text),
+        ) {
+            (Ok(store), Ok(text_lexed)) => Ok(Query::FlushT(store, text_lexed)),
+            _ => Err(()),
+        }
+    }
+
+    pub fn flush_orphans<'a>(collection: &'a str, bucket: &'a str) -> QueryBuilderResult<'a> {
+        match StoreItemBuilder::from_depth_2(collection, bucket) {
+            Ok(store) => Ok(Query::FlushOrphans(store)),
+            _ => Err(()),
+        }
+    }
+
+    pub fn learn<'a>(collection: &'a str, user: &'a str, text: &'a str) -> QueryBuilderResult<'a> {
+        match (
+            StoreItemBuilder::from_depth_1(collection),
+            StoreItemPart::from_str(user),
+            TokenLexerBuilder::from(TokenLexerMode::from_query_lang(None), text),
+        ) {
+            (Ok(store), Ok(user), Ok(text_lexed)) => Ok(Query::Learn(store, user, text_lexed)),
+            _ => Err(()),
+        }
+    }
+
+    pub fn inspect<'a>(
+        collection: &'a str,
+        bucket: &'a str,
+        limit: QuerySearchLimit,
+    ) -> QueryBuilderResult<'a> {
+        match StoreItemBuilder::from_depth_2(collection, bucket) {
+            Ok(store) => Ok(Query::Inspect(store, limit)),
+            _ => Err(()),
+        }
+    }
+
+    pub fn listcollections<'a>() -> QueryBuilderResult<'a> {
+        Ok(Query::ListCollections)
+    }
+
+    pub fn listbuckets<'a>(collection: &'a str) -> QueryBuilderResult<'a> {
+        match StoreItemBuilder::from_depth_1(collection) {
+            Ok(store) => Ok(Query::ListBuckets(store)),
+            _ => Err(()),
+        }
+    }
 }
 
 #[cfg(test)]
@@ -145,17 +255,90 @@ mod tests {
 
     #[test]
     fn it_builds_search_query() {
-        assert!(
-            QueryBuilder::search("id1", "c:test:1", "b:test:1", "Michael Dake", 10, 20, None)
-                .is_ok()
-        );
-        assert!(QueryBuilder::search("id2", "c:test:1", "", "Michael Dake", 1, 0, None).is_err());
+        assert!(QueryBuilder::search(
+            "id1",
+            "c:test:1",
+            "b:test:1",
+            "Michael Dake",
+            10,
+            20,
+            QuerySearchOptions::default()
+        )
+        .is_ok());
+        assert!(QueryBuilder::search(
+            "id2",
+            "c:test:1",
+            "",
+            "Michael Dake",
+            1,
+            0,
+            QuerySearchOptions::default()
+        )
+        .is_err());
     }
 
     #[test]
     fn it_builds_suggest_query() {
-        assert!(QueryBuilder::suggest("id1", "c:test:2", "b:test:2", "Micha", 5).is_ok());
-        assert!(QueryBuilder::suggest("id2", "c:test:2", "", "Micha", 1).is_err());
+        assert!(QueryBuilder::suggest(
+            "id1",
+            "c:test:2",
+            "b:test:2",
+            "Micha",
+            5,
+            QuerySuggestOptions::default()
+        )
+        .is_ok());
+        assert!(QueryBuilder::suggest(
+            "id1",
+            "c:test:2",
+            "b:test:2",
+            "Micha",
+            5,
+            QuerySuggestOptions {
+                context: vec!["dake".to_string()],
+                ..Default::default()
+            }
+        )
+        .is_ok());
+        assert!(QueryBuilder::suggest(
+            "id1",
+            "c:test:2",
+            "b:test:2",
+            "Micha",
+            5,
+            QuerySuggestOptions {
+                expand: true,
+                ..Default::default()
+            }
+        )
+        .is_ok());
+        assert!(QueryBuilder::suggest(
+            "id1",
+            "c:test:2",
+            "b:test:2",
+            "Micha",
+            5,
+            QuerySuggestOptions {
+                min_freq: Some(5),
+                ..Default::default()
+            }
+        )
+        .is_ok());
+        assert!(QueryBuilder::suggest(
+            "id2",
+            "c:test:2",
+            "",
+            "Micha",
+            1,
+            QuerySuggestOptions::default()
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn it_builds_spellcheck_query() {
+        assert!(QueryBuilder::spellcheck("id1", "c:test:10", "b:test:10", "helo").is_ok());
+        assert!(QueryBuilder::spellcheck("id2", "c:test:10", "", "helo").is_err());
     }
 
     #[test]
@@ -164,6 +347,18 @@ mod tests {
         assert!(QueryBuilder::list("id2", "c:test:2", "", 10, 0).is_err());
     }
 
+    #[test]
+    fn it_builds_vsearch_query() {
+        assert!(QueryBuilder::vsearch("id1", "c:test:13", "b:test:13", vec![0.6, 0.8], 10).is_ok());
+        assert!(QueryBuilder::vsearch("id2", "c:test:13", "", vec![0.6, 0.8], 10).is_err());
+    }
+
+    #[test]
+    fn it_builds_get_query() {
+        assert!(QueryBuilder::get("c:test:15", "b:test:15", "o:test:15").is_ok());
+        assert!(QueryBuilder::get("c:test:15", "b:test:15", "").is_err());
+    }
+
     #[test]
     fn it_builds_push_query() {
         assert!(QueryBuilder::push(
@@ -171,13 +366,29 @@ mod tests {
             "b:test:3",
             "o:test:3",
             "My name is Michael Dake. I'm ordering in the US.",
-            None
+            QueryPushOptions::default()
+        )
+        .is_ok());
+        assert!(QueryBuilder::push(
+            "c:test:3",
+            "b:test:3",
+            "o:test:3",
+            "Some restaurant in Paris.",
+            QueryPushOptions {
+                geo: Some((48.85, 2.35)),
+                deduplicate: true,
+                ..Default::default()
+            }
         )
         .is_ok());
-        assert!(
-            QueryBuilder::push("c:test:3", "", "o:test:3", "My name is Michael Dake.", None)
-                .is_err()
-        );
+        assert!(QueryBuilder::push(
+            "c:test:3",
+            "",
+            "o:test:3",
+            "My name is Michael Dake.",
+            QueryPushOptions::default()
+        )
+        .is_err());
     }
 
     #[test]
@@ -211,4 +422,34 @@ mod tests {
         assert!(QueryBuilder::flusho("c:test:8", "b:test:8", "o:test:8").is_ok());
         assert!(QueryBuilder::flusho("c:test:8", "b:test:8", "").is_err());
     }
+
+    #[test]
+    fn it_builds_flusht_query() {
+        assert!(QueryBuilder::flusht("c:test:11", "illegal").is_ok());
+        assert!(QueryBuilder::flusht("", "illegal").is_err());
+    }
+
+    #[test]
+    fn it_builds_flush_orphans_query() {
+        assert!(QueryBuilder::flush_orphans("c:test:9", "b:test:9").is_ok());
+        assert!(QueryBuilder::flush_orphans("c:test:9", "").is_err());
+    }
+
+    #[test]
+    fn it_builds_learn_query() {
+        assert!(QueryBuilder::learn("c:test:12", "u:test:12", "electric cars").is_ok());
+        assert!(QueryBuilder::learn("c:test:12", "", "electric cars").is_err());
+    }
+
+    #[test]
+    fn it_builds_inspect_query() {
+        assert!(QueryBuilder::inspect("c:test:14", "b:test:14", 10).is_ok());
+        assert!(QueryBuilder::inspect("c:test:14", "", 10).is_err());
+    }
+
+    #[test]
+    fn it_builds_listbuckets_query() {
+        assert!(QueryBuilder::listbuckets("c:test:15").is_ok());
+        assert!(QueryBuilder::listbuckets("").is_err());
+    }
 }
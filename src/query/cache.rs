@@ -0,0 +1,290 @@
+// Sonic
+//
+// Fast, lightweight and schema-less search backend
+// Copyright: 2019, Valerian Saliou <valerian@valeriansaliou.name>
+// License: Mozilla Public License v2.0 (MPL v2.0)
+
+use hashbrown::HashMap;
+use std::hash::{Hash, Hasher};
+use std::sync::RwLock;
+use std::time::{Duration, SystemTime};
+use twox_hash::XxHash64;
+
+use super::types::{QuerySearchLimit, QuerySearchOffset, QuerySearchOptions};
+use crate::store::item::StoreItemPart;
+use crate::APP_CONF;
+
+pub struct QueryCache;
+
+pub type QueryCacheKey = u64;
+pub type QueryCacheResults = Option<Vec<String>>;
+
+struct QueryCacheEntry {
+    target: (String, String),
+    results: QueryCacheResults,
+    inserted_at: SystemTime,
+}
+
+lazy_static! {
+    static ref QUERY_CACHE: RwLock<HashMap<QueryCacheKey, QueryCacheEntry>> =
+        RwLock::new(HashMap::new());
+}
+
+impl QueryCache {
+    pub fn make_key(
+        collection: StoreItemPart,
+        bucket: StoreItemPart,
+        terms: &[String],
+        limit: QuerySearchLimit,
+        offset: QuerySearchOffset,
+        options: &QuerySearchOptions,
+    ) -> QueryCacheKey {
+        let mut hasher = XxHash64::with_seed(0);
+
+        collection.as_str().hash(&mut hasher);
+        bucket.as_str().hash(&mut hasher);
+        terms.hash(&mut hasher);
+        limit.hash(&mut hasher);
+        offset.hash(&mut hasher);
+
+        // Notice: all options that alter results must be hashed (floats are hashed from their \
+        //   bit representation, as they do not implement 'Hash'). The language is accounted for \
+        //   in lexed terms, and debugged searches are never cached.
+        for (word, factor) in &options.boosts {
+            word.hash(&mut hasher);
+            factor.to_bits().hash(&mut hasher);
+        }
+
+        for (term, weight) in &options.term_weights {
+            term.hash(&mut hasher);
+            weight.to_bits().hash(&mut hasher);
+        }
+
+        if let Some(((latitude, longitude), distance)) = options.within_distance {
+            latitude.to_bits().hash(&mut hasher);
+            longitude.to_bits().hash(&mut hasher);
+            distance.to_bits().hash(&mut hasher);
+        }
+
+        options.penalty_empty.map(f32::to_bits).hash(&mut hasher);
+        options.abstract_chars.hash(&mut hasher);
+        options.highlight_style.hash(&mut hasher);
+        options.highlight.hash(&mut hasher);
+        options.filter_tags.hash(&mut hasher);
+        options.must_not.hash(&mut hasher);
+        options.personalize.hash(&mut hasher);
+        options.recent.hash(&mut hasher);
+        options.decay.hash(&mut hasher);
+        options.boost_recency.hash(&mut hasher);
+        options.segment.hash(&mut hasher);
+        options.shard.hash(&mut hasher);
+        options.span.hash(&mut hasher);
+        options.random.hash(&mut hasher);
+        options.sort.hash(&mut hasher);
+        options.cursor.hash(&mut hasher);
+        options.or_terms.hash(&mut hasher);
+        options.phrases.hash(&mut hasher);
+        options.buckets.hash(&mut hasher);
+
+        for (bucket, weight) in &options.bucket_weights {
+            bucket.hash(&mut hasher);
+            weight.to_bits().hash(&mut hasher);
+        }
+
+        options.timeout_ms.hash(&mut hasher);
+
+        hasher.finish()
+    }
+
+    pub fn get(key: QueryCacheKey) -> Option<QueryCacheResults> {
+        let ttl = Duration::from_secs(APP_CONF.channel.search.query_cache.ttl_secs);
+
+        QUERY_CACHE
+            .read()
+            .unwrap()
+            .get(&key)
+            .filter(|entry| Self::is_alive(entry, ttl))
+            .map(|entry| entry.results.clone())
+    }
+
+    pub fn set(
+        key: QueryCacheKey,
+        collection: StoreItemPart,
+        bucket: StoreItemPart,
+        results: QueryCacheResults,
+    ) {
+        let (max_entries, ttl) = (
+            APP_CONF.channel.search.query_cache.max_entries,
+            Duration::from_secs(APP_CONF.channel.search.query_cache.ttl_secs),
+        );
+
+        let mut cache_write = QUERY_CACHE.write().unwrap();
+
+        // Cache is full? Evict expired entries, then the oldest entry if still full
+        if cache_write.len() >= max_entries {
+            cache_write.retain(|_, entry| Self::is_alive(entry, ttl));
+
+            if cache_write.len() >= max_entries {
+                let oldest_key = cache_write
+                    .iter()
+                    .min_by_key(|(_, entry)| entry.inserted_at)
+                    .map(|(key, _)| *key);
+
+                if let Some(oldest_key) = oldest_key {
+                    cache_write.remove(&oldest_key);
+                }
+            }
+        }
+
+        if max_entries > 0 {
+            cache_write.insert(
+                key,
+                QueryCacheEntry {
+                    target: (collection.as_str().to_owned(), bucket.as_str().to_owned()),
+                    results,
+                    inserted_at: SystemTime::now(),
+                },
+            );
+        }
+    }
+
+    pub fn invalidate(collection: StoreItemPart, bucket: Option<StoreItemPart>) {
+        // Exit trap: cache is disabled? (nothing to invalidate)
+        if !APP_CONF.channel.search.query_cache.enabled {
+            return;
+        }
+
+        let (collection_str, bucket_str) =
+            (collection.as_str(), bucket.map(|bucket| bucket.as_str()));
+
+        debug!(
+            "invalidating query cache for collection: {} and bucket: {:?}",
+            collection_str, bucket_str
+        );
+
+        // Notice: if no bucket is given, all buckets in collection get invalidated.
+        QUERY_CACHE.write().unwrap().retain(|_, entry| {
+            entry.target.0 != collection_str
+                || matches!(bucket_str, Some(bucket_str) if entry.target.1 != bucket_str)
+        });
+    }
+
+    pub fn clear() {
+        // Exit trap: cache is disabled? (nothing to clear)
+        if !APP_CONF.channel.search.query_cache.enabled {
+            return;
+        }
+
+        debug!("clearing query cache for all collections");
+
+        QUERY_CACHE.write().unwrap().clear();
+    }
+
+    fn is_alive(entry: &QueryCacheEntry, ttl: Duration) -> bool {
+        // Notice: be lenient with system clock going back to a past duration, by considering \
+        //   the entry as expired.
+        entry
+            .inserted_at
+            .elapsed()
+            .map(|elapsed| elapsed < ttl)
+            .unwrap_or(false)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_makes_key() {
+        let (collection, bucket) = (
+            StoreItemPart::from_str("c:test:cache").unwrap(),
+            StoreItemPart::from_str("b:test:cache").unwrap(),
+        );
+        let terms = vec!["hello".to_string(), "world".to_string()];
+
+        let key = QueryCache::make_key(
+            collection,
+            bucket,
+            &terms,
+            10,
+            0,
+            &QuerySearchOptions::default(),
+        );
+
+        assert_eq!(
+            key,
+            QueryCache::make_key(
+                collection,
+                bucket,
+                &terms,
+                10,
+                0,
+                &QuerySearchOptions::default()
+            )
+        );
+        assert_ne!(
+            key,
+            QueryCache::make_key(
+                collection,
+                bucket,
+                &terms,
+                10,
+                10,
+                &QuerySearchOptions::default()
+            )
+        );
+        assert_ne!(
+            key,
+            QueryCache::make_key(
+                collection,
+                bucket,
+                &terms,
+                10,
+                0,
+                &QuerySearchOptions {
+                    filter_tags: vec!["category:books".to_string()],
+                    ..Default::default()
+                }
+            )
+        );
+    }
+
+    #[test]
+    fn it_makes_key_with_bucket_weights() {
+        let (collection, bucket) = (
+            StoreItemPart::from_str("c:test:cache").unwrap(),
+            StoreItemPart::from_str("b:test:cache").unwrap(),
+        );
+        let terms = vec!["hello".to_string()];
+
+        let make_options = |weight: f32| QuerySearchOptions {
+            buckets: vec!["title".to_string()],
+            bucket_weights: vec![("title".to_string(), weight)],
+            ..Default::default()
+        };
+
+        assert_eq!(
+            QueryCache::make_key(collection, bucket, &terms, 10, 0, &make_options(2.0)),
+            QueryCache::make_key(collection, bucket, &terms, 10, 0, &make_options(2.0))
+        );
+        assert_ne!(
+            QueryCache::make_key(collection, bucket, &terms, 10, 0, &make_options(2.0)),
+            QueryCache::make_key(collection, bucket, &terms, 10, 0, &make_options(0.5))
+        );
+    }
+
+    #[test]
+    fn it_caches_results() {
+        let (collection, bucket) = (
+            StoreItemPart::from_str("c:test:cache").unwrap(),
+            StoreItemPart::from_str("b:test:cache").unwrap(),
+        );
+
+        assert_eq!(QueryCache::get(1), None);
+
+        QueryCache::set(1, collection, bucket, Some(vec!["object:1".to_string()]));
+
+        assert_eq!(QueryCache::get(1), Some(Some(vec!["object:1".to_string()])));
+    }
+}
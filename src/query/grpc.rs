@@ -0,0 +1,246 @@
+// Sonic
+//
+// Fast, lightweight and schema-less search backend
+// Copyright: 2019, Valerian Saliou <valerian@valeriansaliou.name>
+// License: Mozilla Public License v2.0 (MPL v2.0)
+
+use std::convert::TryFrom;
+
+use super::actions::Query;
+use super::builder::QueryBuilder;
+use super::guard::{QueryGuard, GUARD_REJECT_OID_TOO_LONG, GUARD_REJECT_TEXT_TOO_LONG};
+use super::types::{
+    QueryGenericLang, QueryPushOptions, QuerySearchLimit, QuerySearchOptions, QuerySuggestOptions,
+};
+use crate::channel::grpc::proto::{
+    CountRequest, FlushBucketRequest, FlushCollectionRequest, FlushObjectRequest, PopRequest,
+    PushRequest, QueryRequest, SuggestRequest,
+};
+use crate::APP_CONF;
+
+pub struct QueryGRPC;
+
+pub type QueryGRPCResult<'a> = Result<Query<'a>, &'static str>;
+
+pub const GRPC_REJECT_INVALID_REQUEST: &str = "invalid request";
+
+impl QueryGRPC {
+    pub fn query<'a>(query_id: &'a str, request: &'a QueryRequest) -> QueryGRPCResult<'a> {
+        let limit = Self::parse_limit(
+            request.limit,
+            APP_CONF.channel.search.query_limit_default,
+            APP_CONF.channel.search.query_limit_maximum,
+        )?;
+
+        Self::guard_text(&request.terms, QueryGuard::max_text_length())?;
+
+        QueryBuilder::search(
+            query_id,
+            &request.collection,
+            &request.bucket,
+            &request.terms,
+            limit,
+            request.offset.unwrap_or(0),
+            QuerySearchOptions {
+                lang: Self::parse_lang(request.lang.as_deref())?,
+                ..Default::default()
+            },
+        )
+        .or(Err(GRPC_REJECT_INVALID_REQUEST))
+    }
+
+    pub fn suggest<'a>(query_id: &'a str, request: &'a SuggestRequest) -> QueryGRPCResult<'a> {
+        let limit = Self::parse_limit(
+            request.limit,
+            APP_CONF.channel.search.suggest_limit_default,
+            APP_CONF.channel.search.suggest_limit_maximum,
+        )?;
+
+        Self::guard_text(&request.word, QueryGuard::max_text_length())?;
+
+        QueryBuilder::suggest(
+            query_id,
+            &request.collection,
+            &request.bucket,
+            &request.word,
+            limit,
+            QuerySuggestOptions::default(),
+        )
+        .or(Err(GRPC_REJECT_INVALID_REQUEST))
+    }
+
+    pub fn push<'a>(_query_id: &'a str, request: &'a PushRequest) -> QueryGRPCResult<'a> {
+        Self::guard_oid(&request.object)?;
+        Self::guard_text(&request.text, QueryGuard::max_push_text_length())?;
+
+        QueryBuilder::push(
+            &request.collection,
+            &request.bucket,
+            &request.object,
+            &request.text,
+            QueryPushOptions {
+                lang: Self::parse_lang(request.lang.as_deref())?,
+                ..Default::default()
+            },
+        )
+        .or(Err(GRPC_REJECT_INVALID_REQUEST))
+    }
+
+    pub fn pop<'a>(_query_id: &'a str, request: &'a PopRequest) -> QueryGRPCResult<'a> {
+        Self::guard_oid(&request.object)?;
+        Self::guard_text(&request.text, QueryGuard::max_text_length())?;
+
+        QueryBuilder::pop(
+            &request.collection,
+            &request.bucket,
+            &request.object,
+            &request.text,
+        )
+        .or(Err(GRPC_REJECT_INVALID_REQUEST))
+    }
+
+    pub fn count<'a>(_query_id: &'a str, request: &'a CountRequest) -> QueryGRPCResult<'a> {
+        if let Some(ref object) = request.object {
+            Self::guard_oid(object)?;
+        }
+
+        QueryBuilder::count(
+            &request.collection,
+            request.bucket.as_deref(),
+            request.object.as_deref(),
+        )
+        .or(Err(GRPC_REJECT_INVALID_REQUEST))
+    }
+
+    pub fn flush_collection<'a>(
+        _query_id: &'a str,
+        request: &'a FlushCollectionRequest,
+    ) -> QueryGRPCResult<'a> {
+        QueryBuilder::flushc(&request.collection).or(Err(GRPC_REJECT_INVALID_REQUEST))
+    }
+
+    pub fn flush_bucket<'a>(
+        _query_id: &'a str,
+        request: &'a FlushBucketRequest,
+    ) -> QueryGRPCResult<'a> {
+        QueryBuilder::flushb(&request.collection, &request.bucket)
+            .or(Err(GRPC_REJECT_INVALID_REQUEST))
+    }
+
+    pub fn flush_object<'a>(
+        _query_id: &'a str,
+        request: &'a FlushObjectRequest,
+    ) -> QueryGRPCResult<'a> {
+        Self::guard_oid(&request.object)?;
+
+        QueryBuilder::flusho(&request.collection, &request.bucket, &request.object)
+            .or(Err(GRPC_REJECT_INVALID_REQUEST))
+    }
+
+    fn parse_limit(
+        limit: Option<u32>,
+        limit_default: QuerySearchLimit,
+        limit_maximum: QuerySearchLimit,
+    ) -> Result<QuerySearchLimit, &'static str> {
+        // Enforce the same limit bounds as Sonic Channel
+        match limit.map(QuerySearchLimit::try_from) {
+            None => Ok(limit_default),
+            Some(Ok(limit)) if limit >= 1 && limit <= limit_maximum => Ok(limit),
+            _ => Err(GRPC_REJECT_INVALID_REQUEST),
+        }
+    }
+
+    fn parse_lang(lang: Option<&str>) -> Result<Option<QueryGenericLang>, &'static str> {
+        match lang {
+            Some(lang) => QueryGenericLang::from_value(lang)
+                .map(Some)
+                .ok_or(GRPC_REJECT_INVALID_REQUEST),
+            None => Ok(None),
+        }
+    }
+
+    fn guard_oid(object: &str) -> Result<(), &'static str> {
+        // Notice: the same guards as Sonic Channel apply (they do not depend on the transport)
+        if QueryGuard::oid_over_limit(object) {
+            Err(GUARD_REJECT_OID_TOO_LONG)
+        } else {
+            Ok(())
+        }
+    }
+
+    fn guard_text(text: &str, max_text_length: Option<usize>) -> Result<(), &'static str> {
+        if QueryGuard::text_over_limit(text, max_text_length) {
+            Err(GUARD_REJECT_TEXT_TOO_LONG)
+        } else {
+            Ok(())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_parses_limit() {
+        assert_eq!(QueryGRPC::parse_limit(None, 10, 100), Ok(10));
+        assert_eq!(QueryGRPC::parse_limit(Some(100), 10, 100), Ok(100));
+        assert_eq!(
+            QueryGRPC::parse_limit(Some(0), 10, 100),
+            Err(GRPC_REJECT_INVALID_REQUEST)
+        );
+        assert_eq!(
+            QueryGRPC::parse_limit(Some(101), 10, 100),
+            Err(GRPC_REJECT_INVALID_REQUEST)
+        );
+        assert_eq!(
+            QueryGRPC::parse_limit(Some(70000), 10, 100),
+            Err(GRPC_REJECT_INVALID_REQUEST)
+        );
+    }
+
+    #[test]
+    fn it_builds_query_from_request() {
+        assert!(QueryGRPC::query(
+            "id1",
+            &QueryRequest {
+                collection: "c:test:1".to_string(),
+                bucket: "b:test:1".to_string(),
+                terms: "Michael Dake".to_string(),
+                limit: Some(10),
+                offset: None,
+                lang: Some("eng".to_string()),
+            }
+        )
+        .is_ok());
+        assert!(QueryGRPC::query(
+            "id2",
+            &QueryRequest {
+                collection: "c:test:1".to_string(),
+                bucket: "b:test:1".to_string(),
+                terms: "Michael Dake".to_string(),
+                limit: None,
+                offset: None,
+                lang: Some("xxx".to_string()),
+            }
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn it_guards_push_request() {
+        let make_request = |object: String| PushRequest {
+            collection: "c:test:2".to_string(),
+            bucket: "b:test:2".to_string(),
+            object,
+            text: "Michael Dake".to_string(),
+            lang: None,
+        };
+
+        assert!(QueryGRPC::push("id1", &make_request("o:test:2".to_string())).is_ok());
+        assert!(matches!(
+            QueryGRPC::push("id2", &make_request("a".repeat(257))),
+            Err(GUARD_REJECT_OID_TOO_LONG)
+        ));
+    }
+}
@@ -0,0 +1,83 @@
+// Sonic
+//
+// Fast, lightweight and schema-less search backend
+// Copyright: 2019, Valerian Saliou <valerian@valeriansaliou.name>
+// License: Mozilla Public License v2.0 (MPL v2.0)
+
+use crate::APP_CONF;
+
+pub struct QueryGuard;
+
+pub const GUARD_REJECT_OID_TOO_LONG: &str = "oid_too_long";
+pub const GUARD_REJECT_TEXT_TOO_LONG: &str = "text_too_long";
+
+impl QueryGuard {
+    pub fn oid_over_limit(object: &str) -> bool {
+        let (object_length, max_oid_length) = (
+            object.chars().count(),
+            APP_CONF.channel.ingest.max_oid_length,
+        );
+
+        if object_length > max_oid_length {
+            info!(
+                "object identifier is over length limit: {} over limit: {}",
+                object_length, max_oid_length
+            );
+
+            true
+        } else {
+            false
+        }
+    }
+
+    pub fn text_over_limit(text: &str, max_text_length: Option<usize>) -> bool {
+        if let Some(max_text_length) = max_text_length {
+            let text_length = text.chars().count();
+
+            if text_length > max_text_length {
+                info!(
+                    "text is over length limit: {} over limit: {}",
+                    text_length, max_text_length
+                );
+
+                return true;
+            }
+        }
+
+        false
+    }
+
+    pub fn max_text_length() -> Option<usize> {
+        APP_CONF.channel.max_text_length
+    }
+
+    pub fn max_push_text_length() -> Option<usize> {
+        // Notice: the ingest push text length limit overrides the generic limit, if set.
+        APP_CONF
+            .channel
+            .ingest
+            .max_push_text_length
+            .or(APP_CONF.channel.max_text_length)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_limits_oid_length() {
+        assert!(!QueryGuard::oid_over_limit(&"a".repeat(256)));
+        assert!(QueryGuard::oid_over_limit(&"a".repeat(257)));
+        assert!(!QueryGuard::oid_over_limit(&"é".repeat(256)));
+    }
+
+    #[test]
+    fn it_limits_text_length() {
+        assert!(!QueryGuard::text_over_limit(&"a".repeat(99), Some(100)));
+        assert!(!QueryGuard::text_over_limit(&"a".repeat(100), Some(100)));
+        assert!(QueryGuard::text_over_limit(&"a".repeat(101), Some(100)));
+        assert!(!QueryGuard::text_over_limit(&"é".repeat(100), Some(100)));
+        assert!(!QueryGuard::text_over_limit(&"a".repeat(101), None));
+    }
+}
@@ -6,4 +6,8 @@
 
 pub mod actions;
 pub mod builder;
+pub mod cache;
+#[cfg(feature = "transport-grpc")]
+pub mod grpc;
+pub mod guard;
 pub mod types;
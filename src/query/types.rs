@@ -6,30 +6,215 @@
 
 use whatlang::Lang;
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, PartialEq, Clone)]
 pub enum QueryGenericLang {
     Enabled(Lang),
+    Detect,
+    Disabled,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum QueryGenericStopwords {
+    Enabled,
     Disabled,
 }
 
+#[derive(Debug, PartialEq, Hash, Clone, Copy)]
+pub enum QuerySearchHighlightStyle {
+    Html,
+    Markdown,
+    None,
+}
+
+#[derive(Debug, PartialEq, Hash, Clone, Copy, Default)]
+pub enum QuerySearchSortBy {
+    Relevance,
+    #[default]
+    Insertion,
+}
+
 pub type QuerySearchID<'a> = &'a str;
 pub type QuerySearchLimit = u16;
 pub type QuerySearchOffset = u32;
+pub type QuerySearchBoost = (String, f32);
+pub type QuerySearchWithinDistance = (QueryGenericGeo, f32);
+pub type QuerySearchPenalty = f32;
+pub type QuerySearchAbstract = usize;
+pub type QuerySearchMustNot = String;
+pub type QuerySearchPersonalize = String;
+pub type QuerySearchRecent = u64;
+pub type QuerySearchDecay = u64;
+pub type QuerySearchShard = (u32, u32);
+pub type QuerySearchSpan = u32;
+pub type QuerySearchRandom = u64;
+pub type QuerySearchTimeout = u64;
+pub type QuerySearchCursor = Option<u32>;
+pub type QuerySearchBucketWeight = (String, f32);
+pub type QuerySearchTermWeight = (String, f32);
+pub type QueryGenericGeo = (f32, f32);
+pub type QueryPushWeight = f32;
+pub type QueryPushDocID = u32;
+pub type QueryPushChecksum = [u8; 32];
+pub type QueryPushPriority = u8;
+pub type QuerySuggestContext = Vec<String>;
+pub type QuerySuggestMinFreq = u32;
+pub type QueryGenericTags = Vec<String>;
+pub type QueryGenericVector = Vec<f32>;
+pub type QueryGenericSegment = u8;
+
+#[derive(Default, Clone)]
+pub struct QuerySearchOptions {
+    pub lang: Option<QueryGenericLang>,
+    pub boosts: Vec<QuerySearchBoost>,
+    pub term_weights: Vec<QuerySearchTermWeight>,
+    pub within_distance: Option<QuerySearchWithinDistance>,
+    pub penalty_empty: Option<QuerySearchPenalty>,
+    pub abstract_chars: Option<QuerySearchAbstract>,
+    pub filter_tags: QueryGenericTags,
+    pub must_not: Vec<QuerySearchMustNot>,
+    pub personalize: Option<QuerySearchPersonalize>,
+    pub recent: Option<QuerySearchRecent>,
+    pub decay: Option<QuerySearchDecay>,
+    pub boost_recency: Option<QuerySearchDecay>,
+    pub segment: Option<QueryGenericSegment>,
+    pub shard: Option<QuerySearchShard>,
+    pub span: Option<QuerySearchSpan>,
+    pub random: Option<QuerySearchRandom>,
+    pub buckets: Vec<String>,
+    pub bucket_weights: Vec<QuerySearchBucketWeight>,
+    pub debug: bool,
+    pub highlight_style: Option<QuerySearchHighlightStyle>,
+    pub highlight: bool,
+    pub sort: QuerySearchSortBy,
+    pub timeout_ms: Option<QuerySearchTimeout>,
+    pub cursor: Option<QuerySearchCursor>,
+    pub or_terms: Vec<String>,
+    pub phrases: Vec<String>,
+}
+
+#[derive(Default)]
+pub struct QuerySuggestOptions {
+    pub context: QuerySuggestContext,
+    pub expand: bool,
+    pub min_freq: Option<QuerySuggestMinFreq>,
+}
+
+#[derive(Default)]
+pub struct QueryPushOptions {
+    pub lang: Option<QueryGenericLang>,
+    pub geo: Option<QueryGenericGeo>,
+    pub weight: Option<QueryPushWeight>,
+    pub deduplicate: bool,
+    pub text: Option<String>,
+    pub tags: Option<QueryGenericTags>,
+    pub docid: Option<QueryPushDocID>,
+    pub vector: Option<QueryGenericVector>,
+    pub segment: Option<QueryGenericSegment>,
+    pub priority: Option<QueryPushPriority>,
+    pub alias: Option<String>,
+}
 
-pub type QueryMetaData = (
-    Option<QuerySearchLimit>,
-    Option<QuerySearchOffset>,
-    Option<QueryGenericLang>,
-);
+pub enum QueryMetaData {
+    Limit(QuerySearchLimit),
+    Offset(QuerySearchOffset),
+    Lang(QueryGenericLang),
+    Stopwords(QueryGenericStopwords),
+    Mux(String),
+    Boost(QuerySearchBoost),
+    TermWeights(Vec<QuerySearchTermWeight>),
+    WithinDistance(QuerySearchWithinDistance),
+    Spellcheck(bool),
+    PenaltyEmpty(bool),
+    Penalty(QuerySearchPenalty),
+    Abstract(QuerySearchAbstract),
+    LangDetect(bool),
+    FilterTag(String),
+    MustNot(QuerySearchMustNot),
+    Personalize(QuerySearchPersonalize),
+    Recent(QuerySearchRecent),
+    Decay(QuerySearchDecay),
+    BoostRecency(QuerySearchDecay),
+    Segment(QueryGenericSegment),
+    Shard(QuerySearchShard),
+    Span(QuerySearchSpan),
+    Random(QuerySearchRandom),
+    MultiBucket(Vec<String>),
+    BucketWeights(Vec<QuerySearchBucketWeight>),
+    Debug(bool),
+    HighlightStyle(QuerySearchHighlightStyle),
+    Highlight(bool),
+    Sort(QuerySearchSortBy),
+    Timeout(QuerySearchTimeout),
+    Cursor(QuerySearchCursor),
+}
+
+pub enum PushMetaData {
+    Lang(QueryGenericLang),
+    Geo(QueryGenericGeo),
+    Weight(QueryPushWeight),
+    Deduplicate(bool),
+    Tags(QueryGenericTags),
+    DocID(QueryPushDocID),
+    Vector(QueryGenericVector),
+    Segment(QueryGenericSegment),
+    Priority(QueryPushPriority),
+    Alias(String),
+    Checksum(QueryPushChecksum),
+}
+
+pub enum SuggestMetaData {
+    Limit(QuerySearchLimit),
+    Mux(String),
+    Context(QuerySuggestContext),
+    Expand(bool),
+    MinFreq(QuerySuggestMinFreq),
+}
+
+pub enum VsearchMetaData {
+    Vector(QueryGenericVector),
+    Limit(QuerySearchLimit),
+    Mux(String),
+}
 
 pub type ListMetaData = (Option<QuerySearchLimit>, Option<QuerySearchOffset>);
 
 impl QueryGenericLang {
     pub fn from_value(value: &str) -> Option<QueryGenericLang> {
-        if value == "none" {
-            Some(QueryGenericLang::Disabled)
-        } else {
-            Lang::from_code(value).map(QueryGenericLang::Enabled)
+        match value {
+            "auto" => Some(QueryGenericLang::Detect),
+            "none" => Some(QueryGenericLang::Disabled),
+            _ => Lang::from_code(value).map(QueryGenericLang::Enabled),
+        }
+    }
+}
+
+impl QueryGenericStopwords {
+    pub fn from_value(value: &str) -> Option<QueryGenericStopwords> {
+        match value {
+            "auto" => Some(QueryGenericStopwords::Enabled),
+            "none" => Some(QueryGenericStopwords::Disabled),
+            _ => None,
+        }
+    }
+}
+
+impl QuerySearchHighlightStyle {
+    pub fn from_value(value: &str) -> Option<QuerySearchHighlightStyle> {
+        match value {
+            "html" => Some(QuerySearchHighlightStyle::Html),
+            "markdown" => Some(QuerySearchHighlightStyle::Markdown),
+            "none" => Some(QuerySearchHighlightStyle::None),
+            _ => None,
+        }
+    }
+}
+
+impl QuerySearchSortBy {
+    pub fn from_value(value: &str) -> Option<QuerySearchSortBy> {
+        match value {
+            "relevance" => Some(QuerySearchSortBy::Relevance),
+            "insertion" => Some(QuerySearchSortBy::Insertion),
+            _ => None,
         }
     }
 }
@@ -40,6 +225,10 @@ mod tests {
 
     #[test]
     fn it_parses_generic_lang_from_value() {
+        assert_eq!(
+            QueryGenericLang::from_value("auto"),
+            Some(QueryGenericLang::Detect)
+        );
         assert_eq!(
             QueryGenericLang::from_value("none"),
             Some(QueryGenericLang::Disabled)
@@ -50,4 +239,48 @@ mod tests {
         );
         assert_eq!(QueryGenericLang::from_value("xxx"), None);
     }
+
+    #[test]
+    fn it_parses_generic_stopwords_from_value() {
+        assert_eq!(
+            QueryGenericStopwords::from_value("auto"),
+            Some(QueryGenericStopwords::Enabled)
+        );
+        assert_eq!(
+            QueryGenericStopwords::from_value("none"),
+            Some(QueryGenericStopwords::Disabled)
+        );
+        assert_eq!(QueryGenericStopwords::from_value("all"), None);
+    }
+
+    #[test]
+    fn it_parses_search_highlight_style_from_value() {
+        assert_eq!(
+            QuerySearchHighlightStyle::from_value("html"),
+            Some(QuerySearchHighlightStyle::Html)
+        );
+        assert_eq!(
+            QuerySearchHighlightStyle::from_value("markdown"),
+            Some(QuerySearchHighlightStyle::Markdown)
+        );
+        assert_eq!(
+            QuerySearchHighlightStyle::from_value("none"),
+            Some(QuerySearchHighlightStyle::None)
+        );
+        assert_eq!(QuerySearchHighlightStyle::from_value("bbcode"), None);
+    }
+
+    #[test]
+    fn it_parses_search_sort_by_from_value() {
+        assert_eq!(
+            QuerySearchSortBy::from_value("relevance"),
+            Some(QuerySearchSortBy::Relevance)
+        );
+        assert_eq!(
+            QuerySearchSortBy::from_value("insertion"),
+            Some(QuerySearchSortBy::Insertion)
+        );
+        assert_eq!(QuerySearchSortBy::from_value("random"), None);
+        assert_eq!(QuerySearchSortBy::default(), QuerySearchSortBy::Insertion);
+    }
 }
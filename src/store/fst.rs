@@ -15,13 +15,17 @@ use fst_regex::Regex;
 use hashbrown::{HashMap, HashSet};
 use radix::RadixNum;
 use regex_syntax::escape as regex_escape;
+use sha2::{Digest, Sha256};
+use std::cell::RefCell;
 use std::collections::VecDeque;
 use std::fmt;
 use std::fs::{self, File};
 use std::io::{self, BufRead, BufReader, BufWriter, Write};
 use std::iter::FromIterator;
+use std::panic::{self, AssertUnwindSafe};
 use std::path::{Path, PathBuf};
 use std::str;
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::{Arc, Mutex, RwLock};
 use std::thread;
 use std::time::{Duration, SystemTime};
@@ -29,9 +33,11 @@ use std::time::{Duration, SystemTime};
 use super::generic::{
     StoreGeneric, StoreGenericActionBuilder, StoreGenericBuilder, StoreGenericPool,
 };
+use super::identifiers::{StoreTermHash, StoreTermHashed};
 use super::keyer::StoreKeyerHasher;
+use crate::lexer::normalize::LexerNormalize;
 use crate::lexer::ranges::LexerRegexRange;
-use crate::APP_CONF;
+use crate::{APP_CONF, THREAD_NAME_CONSOLIDATE};
 
 pub struct StoreFSTPool;
 pub struct StoreFSTBuilder;
@@ -64,18 +70,29 @@ pub struct StoreFSTKey {
 
 pub struct StoreFSTMisc;
 
+#[derive(Default)]
+pub struct StoreFSTPoolStats {
+    pub terms: u64,
+    pub disk_files: u64,
+    pub disk_bytes: u64,
+    pub memory_bytes: u64,
+}
+
 #[derive(Copy, Clone)]
 enum StoreFSTPathMode {
     Permanent,
     Temporary,
     Backup,
+    Export,
 }
 
-type StoreFSTAtom = u32;
+pub type StoreFSTAtom = u32;
 type StoreFSTBox = Arc<StoreFST>;
+type StoreFSTDumpItemFn<'a> = dyn Fn(&Path, &Path, &str, &str) -> Result<usize, io::Error> + 'a;
 
 const WORD_LIMIT_LENGTH: usize = 40;
 const ATOM_HASH_RADIX: usize = 16;
+const BACKUP_MANIFEST_NAME: &str = "manifest.sha256";
 
 lazy_static! {
     pub static ref GRAPH_ACCESS_LOCK: Arc<RwLock<bool>> = Arc::new(RwLock::new(false));
@@ -87,12 +104,15 @@ lazy_static! {
         Arc::new(RwLock::new(HashSet::new()));
 }
 
+static GRAPH_CONSOLIDATE_EARLY: AtomicBool = AtomicBool::new(false);
+
 impl StoreFSTPathMode {
     fn extension(&self) -> &'static str {
         match self {
             StoreFSTPathMode::Permanent => ".fst",
             StoreFSTPathMode::Temporary => ".fst.tmp",
             StoreFSTPathMode::Backup => ".fst.bck",
+            StoreFSTPathMode::Export => ".txt",
         }
     }
 }
@@ -105,11 +125,98 @@ impl StoreFSTPool {
         )
     }
 
+    pub fn stats() -> StoreFSTPoolStats {
+        let graphs: Vec<StoreFSTBox> = GRAPH_POOL.read().unwrap().values().cloned().collect();
+
+        let (disk_files, disk_bytes) = Self::disk_usage();
+
+        let mut stats = StoreFSTPoolStats {
+            disk_files,
+            disk_bytes,
+            ..Default::default()
+        };
+
+        for graph in graphs {
+            stats.terms += graph.cardinality() as u64;
+            stats.memory_bytes += graph.memory_usage() as u64;
+        }
+
+        stats
+    }
+
+    fn consolidate_early() {
+        // Notice: consolidation runs from its own thread, as the caller holds the graph access \
+        //   lock that consolidation needs to acquire in write mode. Only one early consolidation \
+        //   can be scheduled at a time, further requests are dropped until it is done.
+        if !GRAPH_CONSOLIDATE_EARLY.swap(true, Ordering::SeqCst) {
+            info!("scheduling early fst store pool consolidation, as too many words are pending");
+
+            let spawn = thread::Builder::new()
+                .name(THREAD_NAME_CONSOLIDATE.to_string())
+                .spawn(|| {
+                    Self::consolidate(true);
+
+                    GRAPH_CONSOLIDATE_EARLY.store(false, Ordering::SeqCst);
+                });
+
+            if let Err(err) = spawn {
+                error!("could not spawn early consolidation thread: {}", err);
+
+                GRAPH_CONSOLIDATE_EARLY.store(false, Ordering::SeqCst);
+            }
+        }
+    }
+
+    fn disk_usage() -> (u64, u64) {
+        let path_mode = StoreFSTPathMode::Permanent;
+
+        let (mut count, mut size) = (0, 0);
+
+        // Scan all collection directories, and count then sum sizes of contained FST files
+        if let Ok(collections) = fs::read_dir(&APP_CONF.store.fst.path) {
+            for collection in collections.flatten() {
+                if let Ok(buckets) = fs::read_dir(collection.path()) {
+                    for bucket in buckets.flatten() {
+                        // Only account for permanent FST files (skip temporary and backup files)
+                        if let Some(bucket_name) = bucket.file_name().to_str() {
+                            if bucket_name.ends_with(path_mode.extension()) {
+                                count += 1;
+
+                                if let Ok(metadata) = fs::metadata(bucket.path()) {
+                                    size += metadata.len();
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+
+        (count, size)
+    }
+
     pub fn acquire<'a, T: Into<&'a str>>(collection: T, bucket: T) -> Result<StoreFSTBox, ()> {
         let (collection_str, bucket_str) = (collection.into(), bucket.into());
 
-        let pool_key = StoreFSTKey::from_str(collection_str, bucket_str);
+        Self::acquire_key(
+            collection_str,
+            StoreFSTKey::from_str(collection_str, bucket_str),
+        )
+    }
+
+    pub fn acquire_atom<'a, T: Into<&'a str>>(
+        collection: T,
+        bucket_hash: StoreFSTAtom,
+    ) -> Result<StoreFSTBox, ()> {
+        let collection_str = collection.into();
+
+        Self::acquire_key(
+            collection_str,
+            StoreFSTKey::from_atom(StoreKeyerHasher::to_compact(collection_str), bucket_hash),
+        )
+    }
 
+    fn acquire_key(collection_str: &str, pool_key: StoreFSTKey) -> Result<StoreFSTBox, ()> {
         // Freeze acquire lock, and reference it in context
         // Notice: this prevents two graphs on the same collection to be opened at the same time.
         let _acquire = GRAPH_ACQUIRE_LOCK.lock().unwrap();
@@ -121,18 +228,51 @@ impl StoreFSTPool {
             Self::proceed_acquire_cache("fst", collection_str, pool_key, store_fst)
         } else {
             info!(
-                "fst store not in pool for collection: {} <{:x?}> / bucket: {} <{:x?}>, opening it",
-                collection_str, pool_key.collection_hash, bucket_str, pool_key.bucket_hash
+                "fst store not in pool for collection: {} <{:x?}> / bucket: <{:x?}>, opening it",
+                collection_str, pool_key.collection_hash, pool_key.bucket_hash
             );
 
             // Important: we need to drop the read reference first, to avoid dead-locking \
             //   when acquiring the RWLock in write mode in this block.
             drop(graph_pool_read);
 
-            Self::proceed_acquire_open("fst", collection_str, pool_key, &*GRAPH_POOL)
+            Self::proceed_acquire_open(
+                "fst",
+                collection_str,
+                pool_key,
+                &*GRAPH_POOL,
+                0,
+                APP_CONF.store.fst.pool.max_open,
+            )
         }
     }
 
+    pub fn drain(collection_str: &str) -> Vec<StoreFSTAtom> {
+        let collection_atom = StoreKeyerHasher::to_compact(collection_str);
+
+        // Scan for opened buckets in-memory (all of them must be drained)
+        let bucket_keys: Vec<StoreFSTKey> = GRAPH_POOL
+            .read()
+            .unwrap()
+            .keys()
+            .filter(|key| key.collection_hash == collection_atom)
+            .copied()
+            .collect();
+
+        for bucket_key in &bucket_keys {
+            // Consolidate pending changes before closing? (as they would be lost otherwise)
+            if let Some(store) = GRAPH_POOL.read().unwrap().get(bucket_key) {
+                debug!("fst key: {} consolidating before drain", bucket_key);
+
+                Self::consolidate_item(store);
+            }
+
+            StoreFSTBuilder::close(bucket_key.collection_hash, bucket_key.bucket_hash);
+        }
+
+        bucket_keys.iter().map(|key| key.bucket_hash).collect()
+    }
+
     pub fn janitor() {
         Self::proceed_janitor(
             "fst",
@@ -154,8 +294,65 @@ impl StoreFSTPool {
             StoreFSTPathMode::Permanent,
             &*APP_CONF.store.fst.path,
             path,
-            &Self::backup_item,
-        )
+            &|backup_path, origin_path, collection_name, bucket_name| {
+                Self::backup_item(
+                    StoreFSTPathMode::Backup,
+                    backup_path,
+                    origin_path,
+                    collection_name,
+                    bucket_name,
+                )
+            },
+        )?;
+
+        // Write checksums of backed up files, as to allow for later verification
+        Self::write_backup_manifest(path)
+    }
+
+    pub fn verify(path: &Path) -> Result<(), io::Error> {
+        debug!("verifying all fst store backups in path: {:?}", path);
+
+        let manifest_reader = BufReader::new(File::open(path.join(BACKUP_MANIFEST_NAME))?);
+
+        let mut count_corrupt = 0;
+
+        // Compare the checksum of each backed up file against its manifest checksum
+        for line in manifest_reader.lines() {
+            let line = line?;
+
+            let (checksum, file_name) = line
+                .split_once("  ")
+                .ok_or_else(|| io_error!("backup manifest format failure"))?;
+
+            let file_path = path.join(file_name);
+
+            match Self::checksum_file(&file_path) {
+                Ok(ref file_checksum) if file_checksum == checksum => {
+                    debug!("fst backup file verified: {:?}", file_path);
+                }
+                Ok(_) => {
+                    error!(
+                        "fst backup file is corrupt: {:?} (checksum mismatch)",
+                        file_path
+                    );
+
+                    count_corrupt += 1;
+                }
+                Err(err) => {
+                    error!("fst backup file is corrupt: {:?} ({})", file_path, err);
+
+                    count_corrupt += 1;
+                }
+            }
+        }
+
+        if count_corrupt > 0 {
+            Err(io_error!("backup verify failure"))
+        } else {
+            info!("fst store backups verified in path: {:?}", path);
+
+            Ok(())
+        }
     }
 
     pub fn restore(path: &Path) -> Result<(), io::Error> {
@@ -169,6 +366,53 @@ impl StoreFSTPool {
             &*APP_CONF.store.fst.path,
             &Self::restore_item,
         )
+        .map(|_| ())
+    }
+
+    pub fn export(path: &Path) -> Result<usize, io::Error> {
+        debug!("exporting all fst stores to path: {:?}", path);
+
+        // Force a FST consolidation first, as to also export words pending in loaded FSTs
+        Self::consolidate(true);
+
+        // Create export directory (full path)
+        fs::create_dir_all(path)?;
+
+        // Proceed dump action (export)
+        Self::dump_action(
+            "export",
+            StoreFSTPathMode::Permanent,
+            &APP_CONF.store.fst.path,
+            path,
+            &|export_path, origin_path, collection_name, bucket_name| {
+                Self::backup_item(
+                    StoreFSTPathMode::Export,
+                    export_path,
+                    origin_path,
+                    collection_name,
+                    bucket_name,
+                )
+            },
+        )
+    }
+
+    pub fn import(path: &Path) -> Result<usize, io::Error> {
+        debug!("importing all fst stores from path: {:?}", path);
+
+        // Proceed dump action (import)
+        // Notice: imported words are pushed to their FST, thus they get merged with any word \
+        //   already there. Words get consolidated right away once all have been pushed.
+        let count_words = Self::dump_action(
+            "import",
+            StoreFSTPathMode::Export,
+            path,
+            &APP_CONF.store.fst.path,
+            &Self::import_item,
+        )?;
+
+        Self::consolidate(true);
+
+        Ok(count_words)
     }
 
     pub fn consolidate(force: bool) {
@@ -324,8 +568,10 @@ impl StoreFSTPool {
         path_mode: StoreFSTPathMode,
         read_path: &Path,
         write_path: &Path,
-        fn_item: &dyn Fn(&Path, &Path, &str, &str) -> Result<(), io::Error>,
-    ) -> Result<(), io::Error> {
+        fn_item: &StoreFSTDumpItemFn<'_>,
+    ) -> Result<usize, io::Error> {
+        let mut count_words = 0;
+
         let fst_extension = path_mode.extension();
         let fst_extension_len = fst_extension.len();
 
@@ -366,7 +612,12 @@ impl StoreFSTPool {
                                     action, collection_name, bucket_name
                                 );
 
-                                fn_item(write_path, &bucket.path(), collection_name, bucket_name)?;
+                                count_words += fn_item(
+                                    write_path,
+                                    &bucket.path(),
+                                    collection_name,
+                                    bucket_name,
+                                )?;
                             }
                         }
                     }
@@ -374,15 +625,16 @@ impl StoreFSTPool {
             }
         }
 
-        Ok(())
+        Ok(count_words)
     }
 
     fn backup_item(
+        backup_mode: StoreFSTPathMode,
         backup_path: &Path,
         _origin_path: &Path,
         collection_name: &str,
         bucket_name: &str,
-    ) -> Result<(), io::Error> {
+    ) -> Result<usize, io::Error> {
         // Acquire access lock (in blocking write mode), and reference it in context
         // Notice: this prevents store to be acquired from any context
         let _access = GRAPH_ACCESS_LOCK.write().unwrap();
@@ -391,7 +643,7 @@ impl StoreFSTPool {
         let fst_backup_path = backup_path.join(collection_name).join(format!(
             "{}{}",
             bucket_name,
-            StoreFSTPathMode::Backup.extension()
+            backup_mode.extension()
         ));
 
         debug!(
@@ -440,7 +692,51 @@ impl StoreFSTPool {
             }
         }
 
-        Ok(())
+        Ok(count_words)
+    }
+
+    fn write_backup_manifest(path: &Path) -> Result<(), io::Error> {
+        let entries = RefCell::new(Vec::new());
+
+        // Checksum all backed up files (format is compatible with the 'sha256sum' utility)
+        Self::dump_action(
+            "checksum",
+            StoreFSTPathMode::Backup,
+            path,
+            path,
+            &|_, backup_path, collection_name, bucket_name| {
+                entries.borrow_mut().push(format!(
+                    "{}  {}/{}{}",
+                    Self::checksum_file(backup_path)?,
+                    collection_name,
+                    bucket_name,
+                    StoreFSTPathMode::Backup.extension()
+                ));
+
+                Ok(0)
+            },
+        )?;
+
+        let mut entries = entries.into_inner();
+
+        entries.sort_unstable();
+
+        let mut manifest_writer = BufWriter::new(File::create(path.join(BACKUP_MANIFEST_NAME))?);
+
+        for entry in entries {
+            manifest_writer.write_all(entry.as_bytes())?;
+            manifest_writer.write_all(b"\n")?;
+        }
+
+        manifest_writer.flush()
+    }
+
+    fn checksum_file(path: &Path) -> Result<String, io::Error> {
+        let mut hasher = Sha256::new();
+
+        io::copy(&mut BufReader::new(File::open(path)?), &mut hasher)?;
+
+        Ok(format!("{:x}", hasher.finalize()))
     }
 
     fn restore_item(
@@ -448,11 +744,13 @@ impl StoreFSTPool {
         origin_path: &Path,
         collection_name: &str,
         bucket_name: &str,
-    ) -> Result<(), io::Error> {
+    ) -> Result<usize, io::Error> {
         // Acquire access lock (in blocking write mode), and reference it in context
         // Notice: this prevents store to be acquired from any context
         let _access = GRAPH_ACCESS_LOCK.write().unwrap();
 
+        let mut count_words = 0;
+
         debug!(
             "fst bucket: {}/{} restoring from path: {:?}",
             collection_name, bucket_name, origin_path
@@ -498,6 +796,8 @@ impl StoreFSTPool {
                     fst_builder
                         .insert(word)
                         .map_err(|_| io_error!("graph restore word insert failure"))?;
+
+                    count_words += 1;
                 }
 
                 fst_builder
@@ -511,7 +811,60 @@ impl StoreFSTPool {
             }
         }
 
-        Ok(())
+        Ok(count_words)
+    }
+
+    fn import_item(
+        _store_path: &Path,
+        origin_path: &Path,
+        collection_name: &str,
+        bucket_name: &str,
+    ) -> Result<usize, io::Error> {
+        let mut count_words = 0;
+
+        debug!(
+            "fst bucket: {}/{} importing from path: {:?}",
+            collection_name, bucket_name, origin_path
+        );
+
+        // Convert names to hashes (as names are hashes encoded as base-16 strings, but we need \
+        //   them as proper integers)
+        if let (Ok(collection_radix), Ok(bucket_radix)) = (
+            RadixNum::from_str(collection_name, ATOM_HASH_RADIX),
+            RadixNum::from_str(bucket_name, ATOM_HASH_RADIX),
+        ) {
+            if let (Ok(collection_hash), Ok(bucket_hash)) =
+                (collection_radix.as_decimal(), bucket_radix.as_decimal())
+            {
+                let store = Self::acquire_key(
+                    collection_name,
+                    StoreFSTKey::from_atom(
+                        collection_hash as StoreFSTAtom,
+                        bucket_hash as StoreFSTAtom,
+                    ),
+                )
+                .map_err(|_| io_error!("graph acquire failure"))?;
+
+                let fst_action = StoreFSTActionBuilder::access(store);
+                let fst_import_reader = BufReader::new(File::open(origin_path)?);
+
+                // Push imported words to FST (pushed words get consolidated later on)
+                for word in fst_import_reader.lines() {
+                    let word = word?;
+
+                    if !word.is_empty() && fst_action.push_word(&word) {
+                        count_words += 1;
+                    }
+                }
+
+                info!(
+                    "fst bucket: {}/{} imported from path: {:?} ({} words)",
+                    collection_name, bucket_name, origin_path, count_words
+                );
+            }
+        }
+
+        Ok(count_words)
     }
 
     fn consolidate_item(store: &StoreFSTBox) -> (bool, usize, usize, usize) {
@@ -818,6 +1171,16 @@ impl StoreFST {
         self.graph.len()
     }
 
+    pub fn memory_usage(&self) -> usize {
+        let pending_size = |words: &Arc<RwLock<HashSet<Vec<u8>>>>| -> usize {
+            words.read().unwrap().iter().map(|word| word.len()).sum()
+        };
+
+        self.graph.as_fst().size()
+            + pending_size(&self.pending.push)
+            + pending_size(&self.pending.pop)
+    }
+
     pub fn as_stream(&self) -> FSTStream<AlwaysMatch> {
         self.graph.into_stream()
     }
@@ -922,6 +1285,11 @@ impl StoreGeneric for StoreFST {
     fn ref_last_used(&self) -> &RwLock<SystemTime> {
         &self.last_used
     }
+
+    fn has_pending(&self) -> bool {
+        !self.pending.push.read().unwrap().is_empty()
+            || !self.pending.pop.read().unwrap().is_empty()
+    }
 }
 
 impl StoreFSTActionBuilder {
@@ -1093,6 +1461,13 @@ impl StoreFSTAction {
 
             self.store.should_consolidate();
 
+            // Too many words pending consolidation? Force an early consolidation.
+            if let Some(max_pending_words) = APP_CONF.store.fst.graph.max_pending_words {
+                if self.store.pending.push.read().unwrap().len() >= max_pending_words {
+                    StoreFSTPool::consolidate_early();
+                }
+            }
+
             // Pushed
             true
         } else {
@@ -1191,6 +1566,105 @@ impl StoreFSTAction {
         self.store.cardinality()
     }
 
+    pub fn hash_words(&self) -> HashSet<StoreTermHashed> {
+        let mut terms_hashed = HashSet::new();
+
+        // Hash all words from FST stream (words are hashed from their diacritic-folded form, \
+        //   the same way the lexer does)
+        let mut stream = self.store.as_stream();
+
+        while let Some(word) = stream.next() {
+            if let Ok(word_str) = str::from_utf8(word) {
+                terms_hashed.insert(StoreTermHash::from(&LexerNormalize::fold_diacritics(
+                    word_str,
+                )));
+            }
+        }
+
+        // Apply pending changes (as they are not yet consolidated in FST stream)
+        for word in self.store.pending.pop.read().unwrap().iter() {
+            if let Ok(word_str) = str::from_utf8(word) {
+                terms_hashed.remove(&StoreTermHash::from(&LexerNormalize::fold_diacritics(
+                    word_str,
+                )));
+            }
+        }
+
+        for word in self.store.pending.push.read().unwrap().iter() {
+            if let Ok(word_str) = str::from_utf8(word) {
+                terms_hashed.insert(StoreTermHash::from(&LexerNormalize::fold_diacritics(
+                    word_str,
+                )));
+            }
+        }
+
+        terms_hashed
+    }
+
+    pub fn map_words(&self) -> HashMap<StoreTermHashed, String> {
+        let mut terms_mapped = HashMap::new();
+
+        // Map all words from FST stream to their hashes
+        let mut stream = self.store.as_stream();
+
+        while let Some(word) = stream.next() {
+            if let Ok(word_str) = str::from_utf8(word) {
+                terms_mapped.insert(
+                    StoreTermHash::from(&LexerNormalize::fold_diacritics(word_str)),
+                    word_str.to_owned(),
+                );
+            }
+        }
+
+        // Apply pending changes (as they are not yet consolidated in FST stream)
+        for word in self.store.pending.pop.read().unwrap().iter() {
+            if let Ok(word_str) = str::from_utf8(word) {
+                terms_mapped.remove(&StoreTermHash::from(&LexerNormalize::fold_diacritics(
+                    word_str,
+                )));
+            }
+        }
+
+        for word in self.store.pending.push.read().unwrap().iter() {
+            if let Ok(word_str) = str::from_utf8(word) {
+                terms_mapped.insert(
+                    StoreTermHash::from(&LexerNormalize::fold_diacritics(word_str)),
+                    word_str.to_owned(),
+                );
+            }
+        }
+
+        terms_mapped
+    }
+
+    pub fn dump_words(&self, path: &Path) -> Result<u32, io::Error> {
+        let mut count = 0;
+
+        // Write all words to a temporary file first, then rename it to its final path, so that \
+        //   a partially-written dump can never be observed at the final path.
+        let mut path_temporary = path.as_os_str().to_owned();
+
+        path_temporary.push(".tmp");
+
+        {
+            let mut writer = BufWriter::new(File::create(&path_temporary)?);
+            let mut stream = self.store.as_stream();
+
+            while let Some(word) = stream.next() {
+                writer.write_all(word)?;
+                writer.write_all(b"\n")?;
+
+                count += 1;
+            }
+
+            writer.flush()?;
+        }
+
+        fs::rename(&path_temporary, path)?;
+
+        Ok(count)
+    }
+
     fn word_over_limit(word: &str) -> bool {
         if word.len() > WORD_LIMIT_LENGTH {
             debug!("got over-limit fst word: {}", word);
@@ -1206,7 +1680,21 @@ impl StoreFSTAction {
         found_words: &mut Vec<String>,
         limit: usize,
     ) {
+        let scan_limit = APP_CONF.store.fst.graph.suggest_scan_limit;
+
+        let mut count_scanned = 0;
+
         while let Some(word) = stream.next() {
+            // Scan limit reached? Stop there, with words found so far (this bounds the time \
+            //   spent scanning large graphs, eg. for rare prefixes)
+            if count_scanned >= scan_limit {
+                debug!("reached fst stream scan limit: {}", scan_limit);
+
+                break;
+            }
+
+            count_scanned += 1;
+
             if let Ok(word_str) = str::from_utf8(word) {
                 let word_string = word_str.to_string();
 
@@ -1224,8 +1712,141 @@ impl StoreFSTAction {
 }
 
 impl StoreFSTMisc {
+    pub fn list_collections() -> Result<Vec<String>, ()> {
+        let fst_path = &APP_CONF.store.fst.path;
+
+        // Exit trap: no FST collection was ever created, there is nothing to list
+        if !fst_path.exists() {
+            return Ok(Vec::new());
+        }
+
+        let entries = fs::read_dir(fst_path).map_err(|err| {
+            error!(
+                "failed reading fst directory for list: {:?}: {}",
+                fst_path, err
+            );
+        })?;
+
+        // Notice: collection directories are named after collection hashes, in hexadecimal
+        Ok(entries
+            .flatten()
+            .filter(
+                |collection| matches!(collection.file_type(), Ok(file_type) if file_type.is_dir()),
+            )
+            .filter_map(|collection| collection.file_name().to_str().map(String::from))
+            .filter(|collection_name| {
+                collection_name
+                    .chars()
+                    .all(|character| character.is_ascii_hexdigit())
+            })
+            .collect())
+    }
+
+    pub fn fsck_collections() -> Result<(Vec<String>, usize, usize), ()> {
+        // Acquire rebuild lock, and reference it in context
+        // Notice: this prevents a consolidation from writing temporary graph files while they \
+        //   get checked, as those would be reported as leftovers
+        let _rebuild = GRAPH_REBUILD_LOCK.lock().unwrap();
+
+        // Acquire access lock (in read mode), and reference it in context
+        // Notice: this prevents graph files from being erased or replaced while being mapped
+        let _access = GRAPH_ACCESS_LOCK.read().unwrap();
+
+        let (mut collections, mut count_leftovers, mut count_invalid) = (Vec::new(), 0, 0);
+
+        let fst_path = &APP_CONF.store.fst.path;
+
+        // Exit trap: no FST collection was ever created, there is nothing to check
+        if !fst_path.exists() {
+            return Ok((collections, count_leftovers, count_invalid));
+        }
+
+        let (permanent_extension, temporary_extension) = (
+            StoreFSTPathMode::Permanent.extension(),
+            StoreFSTPathMode::Temporary.extension(),
+        );
+
+        let read_dir = |path: &Path| {
+            fs::read_dir(path).map_err(|err| {
+                error!("failed reading fst directory for fsck: {:?}: {}", path, err);
+            })
+        };
+
+        for collection in read_dir(fst_path)?.flatten() {
+            if let (Ok(collection_file_type), Some(collection_name)) =
+                (collection.file_type(), collection.file_name().to_str())
+            {
+                if collection_file_type.is_dir() {
+                    for bucket in read_dir(&collection.path())?.flatten() {
+                        if let Some(bucket_name) = bucket.file_name().to_str() {
+                            if bucket_name.ends_with(temporary_extension) {
+                                // Temporary graph found? This is a leftover from a failed \
+                                //   consolidation (no consolidation is running)
+                                warn!(
+                                    "fsck found leftover fst file: {}/{}",
+                                    collection_name, bucket_name
+                                );
+
+                                count_leftovers += 1;
+                            } else if bucket_name.ends_with(permanent_extension) {
+                                // Check that graph can be memory-mapped (the same way as it \
+                                //   gets opened)
+                                if let Err(err) = unsafe { FSTSet::from_path(bucket.path()) } {
+                                    error!(
+                                        "fsck found invalid fst file: {}/{}: {}",
+                                        collection_name, bucket_name, err
+                                    );
+
+                                    count_invalid += 1;
+                                }
+                            }
+                        }
+                    }
+
+                    collections.push(collection_name.to_string());
+                }
+            }
+        }
+
+        Ok((collections, count_leftovers, count_invalid))
+    }
+
+    pub fn copy_collection(source: &str, target: &str) -> Result<usize, ()> {
+        let path_mode = StoreFSTPathMode::Permanent;
+
+        let (source_atom, target_atom) = (
+            StoreKeyerHasher::to_compact(source),
+            StoreKeyerHasher::to_compact(target),
+        );
+
+        let buckets = Self::list_collection_buckets(source)?;
+
+        if !buckets.is_empty() {
+            fs::create_dir_all(StoreFSTBuilder::path(path_mode, target_atom, None)).or(Err(()))?;
+        }
+
+        // Copy all bucket graphs as-is (graph names do not depend on the collection)
+        for bucket_atom in &buckets {
+            fs::copy(
+                StoreFSTBuilder::path(path_mode, source_atom, Some(*bucket_atom)),
+                StoreFSTBuilder::path(path_mode, target_atom, Some(*bucket_atom)),
+            )
+            .map_err(|err| {
+                error!("failed copying fst bucket: <{:x?}>: {}", bucket_atom, err);
+            })?;
+        }
+
+        Ok(buckets.len())
+    }
+
     pub fn count_collection_buckets<'a, T: Into<&'a str>>(collection: T) -> Result<usize, ()> {
-        let mut count = 0;
+        Self::list_collection_buckets(collection).map(|buckets| buckets.len())
+    }
+
+    pub fn list_collection_buckets<'a, T: Into<&'a str>>(
+        collection: T,
+    ) -> Result<Vec<StoreFSTAtom>, ()> {
+        let mut buckets = Vec::new();
 
         let path_mode = StoreFSTPathMode::Permanent;
 
@@ -1233,7 +1854,7 @@ impl StoreFSTMisc {
         let collection_path = StoreFSTBuilder::path(path_mode, collection_atom, None);
 
         if collection_path.exists() {
-            // Scan collection directory for contained buckets (count them)
+            // Scan collection directory for contained buckets (list them)
             if let Ok(entries) = fs::read_dir(&collection_path) {
                 let fst_extension = path_mode.extension();
                 let fst_extension_len = fst_extension.len();
@@ -1245,18 +1866,74 @@ impl StoreFSTMisc {
                         // FST file found? This is a bucket.
                         if entry_name_len > fst_extension_len && entry_name.ends_with(fst_extension)
                         {
-                            count += 1;
+                            let bucket_name = &entry_name[..(entry_name_len - fst_extension_len)];
+
+                            // Convert name to hash (as names are hashes encoded as base-16 \
+                            //   strings)
+                            if let Ok(bucket_hash) =
+                                RadixNum::from_str(bucket_name, ATOM_HASH_RADIX)
+                                    .and_then(|bucket_radix| bucket_radix.as_decimal())
+                            {
+                                buckets.push(bucket_hash as StoreFSTAtom);
+                            } else {
+                                warn!("got invalid fst bucket file name: {}", entry_name);
+                            }
                         }
                     }
                 }
             } else {
-                error!("failed reading directory for count: {:?}", collection_path);
+                error!("failed reading directory for list: {:?}", collection_path);
 
                 return Err(());
             }
         }
 
-        Ok(count)
+        Ok(buckets)
+    }
+
+    pub fn verify_bucket<'a, T: Into<&'a str>>(collection: T, bucket: T) -> Result<usize, String> {
+        let (collection_atom, bucket_atom) = (
+            StoreKeyerHasher::to_compact(collection.into()),
+            StoreKeyerHasher::to_compact(bucket.into()),
+        );
+
+        let bucket_path = StoreFSTBuilder::path(
+            StoreFSTPathMode::Permanent,
+            collection_atom,
+            Some(bucket_atom),
+        );
+
+        // Exit trap: FST does not exist on disk, there is nothing to verify
+        if !bucket_path.exists() {
+            return Ok(0);
+        }
+
+        // Read graph in memory (this does not memory-map the file, unlike when opening the \
+        //   graph; a corrupted graph is thus rejected safely)
+        let graph_bytes = fs::read(&bucket_path).map_err(|err| err.to_string())?;
+        let graph = FSTSet::from_bytes(graph_bytes).map_err(|err| err.to_string())?;
+
+        // Stream all words from graph (a corrupted graph may panic while being decoded)
+        let count_words = panic::catch_unwind(AssertUnwindSafe(|| {
+            let (mut stream, mut count) = (graph.stream(), 0);
+
+            while stream.next().is_some() {
+                count += 1;
+            }
+
+            count
+        }))
+        .map_err(|_| "stream decode failure".to_string())?;
+
+        if count_words == graph.len() {
+            Ok(count_words)
+        } else {
+            Err(format!(
+                "streamed {} words out of {}",
+                count_words,
+                graph.len()
+            ))
+        }
     }
 
     fn check_over_limits(bytes_count: usize, words_count: usize) -> bool {
@@ -1329,4 +2006,53 @@ mod tests {
 
         assert!(store.lookup_typos("valerien", None).is_ok());
     }
+
+    #[test]
+    fn it_verifies_missing_graph() {
+        assert_eq!(StoreFSTMisc::verify_bucket("c:test:3", "b:test:3"), Ok(0));
+    }
+
+    #[test]
+    fn it_lists_collection_buckets() {
+        for bucket in ["b:test:4:1", "b:test:4:2"] {
+            let store = StoreFSTPool::acquire("c:test:4", bucket).unwrap();
+
+            StoreFSTActionBuilder::access(store).push_word("valerian");
+        }
+
+        // Commit pending words to on-disk graphs, as only those are listed
+        StoreFSTPool::consolidate(true);
+
+        let buckets = StoreFSTMisc::list_collection_buckets("c:test:4").unwrap();
+
+        assert!(buckets.contains(&StoreKeyerHasher::to_compact("b:test:4:1")));
+        assert!(buckets.contains(&StoreKeyerHasher::to_compact("b:test:4:2")));
+    }
+
+    #[test]
+    fn it_verifies_backup_manifest() {
+        let path = std::env::temp_dir().join("sonic-test-store-fst-verify");
+        let file_path = path.join("1a").join("2b.fst.bck");
+
+        fs::remove_dir_all(&path).ok();
+        fs::create_dir_all(path.join("1a")).unwrap();
+
+        fs::write(&file_path, "valerian\nsaliou\n").unwrap();
+
+        assert!(StoreFSTPool::verify(&path).is_err());
+
+        assert!(StoreFSTPool::write_backup_manifest(&path).is_ok());
+        assert!(StoreFSTPool::verify(&path).is_ok());
+
+        // Corrupt backed up file
+        fs::write(&file_path, "valerian\nsalio\n").unwrap();
+
+        assert!(StoreFSTPool::verify(&path).is_err());
+
+        // Back up cleanly again
+        assert!(StoreFSTPool::write_backup_manifest(&path).is_ok());
+        assert!(StoreFSTPool::verify(&path).is_ok());
+
+        fs::remove_dir_all(path).unwrap();
+    }
 }
@@ -9,10 +9,17 @@ use core::hash::Hash;
 use hashbrown::HashMap;
 use std::fmt::Display;
 use std::sync::{Arc, RwLock};
+use std::thread;
 use std::time::{Duration, SystemTime};
 
+const STORE_OPEN_RETRY_DELAY_BASE: Duration = Duration::from_millis(10);
+
 pub trait StoreGeneric {
     fn ref_last_used(&self) -> &RwLock<SystemTime>;
+
+    fn has_pending(&self) -> bool {
+        false
+    }
 }
 
 pub trait StoreGenericPool<
@@ -48,13 +55,46 @@ pub trait StoreGenericPool<
         collection_str: &str,
         pool_key: K,
         pool: &Arc<RwLock<HashMap<K, Arc<S>>>>,
+        open_retries: u32,
+        max_open: usize,
     ) -> Result<Arc<S>, ()> {
-        match B::build(pool_key) {
+        let mut build_result = B::build(pool_key);
+
+        // Retry opening store? (opening failures may be transient, eg. if the store is busy; \
+        //   retries are delayed with an exponential backoff)
+        for retry in 0..open_retries {
+            if build_result.is_ok() {
+                break;
+            }
+
+            let retry_delay = STORE_OPEN_RETRY_DELAY_BASE * 2_u32.pow(retry);
+
+            warn!(
+                "failed opening {} store for collection: {} (pool key: {}), retry {}/{} in {}ms",
+                kind,
+                collection_str,
+                pool_key,
+                retry + 1,
+                open_retries,
+                retry_delay.as_millis()
+            );
+
+            thread::sleep(retry_delay);
+
+            build_result = B::build(pool_key);
+        }
+
+        match build_result {
             Ok(store) => {
                 // Acquire a thread-safe store pool reference in write mode
                 let mut store_pool_write = pool.write().unwrap();
                 let store_box = Arc::new(store);
 
+                // Evict least recently used store? (if pool is full)
+                if max_open > 0 && store_pool_write.len() >= max_open {
+                    Self::proceed_evict(kind, &mut store_pool_write);
+                }
+
                 store_pool_write.insert(pool_key, store_box.clone());
 
                 debug!(
@@ -75,6 +115,28 @@ pub trait StoreGenericPool<
         }
     }
 
+    fn proceed_evict(kind: &str, pool: &mut HashMap<K, Arc<S>>) {
+        // Notice: stores that are in use elsewhere, or that hold pending changes are never \
+        //   evicted, as they would otherwise be re-opened while still alive, or lose changes. \
+        //   The pool may thus temporarily grow over its limit.
+        let evicted_key = pool
+            .iter()
+            .filter(|(_, store)| Arc::strong_count(store) == 1 && !store.has_pending())
+            .min_by_key(|(_, store)| *store.ref_last_used().read().unwrap())
+            .map(|(pool_key, _)| *pool_key);
+
+        if let Some(evicted_key) = evicted_key {
+            pool.remove(&evicted_key);
+
+            debug!(
+                "evicted least recently used {} store from full pool (pool key: {})",
+                kind, evicted_key
+            );
+        } else {
+            warn!("could not evict any {} store from full pool", kind);
+        }
+    }
+
     fn proceed_janitor(
         kind: &str,
         pool: &Arc<RwLock<HashMap<K, Arc<S>>>>,
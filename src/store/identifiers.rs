@@ -5,26 +5,50 @@
 // License: Mozilla Public License v2.0 (MPL v2.0)
 
 use std::hash::Hasher;
+use std::time::{SystemTime, UNIX_EPOCH};
 use twox_hash::XxHash32;
 
 pub type StoreObjectIID = u32;
 pub type StoreObjectOID<'a> = &'a str;
 pub type StoreTermHashed = u32;
+pub type StoreGeoPoint = (f32, f32);
+pub type StoreGeoCell = u32;
+pub type StoreObjectWeight = f32;
+pub type StoreObjectTags = Vec<String>;
+pub type StoreObjectVector = Vec<f32>;
+pub type StoreObjectCreated = u64;
+pub type StoreObjectSegment = u8;
+pub type StoreObjectPriority = u8;
+pub type StoreTermPosition = u32;
+pub type StoreObjectPositions = Vec<(StoreTermHashed, Vec<StoreTermPosition>)>;
+pub type StoreUserID<'a> = &'a str;
+pub type StoreUserBoosts = Vec<(StoreTermHashed, f32)>;
 
 pub struct StoreTermHash;
+pub struct StoreGeoCellHash;
+pub struct StoreTimestamp;
 
 pub enum StoreMetaKey {
     IIDIncr,
+    VectorDim,
+    HasWeights,
+    HasPriorities,
 }
 
 pub enum StoreMetaValue {
     IIDIncr(StoreObjectIID),
+    VectorDim(u32),
+    HasWeights(bool),
+    HasPriorities(bool),
 }
 
 impl StoreMetaKey {
     pub fn as_u32(&self) -> u32 {
         match self {
             StoreMetaKey::IIDIncr => 0,
+            StoreMetaKey::VectorDim => 1,
+            StoreMetaKey::HasWeights => 2,
+            StoreMetaKey::HasPriorities => 3,
         }
     }
 }
@@ -39,6 +63,38 @@ impl StoreTermHash {
     }
 }
 
+impl StoreGeoCellHash {
+    pub fn from(point: StoreGeoPoint) -> StoreGeoCell {
+        let (row, column) = Self::to_grid(point);
+
+        Self::from_grid(row, column)
+    }
+
+    pub fn to_grid(point: StoreGeoPoint) -> (u32, u32) {
+        // Locations are bucketed in a grid of cells spanning 1 degree on both axes (ie. about \
+        //   111km at the equator); out-of-bounds coordinates are clamped to the grid edges
+        (
+            (point.0.clamp(-90.0, 90.0) + 90.0).floor() as u32,
+            (point.1.clamp(-180.0, 180.0) + 180.0).floor() as u32,
+        )
+    }
+
+    pub fn from_grid(row: u32, column: u32) -> StoreGeoCell {
+        (row << 16) | column
+    }
+}
+
+impl StoreTimestamp {
+    pub fn now() -> StoreObjectCreated {
+        // Timestamps are stored as seconds since the UNIX epoch (clocks set before the epoch \
+        //   yield the epoch itself)
+        SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|duration| duration.as_secs())
+            .unwrap_or(0)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -46,6 +102,9 @@ mod tests {
     #[test]
     fn it_converts_meta_key_to_u32() {
         assert_eq!(StoreMetaKey::IIDIncr.as_u32(), 0);
+        assert_eq!(StoreMetaKey::VectorDim.as_u32(), 1);
+        assert_eq!(StoreMetaKey::HasWeights.as_u32(), 2);
+        assert_eq!(StoreMetaKey::HasPriorities.as_u32(), 3);
     }
 
     #[test]
@@ -53,4 +112,13 @@ mod tests {
         assert_eq!(StoreTermHash::from("hash:1"), 3637660813);
         assert_eq!(StoreTermHash::from("hash:2"), 3577985381);
     }
+
+    #[test]
+    fn it_hashes_geo_cell() {
+        assert_eq!(StoreGeoCellHash::to_grid((48.85, 2.35)), (138, 182));
+        assert_eq!(StoreGeoCellHash::to_grid((-90.0, -180.0)), (0, 0));
+        assert_eq!(StoreGeoCellHash::from((48.85, 2.35)), 9044150);
+        assert_eq!(StoreGeoCellHash::from((48.1, 2.9)), 9044150);
+        assert_ne!(StoreGeoCellHash::from((49.1, 2.9)), 9044150);
+    }
 }
@@ -11,6 +11,7 @@ use std::io::Cursor;
 use twox_hash::XxHash32;
 
 use super::identifiers::*;
+use crate::APP_CONF;
 
 pub struct StoreKeyerBuilder;
 
@@ -26,10 +27,27 @@ enum StoreKeyerIdx<'a> {
     OIDToIID(StoreObjectOID<'a>),
     IIDToOID(StoreObjectIID),
     IIDToTerms(StoreObjectIID),
+    TermToWord(StoreTermHashed),
+    GeoCellToIIDs(StoreGeoCell),
+    IIDToGeo(StoreObjectIID),
+    IIDToWeight(StoreObjectIID),
+    IIDToText(StoreObjectIID),
+    IIDToTags(StoreObjectIID),
+    UserToBoosts(StoreUserID<'a>),
+    IIDToVector(StoreObjectIID),
+    IIDToCreated(StoreObjectIID),
+    IIDToSegment(StoreObjectIID),
+    IIDToPositions(StoreObjectIID),
+    IIDToPriority(StoreObjectIID),
+    OIDToAlias(StoreObjectOID<'a>),
 }
 
 pub type StoreKeyerKey = [u8; 9];
 pub type StoreKeyerPrefix = [u8; 5];
+pub type StoreKeyerIndex = [u8; 1];
+pub type StoreKeyerAtom = u32;
+
+const STORE_KEYER_ATOM_NULL: StoreKeyerAtom = 0;
 
 impl<'a> StoreKeyerIdx<'a> {
     pub fn to_index(&self) -> u8 {
@@ -39,6 +57,19 @@ impl<'a> StoreKeyerIdx<'a> {
             StoreKeyerIdx::OIDToIID(_) => 2,
             StoreKeyerIdx::IIDToOID(_) => 3,
             StoreKeyerIdx::IIDToTerms(_) => 4,
+            StoreKeyerIdx::TermToWord(_) => 5,
+            StoreKeyerIdx::GeoCellToIIDs(_) => 12,
+            StoreKeyerIdx::IIDToGeo(_) => 13,
+            StoreKeyerIdx::IIDToWeight(_) => 14,
+            StoreKeyerIdx::IIDToText(_) => 15,
+            StoreKeyerIdx::IIDToTags(_) => 16,
+            StoreKeyerIdx::UserToBoosts(_) => 17,
+            StoreKeyerIdx::IIDToVector(_) => 18,
+            StoreKeyerIdx::IIDToCreated(_) => 19,
+            StoreKeyerIdx::IIDToSegment(_) => 20,
+            StoreKeyerIdx::IIDToPositions(_) => 21,
+            StoreKeyerIdx::IIDToPriority(_) => 22,
+            StoreKeyerIdx::OIDToAlias(_) => 23,
         }
     }
 }
@@ -64,19 +95,111 @@ impl StoreKeyerBuilder {
         Self::make(StoreKeyerIdx::IIDToTerms(iid), bucket)
     }
 
+    pub fn term_to_word(bucket: &str, term_hash: StoreTermHashed) -> StoreKeyer {
+        Self::make(StoreKeyerIdx::TermToWord(term_hash), bucket)
+    }
+
+    pub fn geo_cell_to_iids(bucket: &str, cell: StoreGeoCell) -> StoreKeyer {
+        Self::make(StoreKeyerIdx::GeoCellToIIDs(cell), bucket)
+    }
+
+    pub fn iid_to_geo(bucket: &str, iid: StoreObjectIID) -> StoreKeyer {
+        Self::make(StoreKeyerIdx::IIDToGeo(iid), bucket)
+    }
+
+    pub fn iid_to_weight(bucket: &str, iid: StoreObjectIID) -> StoreKeyer {
+        Self::make(StoreKeyerIdx::IIDToWeight(iid), bucket)
+    }
+
+    pub fn iid_to_text(bucket: &str, iid: StoreObjectIID) -> StoreKeyer {
+        Self::make(StoreKeyerIdx::IIDToText(iid), bucket)
+    }
+
+    pub fn iid_to_tags(bucket: &str, iid: StoreObjectIID) -> StoreKeyer {
+        Self::make(StoreKeyerIdx::IIDToTags(iid), bucket)
+    }
+
+    pub fn iid_to_vector(bucket: &str, iid: StoreObjectIID) -> StoreKeyer {
+        Self::make(StoreKeyerIdx::IIDToVector(iid), bucket)
+    }
+
+    pub fn iid_to_created(bucket: &str, iid: StoreObjectIID) -> StoreKeyer {
+        Self::make(StoreKeyerIdx::IIDToCreated(iid), bucket)
+    }
+
+    pub fn iid_to_segment(bucket: &str, iid: StoreObjectIID) -> StoreKeyer {
+        Self::make(StoreKeyerIdx::IIDToSegment(iid), bucket)
+    }
+
+    pub fn iid_to_positions(bucket: &str, iid: StoreObjectIID) -> StoreKeyer {
+        Self::make(StoreKeyerIdx::IIDToPositions(iid), bucket)
+    }
+
+    pub fn iid_to_priority(bucket: &str, iid: StoreObjectIID) -> StoreKeyer {
+        Self::make(StoreKeyerIdx::IIDToPriority(iid), bucket)
+    }
+
+    pub fn oid_to_alias<'a>(bucket: &'a str, oid: StoreObjectOID<'a>) -> StoreKeyer {
+        Self::make(StoreKeyerIdx::OIDToAlias(oid), bucket)
+    }
+
+    pub fn user_to_boosts(user: StoreUserID) -> StoreKeyer {
+        // Notice: user boosts are shared by all buckets in a collection, thus they are keyed \
+        //   under the null bucket atom.
+        Self::make_from_atom(StoreKeyerIdx::UserToBoosts(user), STORE_KEYER_ATOM_NULL)
+    }
+
+    pub fn meta_to_value_from_atom(bucket_atom: StoreKeyerAtom, meta: &StoreMetaKey) -> StoreKeyer {
+        Self::make_from_atom(StoreKeyerIdx::MetaToValue(meta), bucket_atom)
+    }
+
+    pub fn term_to_iids_from_atom(
+        bucket_atom: StoreKeyerAtom,
+        term_hash: StoreTermHashed,
+    ) -> StoreKeyer {
+        Self::make_from_atom(StoreKeyerIdx::TermToIIDs(term_hash), bucket_atom)
+    }
+
+    pub fn oid_to_iid_from_atom(bucket_atom: StoreKeyerAtom, oid: StoreObjectOID) -> StoreKeyer {
+        Self::make_from_atom(StoreKeyerIdx::OIDToIID(oid), bucket_atom)
+    }
+
+    pub fn iid_to_oid_from_atom(bucket_atom: StoreKeyerAtom, iid: StoreObjectIID) -> StoreKeyer {
+        Self::make_from_atom(StoreKeyerIdx::IIDToOID(iid), bucket_atom)
+    }
+
+    pub fn iid_to_terms_from_atom(bucket_atom: StoreKeyerAtom, iid: StoreObjectIID) -> StoreKeyer {
+        Self::make_from_atom(StoreKeyerIdx::IIDToTerms(iid), bucket_atom)
+    }
+
+    pub fn iid_to_vector_from_atom(bucket_atom: StoreKeyerAtom, iid: StoreObjectIID) -> StoreKeyer {
+        Self::make_from_atom(StoreKeyerIdx::IIDToVector(iid), bucket_atom)
+    }
+
+    pub fn term_to_word_from_atom(
+        bucket_atom: StoreKeyerAtom,
+        term_hash: StoreTermHashed,
+    ) -> StoreKeyer {
+        Self::make_from_atom(StoreKeyerIdx::TermToWord(term_hash), bucket_atom)
+    }
+
     fn make<'a>(idx: StoreKeyerIdx<'a>, bucket: &'a str) -> StoreKeyer {
+        Self::make_from_atom(idx, StoreKeyerHasher::to_compact(bucket))
+    }
+
+    fn make_from_atom(idx: StoreKeyerIdx, bucket_atom: StoreKeyerAtom) -> StoreKeyer {
         StoreKeyer {
-            key: Self::build_key(idx, bucket),
+            key: Self::build_key(idx, bucket_atom),
         }
     }
 
-    fn build_key<'a>(idx: StoreKeyerIdx<'a>, bucket: &'a str) -> StoreKeyerKey {
+    fn build_key(idx: StoreKeyerIdx, bucket_atom: StoreKeyerAtom) -> StoreKeyerKey {
         // Key format: [idx<1B> | bucket<4B> | route<4B>]
 
         // Encode key bucket + key route from u32 to array of u8 (ie. binary)
         let (mut bucket_encoded, mut route_encoded) = ([0; 4], [0; 4]);
 
-        LittleEndian::write_u32(&mut bucket_encoded, StoreKeyerHasher::to_compact(bucket));
+        LittleEndian::write_u32(&mut bucket_encoded, bucket_atom);
         LittleEndian::write_u32(&mut route_encoded, Self::route_to_compact(&idx));
 
         // Generate final binary key
@@ -103,6 +226,19 @@ impl StoreKeyerBuilder {
             StoreKeyerIdx::OIDToIID(route) => StoreKeyerHasher::to_compact(route),
             StoreKeyerIdx::IIDToOID(route) => *route,
             StoreKeyerIdx::IIDToTerms(route) => *route,
+            StoreKeyerIdx::TermToWord(route) => *route,
+            StoreKeyerIdx::GeoCellToIIDs(route) => *route,
+            StoreKeyerIdx::IIDToGeo(route) => *route,
+            StoreKeyerIdx::IIDToWeight(route) => *route,
+            StoreKeyerIdx::IIDToText(route) => *route,
+            StoreKeyerIdx::IIDToTags(route) => *route,
+            StoreKeyerIdx::UserToBoosts(route) => StoreKeyerHasher::to_compact(route),
+            StoreKeyerIdx::IIDToVector(route) => *route,
+            StoreKeyerIdx::IIDToCreated(route) => *route,
+            StoreKeyerIdx::IIDToSegment(route) => *route,
+            StoreKeyerIdx::IIDToPositions(route) => *route,
+            StoreKeyerIdx::IIDToPriority(route) => *route,
+            StoreKeyerIdx::OIDToAlias(route) => StoreKeyerHasher::to_compact(route),
         }
     }
 }
@@ -112,6 +248,12 @@ impl StoreKeyer {
         self.key
     }
 
+    pub fn as_index(&self) -> StoreKeyerIndex {
+        // Index format: [idx<1B>] (this matches keys from all buckets)
+
+        [self.key[0]]
+    }
+
     pub fn as_prefix(&self) -> StoreKeyerPrefix {
         // Prefix format: [idx<1B> | bucket<4B>]
 
@@ -128,7 +270,9 @@ impl StoreKeyer {
 impl StoreKeyerHasher {
     #![allow(clippy::wrong_self_convention)]
     pub fn to_compact(part: &str) -> u32 {
-        let mut hasher = XxHash32::with_seed(0);
+        // Important: the seed is shared by all hashed atoms (FST paths and KV keys), so that \
+        //   KV bucket atoms always match the FST bucket atoms they refer to.
+        let mut hasher = XxHash32::with_seed(APP_CONF.store.fst.graph.bucket_hash_seed);
 
         hasher.write(part.as_bytes());
         hasher.finish() as u32
@@ -208,6 +352,136 @@ mod tests {
         );
     }
 
+    #[test]
+    fn it_keys_term_to_word() {
+        assert_eq!(
+            StoreKeyerBuilder::term_to_word("bucket:5", 1).as_bytes(),
+            [5, 137, 142, 73, 67, 1, 0, 0, 0]
+        );
+    }
+
+    #[test]
+    fn it_keys_geo_cell_to_iids() {
+        assert_eq!(
+            StoreKeyerBuilder::geo_cell_to_iids("bucket:5", 9044150).as_bytes(),
+            [12, 137, 142, 73, 67, 182, 0, 138, 0]
+        );
+    }
+
+    #[test]
+    fn it_keys_iid_to_geo() {
+        assert_eq!(
+            StoreKeyerBuilder::iid_to_geo("bucket:5", 1).as_bytes(),
+            [13, 137, 142, 73, 67, 1, 0, 0, 0]
+        );
+    }
+
+    #[test]
+    fn it_keys_iid_to_weight() {
+        assert_eq!(
+            StoreKeyerBuilder::iid_to_weight("bucket:5", 1).as_bytes(),
+            [14, 137, 142, 73, 67, 1, 0, 0, 0]
+        );
+    }
+
+    #[test]
+    fn it_keys_iid_to_text() {
+        assert_eq!(
+            StoreKeyerBuilder::iid_to_text("bucket:5", 1).as_bytes(),
+            [15, 137, 142, 73, 67, 1, 0, 0, 0]
+        );
+    }
+
+    #[test]
+    fn it_keys_iid_to_tags() {
+        assert_eq!(
+            StoreKeyerBuilder::iid_to_tags("bucket:5", 1).as_bytes(),
+            [16, 137, 142, 73, 67, 1, 0, 0, 0]
+        );
+    }
+
+    #[test]
+    fn it_keys_iid_to_vector() {
+        assert_eq!(
+            StoreKeyerBuilder::iid_to_vector("bucket:5", 1).as_bytes(),
+            [18, 137, 142, 73, 67, 1, 0, 0, 0]
+        );
+    }
+
+    #[test]
+    fn it_keys_iid_to_created() {
+        assert_eq!(
+            StoreKeyerBuilder::iid_to_created("bucket:5", 1).as_bytes(),
+            [19, 137, 142, 73, 67, 1, 0, 0, 0]
+        );
+    }
+
+    #[test]
+    fn it_keys_iid_to_segment() {
+        assert_eq!(
+            StoreKeyerBuilder::iid_to_segment("bucket:5", 1).as_bytes(),
+            [20, 137, 142, 73, 67, 1, 0, 0, 0]
+        );
+    }
+
+    #[test]
+    fn it_keys_iid_to_positions() {
+        assert_eq!(
+            StoreKeyerBuilder::iid_to_positions("bucket:5", 1).as_bytes(),
+            [21, 137, 142, 73, 67, 1, 0, 0, 0]
+        );
+    }
+
+    #[test]
+    fn it_keys_iid_to_priority() {
+        assert_eq!(
+            StoreKeyerBuilder::iid_to_priority("bucket:5", 1).as_bytes(),
+            [22, 137, 142, 73, 67, 1, 0, 0, 0]
+        );
+    }
+
+    #[test]
+    fn it_keys_oid_to_alias() {
+        assert_eq!(
+            StoreKeyerBuilder::oid_to_alias("bucket:5", "conversation:6ab0").as_prefix(),
+            [23, 137, 142, 73, 67]
+        );
+        assert_eq!(
+            StoreKeyerBuilder::oid_to_alias("bucket:5", "conversation:6ab0").as_bytes()[5..],
+            StoreKeyerHasher::to_compact("conversation:6ab0").to_le_bytes()
+        );
+    }
+
+    #[test]
+    fn it_keys_user_to_boosts() {
+        assert_eq!(
+            StoreKeyerBuilder::user_to_boosts("user:1").as_prefix(),
+            [17, 0, 0, 0, 0]
+        );
+        assert_eq!(
+            StoreKeyerBuilder::user_to_boosts("user:1").as_bytes()[5..],
+            StoreKeyerHasher::to_compact("user:1").to_le_bytes()
+        );
+    }
+
+    #[test]
+    fn it_keys_from_atom() {
+        let bucket_atom = StoreKeyerHasher::to_compact("bucket:5");
+
+        assert_eq!(
+            StoreKeyerBuilder::term_to_iids_from_atom(bucket_atom, 772137347).as_bytes(),
+            StoreKeyerBuilder::term_to_iids("bucket:5", 772137347).as_bytes()
+        );
+        assert_eq!(
+            StoreKeyerBuilder::iid_to_terms_from_atom(bucket_atom, 20).as_bytes(),
+            StoreKeyerBuilder::iid_to_terms("bucket:5", 20).as_bytes()
+        );
+        assert_eq!(
+            StoreKeyerBuilder::term_to_iids_from_atom(bucket_atom, 0).as_index(),
+            [1]
+        );
+    }
+
     #[test]
     fn it_hashes_compact() {
         assert_eq!(StoreKeyerHasher::to_compact("key:1"), 3370353088);
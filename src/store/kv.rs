@@ -5,15 +5,15 @@
 // License: Mozilla Public License v2.0 (MPL v2.0)
 
 use byteorder::{ByteOrder, LittleEndian, ReadBytesExt};
-use hashbrown::HashMap;
+use hashbrown::{HashMap, HashSet};
 use radix::RadixNum;
 use rocksdb::backup::{
     BackupEngine as DBBackupEngine, BackupEngineOptions as DBBackupEngineOptions,
     RestoreOptions as DBRestoreOptions,
 };
 use rocksdb::{
-    DBCompactionStyle, DBCompressionType, Env as DBEnv, Error as DBError, FlushOptions,
-    Options as DBOptions, WriteBatch, WriteOptions, DB,
+    DBCompactionStyle, DBCompressionType, Direction, Env as DBEnv, Error as DBError, FlushOptions,
+    IteratorMode, Options as DBOptions, WriteBatch, WriteOptions, DB,
 };
 use std::fmt;
 use std::fs;
@@ -22,7 +22,7 @@ use std::path::{Path, PathBuf};
 use std::str;
 use std::sync::{Arc, Mutex, RwLock};
 use std::thread;
-use std::time::{Duration, SystemTime};
+use std::time::{Duration, Instant, SystemTime};
 use std::vec::Drain;
 
 use super::generic::{
@@ -30,7 +30,9 @@ use super::generic::{
 };
 use super::identifiers::*;
 use super::item::StoreItemPart;
-use super::keyer::{StoreKeyerBuilder, StoreKeyerHasher, StoreKeyerKey, StoreKeyerPrefix};
+use super::keyer::{
+    StoreKeyerAtom, StoreKeyerBuilder, StoreKeyerHasher, StoreKeyerKey, StoreKeyerPrefix,
+};
 use crate::APP_CONF;
 
 pub struct StoreKVPool;
@@ -50,6 +52,17 @@ pub struct StoreKVAction<'a> {
     bucket: StoreItemPart<'a>,
 }
 
+pub struct StoreKVMisc;
+
+#[derive(Default)]
+pub struct StoreKVPoolStats {
+    pub collections: usize,
+    pub objects: u64,
+    pub keys: u64,
+    pub disk_bytes: u64,
+    pub memory_bytes: u64,
+}
+
 #[derive(PartialEq, Eq, Hash, Clone, Copy)]
 pub struct StoreKVKey {
     collection_hash: StoreKVAtom,
@@ -63,8 +76,10 @@ pub enum StoreKVAcquireMode {
 
 type StoreKVAtom = u32;
 type StoreKVBox = Arc<StoreKV>;
+type StoreKVBucketWords = Vec<(StoreTermHashed, Option<String>)>;
 
 const ATOM_HASH_RADIX: usize = 16;
+const STORE_COPY_BATCH_SIZE: usize = 10000;
 
 lazy_static! {
     pub static ref STORE_ACCESS_LOCK: Arc<RwLock<bool>> = Arc::new(RwLock::new(false));
@@ -79,6 +94,86 @@ impl StoreKVPool {
         STORE_POOL.read().unwrap().len()
     }
 
+    pub fn stats() -> StoreKVPoolStats {
+        // Notice: stores are referenced out of the pool before being scanned, so that the pool \
+        //   lock does not get held while RocksDB properties are read.
+        let stores: Vec<StoreKVBox> = STORE_POOL.read().unwrap().values().cloned().collect();
+
+        let mut stats = StoreKVPoolStats {
+            collections: stores.len(),
+            ..Default::default()
+        };
+
+        for store in stores {
+            stats.objects += store.count_objects();
+            stats.keys += store.property_int("rocksdb.estimate-num-keys");
+            stats.disk_bytes += store.property_int("rocksdb.total-sst-files-size");
+            stats.memory_bytes += store.property_int("rocksdb.cur-size-all-mem-tables")
+                + store.property_int("rocksdb.estimate-table-readers-mem");
+        }
+
+        stats
+    }
+
+    pub fn compaction_stats(collection: Option<&str>) -> Vec<(StoreKVKey, String)> {
+        // Notice: stores are referenced out of the pool before being scanned, so that the pool \
+        //   lock does not get held while RocksDB properties are read.
+        let stores: Vec<(StoreKVKey, StoreKVBox)> = {
+            let store_pool_read = STORE_POOL.read().unwrap();
+
+            if let Some(collection) = collection {
+                let pool_key = StoreKVKey::from_str(collection);
+
+                store_pool_read
+                    .get(&pool_key)
+                    .map(|store| vec![(pool_key, store.clone())])
+                    .unwrap_or_default()
+            } else {
+                store_pool_read
+                    .iter()
+                    .map(|(pool_key, store)| (*pool_key, store.clone()))
+                    .collect()
+            }
+        };
+
+        stores
+            .into_iter()
+            .filter_map(|(pool_key, store)| {
+                store
+                    .property_string("rocksdb.stats")
+                    .map(|stats| (pool_key, stats))
+            })
+            .collect()
+    }
+
+    pub fn compact<F: Fn(&StoreKVKey) -> bool>(should_compact: F) -> usize {
+        // Notice: stores are referenced out of the pool before being compacted, so that the pool \
+        //   lock does not get held during compactions, which may take a long time.
+        let stores: Vec<(StoreKVKey, StoreKVBox)> = STORE_POOL
+            .read()
+            .unwrap()
+            .iter()
+            .filter(|(pool_key, _)| should_compact(pool_key))
+            .map(|(pool_key, store)| (*pool_key, store.clone()))
+            .collect();
+
+        for (pool_key, store) in &stores {
+            info!("kv key: {} compacting", pool_key);
+
+            let compact_start = Instant::now();
+
+            store.compact();
+
+            info!(
+                "kv key: {} compacted (took {}ms)",
+                pool_key,
+                compact_start.elapsed().as_millis()
+            );
+        }
+
+        stores.len()
+    }
+
     pub fn acquire<'a, T: Into<&'a str>>(
         mode: StoreKVAcquireMode,
         collection: T,
@@ -116,13 +211,36 @@ impl StoreKVPool {
             //   the database does not exist yet on disk and we are just looking to read data from \
             //   it)
             if can_open_db {
-                Self::proceed_acquire_open("kv", collection_str, pool_key, &*STORE_POOL).map(Some)
+                Self::proceed_acquire_open(
+                    "kv",
+                    collection_str,
+                    pool_key,
+                    &*STORE_POOL,
+                    APP_CONF.store.kv.pool.open_retries,
+                    0,
+                )
+                .map(Some)
             } else {
                 Ok(None)
             }
         }
     }
 
+    pub fn drain(collection_str: &str) -> Result<(), ()> {
+        let pool_key = StoreKVKey::from_str(collection_str);
+
+        // Flush database to disk before closing it? (if opened)
+        if let Some(store) = STORE_POOL.read().unwrap().get(&pool_key) {
+            debug!("kv key: {} flushing before drain", pool_key);
+
+            store.flush().or(Err(()))?;
+        }
+
+        StoreKVBuilder::close(pool_key.collection_hash);
+
+        Ok(())
+    }
+
     pub fn janitor() {
         Self::proceed_janitor(
             "kv",
@@ -142,6 +260,47 @@ impl StoreKVPool {
         Self::dump_action("backup", &*APP_CONF.store.kv.path, path, &Self::backup_item)
     }
 
+    pub fn backup_incremental(path: &Path) -> Result<(), io::Error> {
+        debug!("incrementally backing up all kv stores to path: {:?}", path);
+
+        // Create backup directory (full path)
+        fs::create_dir_all(path)?;
+
+        // Proceed dump action (incremental backup)
+        Self::dump_action(
+            "incremental backup",
+            &APP_CONF.store.kv.path,
+            path,
+            &Self::backup_incremental_item,
+        )
+    }
+
+    pub fn backup_prune(path: &Path, keep_count: u32) -> Result<(), io::Error> {
+        debug!(
+            "pruning all kv store backups in path: {:?} (keep: {})",
+            path, keep_count
+        );
+
+        // Proceed dump action (prune, in place)
+        Self::dump_action(
+            "backup prune",
+            path,
+            path,
+            &|_, backup_path, collection_name| {
+                Self::backup_prune_item(backup_path, collection_name, keep_count)
+            },
+        )
+    }
+
+    pub fn verify(path: &Path) -> Result<(), io::Error> {
+        debug!("verifying all kv store backups in path: {:?}", path);
+
+        // Proceed dump action (verify, in place)
+        Self::dump_action("verify", path, path, &|_, backup_path, collection_name| {
+            Self::verify_item(backup_path, collection_name)
+        })
+    }
+
     pub fn restore(path: &Path) -> Result<(), io::Error> {
         debug!("restoring all kv stores from path: {:?}", path);
 
@@ -310,14 +469,7 @@ impl StoreKVPool {
                     .map_err(|_| io_error!("database open failure"))?;
 
                 // Initialize KV database backup engine
-                let kv_backup_options = DBBackupEngineOptions::new(&kv_backup_path)
-                    .map_err(|_| io_error!("backup engine options acquire failure"))?;
-                let kv_backup_environment = DBEnv::new()
-                    .map_err(|_| io_error!("backup engine environment acquire failure"))?;
-
-                let mut kv_backup_engine =
-                    DBBackupEngine::open(&kv_backup_options, &kv_backup_environment)
-                        .map_err(|_| io_error!("backup engine failure"))?;
+                let mut kv_backup_engine = Self::open_backup_engine(&kv_backup_path)?;
 
                 // Proceed actual KV database backup
                 kv_backup_engine
@@ -334,6 +486,122 @@ impl StoreKVPool {
         Ok(())
     }
 
+    fn backup_incremental_item(
+        backup_path: &Path,
+        _origin_path: &Path,
+        collection_name: &str,
+    ) -> Result<(), io::Error> {
+        // Acquire access lock (in blocking write mode), and reference it in context
+        // Notice: this prevents store to be acquired from any context
+        let _access = STORE_ACCESS_LOCK.write().unwrap();
+
+        // Generate path to KV backup
+        let kv_backup_path = backup_path.join(collection_name);
+
+        debug!(
+            "kv collection: {} incrementally backing up to path: {:?}",
+            collection_name, kv_backup_path
+        );
+
+        // Create backup folder for collection (if it does not exist yet)
+        // Notice: any previously-existing KV backup is kept, as the backup engine only copies \
+        //   files that are not already present in the backup folder, and stacks a new backup \
+        //   generation on top of the previous ones.
+        fs::create_dir_all(&kv_backup_path)?;
+
+        // Convert names to hashes (as names are hashes encoded as base-16 strings, but we need \
+        //   them as proper integers)
+        if let Ok(collection_radix) = RadixNum::from_str(collection_name, ATOM_HASH_RADIX) {
+            if let Ok(collection_hash) = collection_radix.as_decimal() {
+                let origin_kv = StoreKVBuilder::open(collection_hash as StoreKVAtom)
+                    .map_err(|_| io_error!("database open failure"))?;
+
+                // Initialize KV database backup engine
+                let mut kv_backup_engine = Self::open_backup_engine(&kv_backup_path)?;
+
+                // Proceed actual KV database backup (flush memtables first, so that the backup \
+                //   does not need to rely on write-ahead logs)
+                kv_backup_engine
+                    .create_new_backup_flush(&origin_kv, true)
+                    .map_err(|_| io_error!("database incremental backup failure"))?;
+
+                info!(
+                    "kv collection: {} incrementally backed up to path: {:?}",
+                    collection_name, kv_backup_path
+                );
+            }
+        }
+
+        Ok(())
+    }
+
+    fn backup_prune_item(
+        backup_path: &Path,
+        collection_name: &str,
+        keep_count: u32,
+    ) -> Result<(), io::Error> {
+        debug!(
+            "kv collection: {} pruning backups in path: {:?}",
+            collection_name, backup_path
+        );
+
+        // Initialize KV database backup engine, and purge oldest backup generations
+        let mut kv_backup_engine = Self::open_backup_engine(backup_path)?;
+
+        kv_backup_engine
+            .purge_old_backups(keep_count as usize)
+            .map_err(|_| io_error!("database backup prune failure"))?;
+
+        info!(
+            "kv collection: {} pruned backups in path: {:?} (kept: {})",
+            collection_name, backup_path, keep_count
+        );
+
+        Ok(())
+    }
+
+    fn verify_item(backup_path: &Path, collection_name: &str) -> Result<(), io::Error> {
+        debug!(
+            "kv collection: {} verifying backups in path: {:?}",
+            collection_name, backup_path
+        );
+
+        // Initialize KV database backup engine
+        let kv_backup_engine = Self::open_backup_engine(backup_path)?;
+
+        let kv_backups = kv_backup_engine.get_backup_info();
+
+        if kv_backups.is_empty() {
+            error!(
+                "kv collection: {} has no backup in path: {:?}",
+                collection_name, backup_path
+            );
+
+            return Err(io_error!("database backup not found"));
+        }
+
+        // Verify all backup generations (the error names the corrupt file, if any)
+        for kv_backup in kv_backups {
+            kv_backup_engine
+                .verify_backup(kv_backup.backup_id)
+                .map_err(|err| {
+                    error!(
+                        "kv collection: {} backup: {} is corrupt in path: {:?} ({})",
+                        collection_name, kv_backup.backup_id, backup_path, err
+                    );
+
+                    io_error!("database backup verify failure")
+                })?;
+        }
+
+        info!(
+            "kv collection: {} verified backups in path: {:?}",
+            collection_name, backup_path
+        );
+
+        Ok(())
+    }
+
     fn restore_item(
         _backup_path: &Path,
         origin_path: &Path,
@@ -367,14 +635,7 @@ impl StoreKVPool {
                 fs::create_dir_all(&kv_path)?;
 
                 // Initialize KV database backup engine
-                let kv_backup_options = DBBackupEngineOptions::new(&origin_path)
-                    .map_err(|_| io_error!("backup engine options acquire failure"))?;
-                let kv_backup_environment = DBEnv::new()
-                    .map_err(|_| io_error!("backup engine environment acquire failure"))?;
-
-                let mut kv_backup_engine =
-                    DBBackupEngine::open(&kv_backup_options, &kv_backup_environment)
-                        .map_err(|_| io_error!("backup engine failure"))?;
+                let mut kv_backup_engine = Self::open_backup_engine(origin_path)?;
 
                 kv_backup_engine
                     .restore_from_latest_backup(&kv_path, &kv_path, &DBRestoreOptions::default())
@@ -389,6 +650,16 @@ impl StoreKVPool {
 
         Ok(())
     }
+
+    fn open_backup_engine(backup_path: &Path) -> Result<DBBackupEngine, io::Error> {
+        let kv_backup_options = DBBackupEngineOptions::new(backup_path)
+            .map_err(|_| io_error!("backup engine options acquire failure"))?;
+        let kv_backup_environment =
+            DBEnv::new().map_err(|_| io_error!("backup engine environment acquire failure"))?;
+
+        DBBackupEngine::open(&kv_backup_options, &kv_backup_environment)
+            .map_err(|_| io_error!("backup engine failure"))
+    }
 }
 
 impl StoreGenericPool<StoreKVKey, StoreKV, StoreKVBuilder> for StoreKVPool {}
@@ -506,6 +777,52 @@ impl StoreKV {
         self.do_write(batch)
     }
 
+    pub fn scan<'b>(
+        &'b self,
+        prefix: &'b [u8],
+    ) -> impl Iterator<Item = (Box<[u8]>, Box<[u8]>)> + 'b {
+        // Notice: iteration stops on the first read error, the caller should thus treat scanned \
+        //   keys as a best-effort listing.
+        self.database
+            .iterator(IteratorMode::From(prefix, Direction::Forward))
+            .map_while(|entry| entry.ok())
+            .take_while(move |(key, _)| key.starts_with(prefix))
+    }
+
+    pub fn count_objects(&self) -> u64 {
+        // Sum IID increments from all buckets (as the IID increment holds the last allocated IID, \
+        //   this counts objects that were ever pushed, not accounting for compactions)
+        let meta_key = StoreMetaKey::IIDIncr;
+        let key_index = StoreKeyerBuilder::meta_to_value_from_atom(0, &meta_key).as_index();
+
+        self.scan(&key_index)
+            .filter_map(|(key, value)| match StoreKVMisc::decode_key_atoms(&key) {
+                Some((_, route)) if route == meta_key.as_u32() => str::from_utf8(&value)
+                    .ok()
+                    .and_then(|value| value.parse::<StoreObjectIID>().ok()),
+                _ => None,
+            })
+            .map(|iid_incr| iid_incr as u64 + 1)
+            .sum()
+    }
+
+    pub fn property_int(&self, name: &str) -> u64 {
+        self.database
+            .property_int_value(name)
+            .ok()
+            .flatten()
+            .unwrap_or(0)
+    }
+
+    pub fn compact(&self) {
+        // Compact the whole key range (this blocks until compaction is done)
+        self.database.compact_range(None::<&[u8]>, None::<&[u8]>)
+    }
+
+    pub fn property_string(&self, name: &str) -> Option<String> {
+        self.database.property_value(name).ok().flatten()
+    }
+
     fn flush(&self) -> Result<(), DBError> {
         // Generate flush options
         let mut flush_options = FlushOptions::default();
@@ -618,6 +935,21 @@ impl<'a> StoreKVAction<'a> {
                                 .ok()
                                 .map(StoreMetaValue::IIDIncr)
                                 .or(None),
+                            StoreMetaKey::VectorDim => value
+                                .parse::<u32>()
+                                .ok()
+                                .map(StoreMetaValue::VectorDim)
+                                .or(None),
+                            StoreMetaKey::HasWeights => value
+                                .parse::<bool>()
+                                .ok()
+                                .map(StoreMetaValue::HasWeights)
+                                .or(None),
+                            StoreMetaKey::HasPriorities => value
+                                .parse::<bool>()
+                                .ok()
+                                .map(StoreMetaValue::HasPriorities)
+                                .or(None),
                         }
                     } else {
                         None
@@ -650,6 +982,9 @@ impl<'a> StoreKVAction<'a> {
 
             let value_string = match value {
                 StoreMetaValue::IIDIncr(iid_incr) => iid_incr.to_string(),
+                StoreMetaValue::VectorDim(vector_dim) => vector_dim.to_string(),
+                StoreMetaValue::HasWeights(has_weights) => has_weights.to_string(),
+                StoreMetaValue::HasPriorities(has_priorities) => has_priorities.to_string(),
             };
 
             store
@@ -944,227 +1279,1521 @@ impl<'a> StoreKVAction<'a> {
         }
     }
 
-    pub fn batch_flush_bucket(
+    /// Geo-Cell-to-IIDs mapper
+    ///
+    /// [IDX=12] ((cell)) ~> [((iid))]
+    pub fn get_geo_cell_to_iids(
         &self,
-        iid: StoreObjectIID,
-        oid: StoreObjectOID<'a>,
-        iid_terms_hashed: &[StoreTermHashed],
-    ) -> Result<u32, ()> {
-        let mut count = 0;
-
-        debug!(
-            "store batch flush bucket: {} with hashed terms: {:?}",
-            iid, iid_terms_hashed
-        );
-
-        // Delete OID <> IID association
-        match (
-            self.delete_oid_to_iid(oid),
-            self.delete_iid_to_oid(iid),
-            self.delete_iid_to_terms(iid),
-        ) {
-            (Ok(_), Ok(_), Ok(_)) => {
-                // Delete IID from each associated term
-                for iid_term in iid_terms_hashed {
-                    if let Ok(Some(mut iid_term_iids)) = self.get_term_to_iids(*iid_term) {
-                        if iid_term_iids.contains(&iid) {
-                            count += 1;
-
-                            // Remove IID from list of IIDs
-                            iid_term_iids.retain(|cur_iid| cur_iid != &iid);
-                        }
-
-                        let is_ok = if iid_term_iids.is_empty() {
-                            self.delete_term_to_iids(*iid_term).is_ok()
-                        } else {
-                            self.set_term_to_iids(*iid_term, &iid_term_iids).is_ok()
-                        };
+        cell: StoreGeoCell,
+    ) -> Result<Option<Vec<StoreObjectIID>>, ()> {
+        if let Some(ref store) = self.store {
+            let store_key = StoreKeyerBuilder::geo_cell_to_iids(self.bucket.as_str(), cell);
 
-                        if !is_ok {
-                            return Err(());
-                        }
-                    }
-                }
+            debug!("store get geo-cell-to-iids: {}", store_key);
 
-                Ok(count)
+            match store.get(&store_key.as_bytes()) {
+                Ok(Some(value)) => Self::decode_u32_list(&value).map(Some),
+                Ok(None) => Ok(None),
+                Err(_) => Err(()),
             }
-            _ => Err(()),
+        } else {
+            Ok(None)
         }
     }
 
-    pub fn batch_truncate_object(
+    pub fn set_geo_cell_to_iids(
         &self,
-        term_hashed: StoreTermHashed,
-        term_iids_drain: Drain<StoreObjectIID>,
-    ) -> Result<u32, ()> {
-        let mut count = 0;
+        cell: StoreGeoCell,
+        iids: &[StoreObjectIID],
+    ) -> Result<(), ()> {
+        if let Some(ref store) = self.store {
+            let store_key = StoreKeyerBuilder::geo_cell_to_iids(self.bucket.as_str(), cell);
 
-        for term_iid_drain in term_iids_drain {
-            debug!("store batch truncate object iid: {}", term_iid_drain);
+            debug!("store set geo-cell-to-iids: {}", store_key);
 
-            // Nuke term in IID to Terms list
-            if let Ok(Some(mut term_iid_drain_terms)) = self.get_iid_to_terms(term_iid_drain) {
-                count += 1;
+            store
+                .put(&store_key.as_bytes(), &Self::encode_u32_list(iids))
+                .or(Err(()))
+        } else {
+            Err(())
+        }
+    }
 
-                term_iid_drain_terms.retain(|cur_term| cur_term != &term_hashed);
+    pub fn delete_geo_cell_to_iids(&self, cell: StoreGeoCell) -> Result<(), ()> {
+        if let Some(ref store) = self.store {
+            let store_key = StoreKeyerBuilder::geo_cell_to_iids(self.bucket.as_str(), cell);
 
-                // IID to Terms list is empty? Flush whole object.
-                if term_iid_drain_terms.is_empty() {
-                    // Acquire OID for this drained IID
-                    if let Ok(Some(term_iid_drain_oid)) = self.get_iid_to_oid(term_iid_drain) {
-                        if self
-                            .batch_flush_bucket(term_iid_drain, &term_iid_drain_oid, &Vec::new())
-                            .is_err()
-                        {
-                            error!(
-                                "failed executing store batch truncate object batch-flush-bucket"
-                            );
-                        }
-                    } else {
-                        error!("failed getting store batch truncate object iid-to-oid");
-                    }
-                } else {
-                    // Update IID to Terms list
-                    if self
-                        .set_iid_to_terms(term_iid_drain, &term_iid_drain_terms)
-                        .is_err()
-                    {
-                        error!("failed setting store batch truncate object iid-to-terms");
-                    }
-                }
-            }
-        }
+            debug!("store delete geo-cell-to-iids: {}", store_key);
 
-        Ok(count)
+            store.delete(&store_key.as_bytes()).or(Err(()))
+        } else {
+            Err(())
+        }
     }
 
-    pub fn batch_erase_bucket(&self) -> Result<u32, ()> {
+    /// IID-to-Geo mapper
+    ///
+    /// [IDX=13] ((iid)) ~> ((latitude, longitude))
+    pub fn get_iid_to_geo(&self, iid: StoreObjectIID) -> Result<Option<StoreGeoPoint>, ()> {
         if let Some(ref store) = self.store {
-            // Generate all key prefix values (with dummy post-prefix values; we dont care)
-            let (k_meta_to_value, k_term_to_iids, k_oid_to_iid, k_iid_to_oid, k_iid_to_terms) = (
-                StoreKeyerBuilder::meta_to_value(self.bucket.as_str(), &StoreMetaKey::IIDIncr),
-                StoreKeyerBuilder::term_to_iids(self.bucket.as_str(), 0),
+            let store_key = StoreKeyerBuilder::iid_to_geo(self.bucket.as_str(), iid);
+
+            debug!("store get iid-to-geo: {}", store_key);
+
+            match store.get(&store_key.as_bytes()) {
+                Ok(Some(value)) => Self::decode_geo_point(&value).map(Some),
+                Ok(None) => Ok(None),
+                Err(_) => Err(()),
+            }
+        } else {
+            Ok(None)
+        }
+    }
+
+    pub fn set_iid_to_geo(&self, iid: StoreObjectIID, point: StoreGeoPoint) -> Result<(), ()> {
+        if let Some(ref store) = self.store {
+            let store_key = StoreKeyerBuilder::iid_to_geo(self.bucket.as_str(), iid);
+
+            debug!("store set iid-to-geo: {}", store_key);
+
+            store
+                .put(&store_key.as_bytes(), &Self::encode_geo_point(point))
+                .or(Err(()))
+        } else {
+            Err(())
+        }
+    }
+
+    pub fn delete_iid_to_geo(&self, iid: StoreObjectIID) -> Result<(), ()> {
+        if let Some(ref store) = self.store {
+            let store_key = StoreKeyerBuilder::iid_to_geo(self.bucket.as_str(), iid);
+
+            debug!("store delete iid-to-geo: {}", store_key);
+
+            store.delete(&store_key.as_bytes()).or(Err(()))
+        } else {
+            Err(())
+        }
+    }
+
+    /// IID-to-Weight mapper
+    ///
+    /// [IDX=14] ((iid)) ~> ((weight))
+    pub fn get_iid_to_weight(&self, iid: StoreObjectIID) -> Result<Option<StoreObjectWeight>, ()> {
+        if let Some(ref store) = self.store {
+            let store_key = StoreKeyerBuilder::iid_to_weight(self.bucket.as_str(), iid);
+
+            debug!("store get iid-to-weight: {}", store_key);
+
+            match store.get(&store_key.as_bytes()) {
+                Ok(Some(value)) => Self::decode_weight(&value).map(Some),
+                Ok(None) => Ok(None),
+                Err(_) => Err(()),
+            }
+        } else {
+            Ok(None)
+        }
+    }
+
+    pub fn set_iid_to_weight(
+        &self,
+        iid: StoreObjectIID,
+        weight: StoreObjectWeight,
+    ) -> Result<(), ()> {
+        if let Some(ref store) = self.store {
+            let store_key = StoreKeyerBuilder::iid_to_weight(self.bucket.as_str(), iid);
+
+            debug!("store set iid-to-weight: {}", store_key);
+
+            store
+                .put(&store_key.as_bytes(), &Self::encode_weight(weight))
+                .or(Err(()))
+        } else {
+            Err(())
+        }
+    }
+
+    pub fn delete_iid_to_weight(&self, iid: StoreObjectIID) -> Result<(), ()> {
+        if let Some(ref store) = self.store {
+            let store_key = StoreKeyerBuilder::iid_to_weight(self.bucket.as_str(), iid);
+
+            debug!("store delete iid-to-weight: {}", store_key);
+
+            store.delete(&store_key.as_bytes()).or(Err(()))
+        } else {
+            Err(())
+        }
+    }
+
+    /// IID-to-Text mapper
+    ///
+    /// [IDX=15] ((iid)) ~> ((text))
+    pub fn get_iid_to_text(&self, iid: StoreObjectIID) -> Result<Option<String>, ()> {
+        if let Some(ref store) = self.store {
+            let store_key = StoreKeyerBuilder::iid_to_text(self.bucket.as_str(), iid);
+
+            debug!("store get iid-to-text: {}", store_key);
+
+            match store.get(&store_key.as_bytes()) {
+                Ok(Some(value)) => Ok(str::from_utf8(&value).ok().map(|value| value.to_string())),
+                Ok(None) => Ok(None),
+                Err(_) => Err(()),
+            }
+        } else {
+            Ok(None)
+        }
+    }
+
+    pub fn set_iid_to_text(&self, iid: StoreObjectIID, text: &str) -> Result<(), ()> {
+        if let Some(ref store) = self.store {
+            let store_key = StoreKeyerBuilder::iid_to_text(self.bucket.as_str(), iid);
+
+            debug!("store set iid-to-text: {}", store_key);
+
+            store
+                .put(&store_key.as_bytes(), text.as_bytes())
+                .or(Err(()))
+        } else {
+            Err(())
+        }
+    }
+
+    pub fn delete_iid_to_text(&self, iid: StoreObjectIID) -> Result<(), ()> {
+        if let Some(ref store) = self.store {
+            let store_key = StoreKeyerBuilder::iid_to_text(self.bucket.as_str(), iid);
+
+            debug!("store delete iid-to-text: {}", store_key);
+
+            store.delete(&store_key.as_bytes()).or(Err(()))
+        } else {
+            Err(())
+        }
+    }
+
+    /// IID-to-Tags mapper
+    ///
+    /// [IDX=16] ((iid)) ~> [((tag))]
+    pub fn get_iid_to_tags(&self, iid: StoreObjectIID) -> Result<Option<StoreObjectTags>, ()> {
+        if let Some(ref store) = self.store {
+            let store_key = StoreKeyerBuilder::iid_to_tags(self.bucket.as_str(), iid);
+
+            debug!("store get iid-to-tags: {}", store_key);
+
+            match store.get(&store_key.as_bytes()) {
+                Ok(Some(value)) => Self::decode_tags(&value).map(Some),
+                Ok(None) => Ok(None),
+                Err(_) => Err(()),
+            }
+        } else {
+            Ok(None)
+        }
+    }
+
+    pub fn set_iid_to_tags(&self, iid: StoreObjectIID, tags: &[String]) -> Result<(), ()> {
+        if let Some(ref store) = self.store {
+            let store_key = StoreKeyerBuilder::iid_to_tags(self.bucket.as_str(), iid);
+
+            debug!("store set iid-to-tags: {}", store_key);
+
+            store
+                .put(&store_key.as_bytes(), &Self::encode_tags(tags))
+                .or(Err(()))
+        } else {
+            Err(())
+        }
+    }
+
+    pub fn delete_iid_to_tags(&self, iid: StoreObjectIID) -> Result<(), ()> {
+        if let Some(ref store) = self.store {
+            let store_key = StoreKeyerBuilder::iid_to_tags(self.bucket.as_str(), iid);
+
+            debug!("store delete iid-to-tags: {}", store_key);
+
+            store.delete(&store_key.as_bytes()).or(Err(()))
+        } else {
+            Err(())
+        }
+    }
+
+    /// IID-to-Vector mapper
+    ///
+    /// [IDX=18] ((iid)) ~> [((component))]
+    pub fn get_iid_to_vector(&self, iid: StoreObjectIID) -> Result<Option<StoreObjectVector>, ()> {
+        if let Some(ref store) = self.store {
+            let store_key = StoreKeyerBuilder::iid_to_vector(self.bucket.as_str(), iid);
+
+            debug!("store get iid-to-vector: {}", store_key);
+
+            match store.get(&store_key.as_bytes()) {
+                Ok(Some(value)) => Self::decode_vector(&value).map(Some),
+                Ok(None) => Ok(None),
+                Err(_) => Err(()),
+            }
+        } else {
+            Ok(None)
+        }
+    }
+
+    pub fn set_iid_to_vector(&self, iid: StoreObjectIID, vector: &[f32]) -> Result<(), ()> {
+        if let Some(ref store) = self.store {
+            let store_key = StoreKeyerBuilder::iid_to_vector(self.bucket.as_str(), iid);
+
+            debug!("store set iid-to-vector: {}", store_key);
+
+            store
+                .put(&store_key.as_bytes(), &Self::encode_vector(vector))
+                .or(Err(()))
+        } else {
+            Err(())
+        }
+    }
+
+    pub fn delete_iid_to_vector(&self, iid: StoreObjectIID) -> Result<(), ()> {
+        if let Some(ref store) = self.store {
+            let store_key = StoreKeyerBuilder::iid_to_vector(self.bucket.as_str(), iid);
+
+            debug!("store delete iid-to-vector: {}", store_key);
+
+            store.delete(&store_key.as_bytes()).or(Err(()))
+        } else {
+            Err(())
+        }
+    }
+
+    /// IID-to-Created mapper
+    ///
+    /// [IDX=19] ((iid)) ~> ((timestamp))
+    pub fn get_iid_to_created(
+        &self,
+        iid: StoreObjectIID,
+    ) -> Result<Option<StoreObjectCreated>, ()> {
+        if let Some(ref store) = self.store {
+            let store_key = StoreKeyerBuilder::iid_to_created(self.bucket.as_str(), iid);
+
+            debug!("store get iid-to-created: {}", store_key);
+
+            match store.get(&store_key.as_bytes()) {
+                Ok(Some(value)) => Self::decode_created(&value).map(Some),
+                Ok(None) => Ok(None),
+                Err(_) => Err(()),
+            }
+        } else {
+            Ok(None)
+        }
+    }
+
+    pub fn set_iid_to_created(
+        &self,
+        iid: StoreObjectIID,
+        created: StoreObjectCreated,
+    ) -> Result<(), ()> {
+        if let Some(ref store) = self.store {
+            let store_key = StoreKeyerBuilder::iid_to_created(self.bucket.as_str(), iid);
+
+            debug!("store set iid-to-created: {}", store_key);
+
+            store
+                .put(&store_key.as_bytes(), &Self::encode_created(created))
+                .or(Err(()))
+        } else {
+            Err(())
+        }
+    }
+
+    pub fn delete_iid_to_created(&self, iid: StoreObjectIID) -> Result<(), ()> {
+        if let Some(ref store) = self.store {
+            let store_key = StoreKeyerBuilder::iid_to_created(self.bucket.as_str(), iid);
+
+            debug!("store delete iid-to-created: {}", store_key);
+
+            store.delete(&store_key.as_bytes()).or(Err(()))
+        } else {
+            Err(())
+        }
+    }
+
+    /// IID-to-Segment mapper
+    ///
+    /// [IDX=20] ((iid)) ~> ((segment))
+    pub fn get_iid_to_segment(
+        &self,
+        iid: StoreObjectIID,
+    ) -> Result<Option<StoreObjectSegment>, ()> {
+        if let Some(ref store) = self.store {
+            let store_key = StoreKeyerBuilder::iid_to_segment(self.bucket.as_str(), iid);
+
+            debug!("store get iid-to-segment: {}", store_key);
+
+            match store.get(&store_key.as_bytes()) {
+                Ok(Some(value)) => Self::decode_segment(&value).map(Some),
+                Ok(None) => Ok(None),
+                Err(_) => Err(()),
+            }
+        } else {
+            Ok(None)
+        }
+    }
+
+    pub fn set_iid_to_segment(
+        &self,
+        iid: StoreObjectIID,
+        segment: StoreObjectSegment,
+    ) -> Result<(), ()> {
+        if let Some(ref store) = self.store {
+            let store_key = StoreKeyerBuilder::iid_to_segment(self.bucket.as_str(), iid);
+
+            debug!("store set iid-to-segment: {}", store_key);
+
+            store.put(&store_key.as_bytes(), &[segment]).or(Err(()))
+        } else {
+            Err(())
+        }
+    }
+
+    pub fn delete_iid_to_segment(&self, iid: StoreObjectIID) -> Result<(), ()> {
+        if let Some(ref store) = self.store {
+            let store_key = StoreKeyerBuilder::iid_to_segment(self.bucket.as_str(), iid);
+
+            debug!("store delete iid-to-segment: {}", store_key);
+
+            store.delete(&store_key.as_bytes()).or(Err(()))
+        } else {
+            Err(())
+        }
+    }
+
+    /// IID-to-Priority mapper
+    ///
+    /// [IDX=22] ((iid)) ~> ((priority))
+    pub fn get_iid_to_priority(
+        &self,
+        iid: StoreObjectIID,
+    ) -> Result<Option<StoreObjectPriority>, ()> {
+        if let Some(ref store) = self.store {
+            let store_key = StoreKeyerBuilder::iid_to_priority(self.bucket.as_str(), iid);
+
+            debug!("store get iid-to-priority: {}", store_key);
+
+            match store.get(&store_key.as_bytes()) {
+                Ok(Some(value)) => Self::decode_priority(&value).map(Some),
+                Ok(None) => Ok(None),
+                Err(_) => Err(()),
+            }
+        } else {
+            Ok(None)
+        }
+    }
+
+    pub fn set_iid_to_priority(
+        &self,
+        iid: StoreObjectIID,
+        priority: StoreObjectPriority,
+    ) -> Result<(), ()> {
+        if let Some(ref store) = self.store {
+            let store_key = StoreKeyerBuilder::iid_to_priority(self.bucket.as_str(), iid);
+
+            debug!("store set iid-to-priority: {}", store_key);
+
+            store.put(&store_key.as_bytes(), &[priority]).or(Err(()))
+        } else {
+            Err(())
+        }
+    }
+
+    pub fn delete_iid_to_priority(&self, iid: StoreObjectIID) -> Result<(), ()> {
+        if let Some(ref store) = self.store {
+            let store_key = StoreKeyerBuilder::iid_to_priority(self.bucket.as_str(), iid);
+
+            debug!("store delete iid-to-priority: {}", store_key);
+
+            store.delete(&store_key.as_bytes()).or(Err(()))
+        } else {
+            Err(())
+        }
+    }
+
+    /// OID-to-Alias mapper
+    ///
+    /// [IDX=23] ((oid)) ~> ((alias))
+    pub fn get_oid_to_alias(&self, oid: StoreObjectOID<'a>) -> Result<Option<String>, ()> {
+        if let Some(ref store) = self.store {
+            let store_key = StoreKeyerBuilder::oid_to_alias(self.bucket.as_str(), oid);
+
+            debug!("store get oid-to-alias: {}", store_key);
+
+            match store.get(&store_key.as_bytes()) {
+                Ok(Some(value)) => Ok(str::from_utf8(&value).ok().map(|value| value.to_string())),
+                Ok(None) => Ok(None),
+                Err(_) => Err(()),
+            }
+        } else {
+            Ok(None)
+        }
+    }
+
+    pub fn set_oid_to_alias(&self, oid: StoreObjectOID<'a>, alias: &str) -> Result<(), ()> {
+        if let Some(ref store) = self.store {
+            let store_key = StoreKeyerBuilder::oid_to_alias(self.bucket.as_str(), oid);
+
+            debug!("store set oid-to-alias: {}", store_key);
+
+            store
+                .put(&store_key.as_bytes(), alias.as_bytes())
+                .or(Err(()))
+        } else {
+            Err(())
+        }
+    }
+
+    pub fn delete_oid_to_alias(&self, oid: StoreObjectOID<'a>) -> Result<(), ()> {
+        if let Some(ref store) = self.store {
+            let store_key = StoreKeyerBuilder::oid_to_alias(self.bucket.as_str(), oid);
+
+            debug!("store delete oid-to-alias: {}", store_key);
+
+            store.delete(&store_key.as_bytes()).or(Err(()))
+        } else {
+            Err(())
+        }
+    }
+
+    /// IID-to-Positions mapper
+    ///
+    /// [IDX=21] ((iid)) ~> [((term), [((position))])]
+    pub fn get_iid_to_positions(
+        &self,
+        iid: StoreObjectIID,
+    ) -> Result<Option<StoreObjectPositions>, ()> {
+        if let Some(ref store) = self.store {
+            let store_key = StoreKeyerBuilder::iid_to_positions(self.bucket.as_str(), iid);
+
+            debug!("store get iid-to-positions: {}", store_key);
+
+            match store.get(&store_key.as_bytes()) {
+                Ok(Some(value)) => Self::decode_positions(&value).map(Some),
+                Ok(None) => Ok(None),
+                Err(_) => Err(()),
+            }
+        } else {
+            Ok(None)
+        }
+    }
+
+    pub fn set_iid_to_positions(
+        &self,
+        iid: StoreObjectIID,
+        positions: &[(StoreTermHashed, Vec<StoreTermPosition>)],
+    ) -> Result<(), ()> {
+        if let Some(ref store) = self.store {
+            let store_key = StoreKeyerBuilder::iid_to_positions(self.bucket.as_str(), iid);
+
+            debug!("store set iid-to-positions: {}", store_key);
+
+            store
+                .put(&store_key.as_bytes(), &Self::encode_positions(positions))
+                .or(Err(()))
+        } else {
+            Err(())
+        }
+    }
+
+    pub fn delete_iid_to_positions(&self, iid: StoreObjectIID) -> Result<(), ()> {
+        if let Some(ref store) = self.store {
+            let store_key = StoreKeyerBuilder::iid_to_positions(self.bucket.as_str(), iid);
+
+            debug!("store delete iid-to-positions: {}", store_key);
+
+            store.delete(&store_key.as_bytes()).or(Err(()))
+        } else {
+            Err(())
+        }
+    }
+
+    pub fn scan_term_to_iids(&self) -> Result<Vec<(StoreTermHashed, Vec<StoreObjectIID>)>, ()> {
+        let key_prefix = StoreKeyerBuilder::term_to_iids(self.bucket.as_str(), 0).as_prefix();
+
+        debug!("store scan term-to-iids: {:?}", key_prefix);
+
+        self.scan_bucket_routes(&key_prefix, |value| Self::decode_u32_list(value).ok())
+    }
+
+    pub fn scan_iid_to_oid(&self) -> Result<Vec<(StoreObjectIID, String)>, ()> {
+        let key_prefix = StoreKeyerBuilder::iid_to_oid(self.bucket.as_str(), 0).as_prefix();
+
+        debug!("store scan iid-to-oid: {:?}", key_prefix);
+
+        self.scan_bucket_routes(&key_prefix, |value| {
+            str::from_utf8(value).ok().map(|value| value.to_string())
+        })
+    }
+
+    pub fn scan_geo_cell_to_iids(&self) -> Result<Vec<(StoreGeoCell, Vec<StoreObjectIID>)>, ()> {
+        let key_prefix = StoreKeyerBuilder::geo_cell_to_iids(self.bucket.as_str(), 0).as_prefix();
+
+        debug!("store scan geo-cell-to-iids: {:?}", key_prefix);
+
+        self.scan_bucket_routes(&key_prefix, |value| Self::decode_u32_list(value).ok())
+    }
+
+    pub fn scan_iid_to_vector(&self) -> Result<Vec<(StoreObjectIID, StoreObjectVector)>, ()> {
+        let key_prefix = StoreKeyerBuilder::iid_to_vector(self.bucket.as_str(), 0).as_prefix();
+
+        debug!("store scan iid-to-vector: {:?}", key_prefix);
+
+        self.scan_bucket_routes(&key_prefix, |value| Self::decode_vector(value).ok())
+    }
+
+    pub fn batch_link_geo(&self, iid: StoreObjectIID, point: StoreGeoPoint) -> Result<(), ()> {
+        let cell = StoreGeoCellHash::from(point);
+
+        debug!(
+            "store batch link geo: {} to: {:?} in cell: {}",
+            iid, point, cell
+        );
+
+        // Unlink IID from its previous cell? (if it moved to another cell)
+        if let Some(point_previous) = self.get_iid_to_geo(iid)? {
+            let cell_previous = StoreGeoCellHash::from(point_previous);
+
+            if cell_previous != cell {
+                self.batch_unlink_geo_cell(iid, cell_previous)?;
+            }
+        }
+
+        // Link IID to its cell (if not already linked)
+        let mut cell_iids = self.get_geo_cell_to_iids(cell)?.unwrap_or_default();
+
+        if !cell_iids.contains(&iid) {
+            cell_iids.push(iid);
+
+            self.set_geo_cell_to_iids(cell, &cell_iids)?;
+        }
+
+        self.set_iid_to_geo(iid, point)
+    }
+
+    pub fn batch_flush_geo(&self, iid: StoreObjectIID) -> Result<(), ()> {
+        // Unlink IID from its cell, then drop its location (if it has any)
+        if let Some(point) = self.get_iid_to_geo(iid)? {
+            debug!("store batch flush geo: {}", iid);
+
+            self.batch_unlink_geo_cell(iid, StoreGeoCellHash::from(point))?;
+            self.delete_iid_to_geo(iid)?;
+        }
+
+        Ok(())
+    }
+
+    fn batch_unlink_geo_cell(&self, iid: StoreObjectIID, cell: StoreGeoCell) -> Result<(), ()> {
+        if let Some(mut cell_iids) = self.get_geo_cell_to_iids(cell)? {
+            cell_iids.retain(|cur_iid| cur_iid != &iid);
+
+            if cell_iids.is_empty() {
+                self.delete_geo_cell_to_iids(cell)?;
+            } else {
+                self.set_geo_cell_to_iids(cell, &cell_iids)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    pub fn batch_flush_bucket(
+        &self,
+        iid: StoreObjectIID,
+        oid: StoreObjectOID<'a>,
+        iid_terms_hashed: &[StoreTermHashed],
+    ) -> Result<u32, ()> {
+        let mut count = 0;
+
+        debug!(
+            "store batch flush bucket: {} with hashed terms: {:?}",
+            iid, iid_terms_hashed
+        );
+
+        // Delete OID <> IID association
+        match (
+            self.delete_oid_to_iid(oid),
+            self.delete_iid_to_oid(iid),
+            self.delete_iid_to_terms(iid),
+            self.batch_flush_geo(iid),
+            self.delete_iid_to_weight(iid),
+            self.delete_iid_to_text(iid),
+            self.delete_iid_to_tags(iid),
+            self.delete_iid_to_vector(iid),
+            self.delete_iid_to_created(iid),
+            self.delete_iid_to_segment(iid),
+            self.delete_iid_to_positions(iid),
+            self.delete_iid_to_priority(iid),
+            self.delete_oid_to_alias(oid),
+        ) {
+            (
+                Ok(_),
+                Ok(_),
+                Ok(_),
+                Ok(_),
+                Ok(_),
+                Ok(_),
+                Ok(_),
+                Ok(_),
+                Ok(_),
+                Ok(_),
+                Ok(_),
+                Ok(_),
+                Ok(_),
+            ) => {
+                // Delete IID from each associated term
+                for iid_term in iid_terms_hashed {
+                    if let Ok(Some(mut iid_term_iids)) = self.get_term_to_iids(*iid_term) {
+                        if iid_term_iids.contains(&iid) {
+                            count += 1;
+
+                            // Remove IID from list of IIDs
+                            iid_term_iids.retain(|cur_iid| cur_iid != &iid);
+                        }
+
+                        let is_ok = if iid_term_iids.is_empty() {
+                            self.delete_term_to_iids(*iid_term).is_ok()
+                        } else {
+                            self.set_term_to_iids(*iid_term, &iid_term_iids).is_ok()
+                        };
+
+                        if !is_ok {
+                            return Err(());
+                        }
+                    }
+                }
+
+                Ok(count)
+            }
+            _ => Err(()),
+        }
+    }
+
+    pub fn batch_truncate_object(
+        &self,
+        term_hashed: StoreTermHashed,
+        term_iids_drain: Drain<StoreObjectIID>,
+    ) -> Result<u32, ()> {
+        let mut count = 0;
+
+        for term_iid_drain in term_iids_drain {
+            debug!("store batch truncate object iid: {}", term_iid_drain);
+
+            // Nuke term in IID to Terms list
+            if let Ok(Some(mut term_iid_drain_terms)) = self.get_iid_to_terms(term_iid_drain) {
+                count += 1;
+
+                term_iid_drain_terms.retain(|cur_term| cur_term != &term_hashed);
+
+                // IID to Terms list is empty? Flush whole object.
+                if term_iid_drain_terms.is_empty() {
+                    // Acquire OID for this drained IID
+                    if let Ok(Some(term_iid_drain_oid)) = self.get_iid_to_oid(term_iid_drain) {
+                        if self
+                            .batch_flush_bucket(term_iid_drain, &term_iid_drain_oid, &Vec::new())
+                            .is_err()
+                        {
+                            error!(
+                                "failed executing store batch truncate object batch-flush-bucket"
+                            );
+                        }
+                    } else {
+                        error!("failed getting store batch truncate object iid-to-oid");
+                    }
+                } else {
+                    // Update IID to Terms list
+                    if self
+                        .set_iid_to_terms(term_iid_drain, &term_iid_drain_terms)
+                        .is_err()
+                    {
+                        error!("failed setting store batch truncate object iid-to-terms");
+                    }
+                }
+            }
+        }
+
+        Ok(count)
+    }
+
+    pub fn batch_erase_bucket(&self) -> Result<u32, ()> {
+        if let Some(ref store) = self.store {
+            // Generate all key prefix values (with dummy post-prefix values; we dont care)
+            let (k_meta_to_value, k_term_to_iids, k_oid_to_iid, k_iid_to_oid, k_iid_to_terms) = (
+                StoreKeyerBuilder::meta_to_value(self.bucket.as_str(), &StoreMetaKey::IIDIncr),
+                StoreKeyerBuilder::term_to_iids(self.bucket.as_str(), 0),
                 StoreKeyerBuilder::oid_to_iid(self.bucket.as_str(), &String::new()),
                 StoreKeyerBuilder::iid_to_oid(self.bucket.as_str(), 0),
                 StoreKeyerBuilder::iid_to_terms(self.bucket.as_str(), 0),
             );
+            let (k_geo_cell_to_iids, k_iid_to_geo, k_iid_to_weight, k_iid_to_text, k_iid_to_tags) = (
+                StoreKeyerBuilder::geo_cell_to_iids(self.bucket.as_str(), 0),
+                StoreKeyerBuilder::iid_to_geo(self.bucket.as_str(), 0),
+                StoreKeyerBuilder::iid_to_weight(self.bucket.as_str(), 0),
+                StoreKeyerBuilder::iid_to_text(self.bucket.as_str(), 0),
+                StoreKeyerBuilder::iid_to_tags(self.bucket.as_str(), 0),
+            );
+            let (k_iid_to_vector, k_iid_to_created, k_iid_to_segment, k_iid_to_positions) = (
+                StoreKeyerBuilder::iid_to_vector(self.bucket.as_str(), 0),
+                StoreKeyerBuilder::iid_to_created(self.bucket.as_str(), 0),
+                StoreKeyerBuilder::iid_to_segment(self.bucket.as_str(), 0),
+                StoreKeyerBuilder::iid_to_positions(self.bucket.as_str(), 0),
+            );
+            let (k_iid_to_priority, k_oid_to_alias, k_term_to_word) = (
+                StoreKeyerBuilder::iid_to_priority(self.bucket.as_str(), 0),
+                StoreKeyerBuilder::oid_to_alias(self.bucket.as_str(), ""),
+                StoreKeyerBuilder::term_to_word(self.bucket.as_str(), 0),
+            );
+
+            let key_prefixes: [StoreKeyerPrefix; 17] = [
+                k_meta_to_value.as_prefix(),
+                k_term_to_iids.as_prefix(),
+                k_oid_to_iid.as_prefix(),
+                k_iid_to_oid.as_prefix(),
+                k_iid_to_terms.as_prefix(),
+                k_geo_cell_to_iids.as_prefix(),
+                k_iid_to_geo.as_prefix(),
+                k_iid_to_weight.as_prefix(),
+                k_iid_to_text.as_prefix(),
+                k_iid_to_tags.as_prefix(),
+                k_iid_to_vector.as_prefix(),
+                k_iid_to_created.as_prefix(),
+                k_iid_to_segment.as_prefix(),
+                k_iid_to_positions.as_prefix(),
+                k_iid_to_priority.as_prefix(),
+                k_oid_to_alias.as_prefix(),
+                k_term_to_word.as_prefix(),
+            ];
+
+            // Scan all keys per-prefix and nuke them right away
+            for key_prefix in &key_prefixes {
+                debug!(
+                    "store batch erase bucket: {} for prefix: {:?}",
+                    self.bucket.as_str(),
+                    key_prefix
+                );
+
+                // Generate start and end prefix for batch delete (in other words, the minimum \
+                //   key value possible, and the highest key value possible)
+                let key_prefix_start: StoreKeyerKey = [
+                    key_prefix[0],
+                    key_prefix[1],
+                    key_prefix[2],
+                    key_prefix[3],
+                    key_prefix[4],
+                    0,
+                    0,
+                    0,
+                    0,
+                ];
+                let key_prefix_end: StoreKeyerKey = [
+                    key_prefix[0],
+                    key_prefix[1],
+                    key_prefix[2],
+                    key_prefix[3],
+                    key_prefix[4],
+                    255,
+                    255,
+                    255,
+                    255,
+                ];
+
+                // Batch-delete keys matching range
+                let mut batch = WriteBatch::default();
+
+                batch.delete_range(&key_prefix_start, &key_prefix_end);
+
+                // Commit operation to database
+                if let Err(err) = store.do_write(batch) {
+                    error!(
+                        "failed in store batch erase bucket: {} with error: {}",
+                        self.bucket.as_str(),
+                        err
+                    );
+                } else {
+                    // Ensure last key is deleted (as RocksDB end key is exclusive; while \
+                    //   start key is inclusive, we need to ensure the end-of-range key is \
+                    //   deleted)
+                    store.delete(&key_prefix_end).ok();
+
+                    debug!(
+                        "succeeded in store batch erase bucket: {}",
+                        self.bucket.as_str()
+                    );
+                }
+            }
+
+            info!(
+                "done processing store batch erase bucket: {}",
+                self.bucket.as_str()
+            );
+
+            Ok(1)
+        } else {
+            Err(())
+        }
+    }
+
+    fn scan_bucket_routes<T>(
+        &self,
+        key_prefix: &StoreKeyerPrefix,
+        fn_decode: impl Fn(&[u8]) -> Option<T>,
+    ) -> Result<Vec<(u32, T)>, ()> {
+        if let Some(ref store) = self.store {
+            let mut routes = Vec::new();
+
+            for (key, value) in store.scan(key_prefix) {
+                // Route is stored in the last 4 bytes of the key (ie. after the prefix)
+                match (
+                    Self::decode_u32(&key[key_prefix.len()..]),
+                    fn_decode(&value[..]),
+                ) {
+                    (Ok(route), Some(value_decoded)) => routes.push((route, value_decoded)),
+                    _ => {
+                        error!("failed decoding scanned store key: {:?}", key);
+
+                        return Err(());
+                    }
+                }
+            }
+
+            Ok(routes)
+        } else {
+            Ok(Vec::new())
+        }
+    }
+
+    fn encode_u32(decoded: u32) -> [u8; 4] {
+        let mut encoded = [0; 4];
+
+        LittleEndian::write_u32(&mut encoded, decoded);
+
+        encoded
+    }
+
+    fn decode_u32(encoded: &[u8]) -> Result<u32, ()> {
+        Cursor::new(encoded).read_u32::<LittleEndian>().or(Err(()))
+    }
+
+    fn encode_u32_list(decoded: &[u32]) -> Vec<u8> {
+        // Pre-reserve required capacity as to avoid heap resizes (50% performance gain relative \
+        //   to initializing this with a zero-capacity)
+        let mut encoded = Vec::with_capacity(decoded.len() * 4);
+
+        for decoded_item in decoded {
+            encoded.extend(&Self::encode_u32(*decoded_item))
+        }
+
+        encoded
+    }
+
+    fn decode_u32_list(encoded: &[u8]) -> Result<Vec<u32>, ()> {
+        // Pre-reserve required capacity as to avoid heap resizes (50% performance gain relative \
+        //   to initializing this with a zero-capacity)
+        let mut decoded = Vec::with_capacity(encoded.len() / 4);
+
+        for encoded_chunk in encoded.chunks(4) {
+            if let Ok(decoded_chunk) = Self::decode_u32(encoded_chunk) {
+                decoded.push(decoded_chunk);
+            } else {
+                return Err(());
+            }
+        }
+
+        Ok(decoded)
+    }
+
+    fn encode_geo_point(decoded: StoreGeoPoint) -> [u8; 8] {
+        let mut encoded = [0; 8];
+
+        LittleEndian::write_f32(&mut encoded[0..4], decoded.0);
+        LittleEndian::write_f32(&mut encoded[4..8], decoded.1);
+
+        encoded
+    }
+
+    fn decode_geo_point(encoded: &[u8]) -> Result<StoreGeoPoint, ()> {
+        let mut cursor = Cursor::new(encoded);
+
+        match (
+            cursor.read_f32::<LittleEndian>(),
+            cursor.read_f32::<LittleEndian>(),
+        ) {
+            (Ok(latitude), Ok(longitude)) => Ok((latitude, longitude)),
+            _ => Err(()),
+        }
+    }
+
+    fn encode_weight(decoded: StoreObjectWeight) -> [u8; 4] {
+        let mut encoded = [0; 4];
+
+        LittleEndian::write_f32(&mut encoded, decoded);
+
+        encoded
+    }
+
+    fn decode_weight(encoded: &[u8]) -> Result<StoreObjectWeight, ()> {
+        Cursor::new(encoded).read_f32::<LittleEndian>().or(Err(()))
+    }
+
+    fn encode_tags(decoded: &[String]) -> Vec<u8> {
+        // Notice: tags cannot contain commas, as they are passed as comma-separated lists.
+        decoded.join(",").into_bytes()
+    }
+
+    fn decode_tags(encoded: &[u8]) -> Result<StoreObjectTags, ()> {
+        let encoded = str::from_utf8(encoded).or(Err(()))?;
+
+        Ok(if encoded.is_empty() {
+            Vec::new()
+        } else {
+            encoded.split(',').map(|tag| tag.to_string()).collect()
+        })
+    }
+
+    fn encode_created(decoded: StoreObjectCreated) -> [u8; 8] {
+        let mut encoded = [0; 8];
+
+        LittleEndian::write_u64(&mut encoded, decoded);
+
+        encoded
+    }
+
+    fn decode_created(encoded: &[u8]) -> Result<StoreObjectCreated, ()> {
+        Cursor::new(encoded).read_u64::<LittleEndian>().or(Err(()))
+    }
+
+    fn decode_segment(encoded: &[u8]) -> Result<StoreObjectSegment, ()> {
+        match encoded {
+            [segment] => Ok(*segment),
+            _ => Err(()),
+        }
+    }
+
+    fn decode_priority(encoded: &[u8]) -> Result<StoreObjectPriority, ()> {
+        match encoded {
+            [priority] => Ok(*priority),
+            _ => Err(()),
+        }
+    }
+
+    fn encode_positions(decoded: &[(StoreTermHashed, Vec<StoreTermPosition>)]) -> Vec<u8> {
+        // Notice: positions are encoded per-term, as the term, then the count of positions, \
+        //   then positions themselves.
+        let mut encoded = Vec::new();
+
+        for (term_hashed, term_positions) in decoded {
+            encoded.extend(&Self::encode_u32(*term_hashed));
+            encoded.extend(&Self::encode_u32(term_positions.len() as u32));
+            encoded.extend(&Self::encode_u32_list(term_positions));
+        }
+
+        encoded
+    }
+
+    fn decode_positions(encoded: &[u8]) -> Result<StoreObjectPositions, ()> {
+        let mut decoded = Vec::new();
+        let mut cursor = Cursor::new(encoded);
+
+        while (cursor.position() as usize) < encoded.len() {
+            let term_hashed = cursor.read_u32::<LittleEndian>().or(Err(()))?;
+            let term_positions_count = cursor.read_u32::<LittleEndian>().or(Err(()))?;
+
+            let term_positions = (0..term_positions_count)
+                .map(|_| cursor.read_u32::<LittleEndian>().or(Err(())))
+                .collect::<Result<Vec<StoreTermPosition>, ()>>()?;
+
+            decoded.push((term_hashed, term_positions));
+        }
+
+        Ok(decoded)
+    }
+
+    fn encode_vector(decoded: &[f32]) -> Vec<u8> {
+        let mut encoded = vec![0; decoded.len() * 4];
+
+        LittleEndian::write_f32_into(decoded, &mut encoded);
+
+        encoded
+    }
+
+    fn decode_vector(encoded: &[u8]) -> Result<StoreObjectVector, ()> {
+        if encoded.chunks_exact(4).remainder().is_empty() {
+            let mut decoded = vec![0.0; encoded.len() / 4];
+
+            LittleEndian::read_f32_into(encoded, &mut decoded);
+
+            Ok(decoded)
+        } else {
+            Err(())
+        }
+    }
+}
+
+impl StoreKVMisc {
+    pub fn list_collections() -> Result<Vec<String>, ()> {
+        let kv_path = &APP_CONF.store.kv.path;
+
+        // Exit trap: no KV collection was ever created, there is nothing to list
+        if !kv_path.exists() {
+            return Ok(Vec::new());
+        }
+
+        let entries = fs::read_dir(kv_path).map_err(|err| {
+            error!(
+                "failed reading kv directory for list: {:?}: {}",
+                kv_path, err
+            );
+        })?;
+
+        // Notice: collection directories are named after collection hashes, in hexadecimal
+        Ok(entries
+            .flatten()
+            .filter(
+                |collection| matches!(collection.file_type(), Ok(file_type) if file_type.is_dir()),
+            )
+            .filter_map(|collection| collection.file_name().to_str().map(String::from))
+            .filter(|collection_name| {
+                collection_name
+                    .chars()
+                    .all(|character| character.is_ascii_hexdigit())
+            })
+            .collect())
+    }
+
+    pub fn fsck_collections() -> Result<(Vec<String>, usize), ()> {
+        let (mut collections, mut count_invalid) = (Vec::new(), 0);
+
+        let kv_path = &APP_CONF.store.kv.path;
+
+        // Exit trap: no KV collection was ever created, there is nothing to check
+        if !kv_path.exists() {
+            return Ok((collections, count_invalid));
+        }
+
+        let entries = fs::read_dir(kv_path).map_err(|err| {
+            error!(
+                "failed reading kv directory for fsck: {:?}: {}",
+                kv_path, err
+            );
+        })?;
+
+        for collection in entries.flatten() {
+            if let (Ok(collection_file_type), Some(collection_name)) =
+                (collection.file_type(), collection.file_name().to_str())
+            {
+                if collection_file_type.is_dir() {
+                    // Check that collection holds a readable database (this only reads the \
+                    //   database manifest, which is safe even if the database is open)
+                    if let Err(err) = DB::list_cf(&DBOptions::default(), collection.path()) {
+                        error!(
+                            "fsck found invalid kv collection: {}: {}",
+                            collection_name, err
+                        );
+
+                        count_invalid += 1;
+                    }
+
+                    collections.push(collection_name.to_string());
+                }
+            }
+        }
+
+        Ok((collections, count_invalid))
+    }
+
+    pub fn batch_copy_collection(
+        source: &Option<StoreKVBox>,
+        target: &Option<StoreKVBox>,
+    ) -> Result<u32, ()> {
+        let mut count = 0;
+
+        if let (Some(ref source), Some(ref target)) = (source, target) {
+            debug!("store batch copy collection");
+
+            // Copy all keys as-is (keys do not depend on the collection, only on buckets), and \
+            //   count copied objects from their IID-to-OID keys
+            let iid_to_oid_index = StoreKeyerBuilder::iid_to_oid("", 0).as_index();
+
+            let (mut batch, mut batch_size) = (WriteBatch::default(), 0);
+
+            for (key, value) in source.scan(&[]) {
+                if key.starts_with(&iid_to_oid_index) {
+                    count += 1;
+                }
+
+                batch.put(&key, &value);
+                batch_size += 1;
+
+                // Commit batch? (as to bound memory usage on large collections)
+                if batch_size >= STORE_COPY_BATCH_SIZE {
+                    target.do_write(batch).or(Err(()))?;
+
+                    batch = WriteBatch::default();
+                    batch_size = 0;
+                }
+            }
+
+            target.do_write(batch).or(Err(()))?;
+
+            debug!("store batch copied collection with {} objects", count);
+        }
+
+        Ok(count)
+    }
+
+    pub fn scan_collection_terms(
+        store: &Option<StoreKVBox>,
+    ) -> Result<HashMap<StoreKeyerAtom, HashSet<StoreTermHashed>>, ()> {
+        let mut buckets: HashMap<StoreKeyerAtom, HashSet<StoreTermHashed>> = HashMap::new();
+
+        if let Some(ref store) = store {
+            debug!("store scan collection terms");
+
+            // Scan term-to-iids keys from all buckets (bucket names cannot be recovered from \
+            //   keys, thus buckets are referenced by their atom)
+            let key_index = StoreKeyerBuilder::term_to_iids_from_atom(0, 0).as_index();
+
+            for (key, _) in store.scan(&key_index) {
+                if let Some((bucket_atom, term_hashed)) = Self::decode_key_atoms(&key) {
+                    buckets.entry(bucket_atom).or_default().insert(term_hashed);
+                } else {
+                    error!("failed decoding scanned store key: {:?}", key);
+
+                    return Err(());
+                }
+            }
+        }
+
+        Ok(buckets)
+    }
+
+    /// Term-to-Word mapper
+    ///
+    /// [IDX=5] ((term)) ~> ((word))
+    pub fn batch_set_terms_to_words(
+        store: &Option<StoreKVBox>,
+        bucket_atom: StoreKeyerAtom,
+        mappings: &[(StoreTermHashed, &str)],
+    ) -> Result<(), ()> {
+        if let Some(ref store) = store {
+            debug!(
+                "store batch set {} terms-to-words for bucket: <{:x?}>",
+                mappings.len(),
+                bucket_atom
+            );
 
-            let key_prefixes: [StoreKeyerPrefix; 5] = [
-                k_meta_to_value.as_prefix(),
-                k_term_to_iids.as_prefix(),
-                k_oid_to_iid.as_prefix(),
-                k_iid_to_oid.as_prefix(),
-                k_iid_to_terms.as_prefix(),
-            ];
+            // Commit in chunks (as to bound memory usage on large buckets)
+            for mappings_chunk in mappings.chunks(STORE_COPY_BATCH_SIZE) {
+                let mut batch = WriteBatch::default();
 
-            // Scan all keys per-prefix and nuke them right away
-            for key_prefix in &key_prefixes {
-                debug!(
-                    "store batch erase bucket: {} for prefix: {:?}",
-                    self.bucket.as_str(),
-                    key_prefix
-                );
+                for (term_hashed, word) in mappings_chunk {
+                    batch.put(
+                        StoreKeyerBuilder::term_to_word_from_atom(bucket_atom, *term_hashed)
+                            .as_bytes(),
+                        word.as_bytes(),
+                    );
+                }
 
-                // Generate start and end prefix for batch delete (in other words, the minimum \
-                //   key value possible, and the highest key value possible)
-                let key_prefix_start: StoreKeyerKey = [
-                    key_prefix[0],
-                    key_prefix[1],
-                    key_prefix[2],
-                    key_prefix[3],
-                    key_prefix[4],
-                    0,
-                    0,
-                    0,
-                    0,
-                ];
-                let key_prefix_end: StoreKeyerKey = [
-                    key_prefix[0],
-                    key_prefix[1],
-                    key_prefix[2],
-                    key_prefix[3],
-                    key_prefix[4],
-                    255,
-                    255,
-                    255,
-                    255,
-                ];
+                store.do_write(batch).or(Err(()))?;
+            }
 
-                // Batch-delete keys matching range
-                let mut batch = WriteBatch::default();
+            Ok(())
+        } else {
+            Err(())
+        }
+    }
 
-                batch.delete_range(&key_prefix_start, &key_prefix_end);
+    pub fn scan_collection_words(
+        store: &Option<StoreKVBox>,
+        bucket: Option<&str>,
+    ) -> Result<HashMap<StoreKeyerAtom, StoreKVBucketWords>, ()> {
+        let mut buckets: HashMap<StoreKeyerAtom, StoreKVBucketWords> = HashMap::new();
 
-                // Commit operation to database
-                if let Err(err) = store.do_write(batch) {
-                    error!(
-                        "failed in store batch erase bucket: {} with error: {}",
-                        self.bucket.as_str(),
-                        err
+        // Notice: the term-to-word reverse index may hold terms that were since popped, and \
+        //   may miss some terms, thus terms are listed from term-to-iids keys.
+        let collection_terms = Self::scan_collection_terms(store)?;
+
+        if let Some(ref store) = store {
+            debug!("store scan collection words for bucket: {:?}", bucket);
+
+            let bucket_atom = bucket.map(StoreKeyerHasher::to_compact);
+
+            // Scan term-to-word keys from all buckets, or from a single bucket (if given)
+            let mut words: HashMap<(StoreKeyerAtom, StoreTermHashed), String> = HashMap::new();
+            let words_index = StoreKeyerBuilder::term_to_word_from_atom(0, 0).as_index();
+
+            for (key, value) in store.scan(&words_index) {
+                match (Self::decode_key_atoms(&key), str::from_utf8(&value)) {
+                    (Some((key_bucket_atom, term_hashed)), Ok(word)) => {
+                        if bucket_atom.is_none() || bucket_atom == Some(key_bucket_atom) {
+                            words.insert((key_bucket_atom, term_hashed), word.to_owned());
+                        }
+                    }
+                    _ => {
+                        error!("failed decoding scanned store key: {:?}", key);
+
+                        return Err(());
+                    }
+                }
+            }
+
+            // Map listed terms to their words (if any)
+            for (key_bucket_atom, terms) in collection_terms {
+                if bucket_atom.is_none() || bucket_atom == Some(key_bucket_atom) {
+                    buckets.insert(
+                        key_bucket_atom,
+                        terms
+                            .into_iter()
+                            .map(|term_hashed| {
+                                (term_hashed, words.remove(&(key_bucket_atom, term_hashed)))
+                            })
+                            .collect(),
                     );
+                }
+            }
+        }
+
+        Ok(buckets)
+    }
+
+    pub fn batch_vacuum_collection(store: &Option<StoreKVBox>) -> Result<u32, ()> {
+        let mut count = 0;
+
+        if let Some(ref store) = store {
+            debug!("store batch vacuum collection");
+
+            let mut batch = WriteBatch::default();
+
+            // Delete term-to-iids keys that map to an empty list of IIDs, in all buckets
+            let terms_index = StoreKeyerBuilder::term_to_iids_from_atom(0, 0).as_index();
+
+            for (key, value) in store.scan(&terms_index) {
+                if StoreKVAction::decode_u32_list(&value)?.is_empty() {
+                    batch.delete(&key);
+
+                    count += 1;
+                }
+            }
+
+            // Delete OID <> IID associations for IIDs that are linked to no term, in all buckets
+            // Notice: objects that hold a vector are kept, as they may have no term on purpose.
+            let oids_index = StoreKeyerBuilder::iid_to_oid_from_atom(0, 0).as_index();
+
+            for (key, value) in store.scan(&oids_index) {
+                if let (Some((bucket_atom, iid)), Ok(oid)) =
+                    (Self::decode_key_atoms(&key), str::from_utf8(&value))
+                {
+                    let iid_terms_key =
+                        StoreKeyerBuilder::iid_to_terms_from_atom(bucket_atom, iid).as_bytes();
+
+                    let iid_vector_key =
+                        StoreKeyerBuilder::iid_to_vector_from_atom(bucket_atom, iid).as_bytes();
+
+                    let iid_terms = store.get(&iid_terms_key).or(Err(()))?;
+
+                    let has_terms = match iid_terms {
+                        Some(ref iid_terms) => {
+                            !StoreKVAction::decode_u32_list(iid_terms)?.is_empty()
+                        }
+                        None => false,
+                    };
+                    let has_vector = store.get(&iid_vector_key).or(Err(()))?.is_some();
+
+                    if !has_terms && !has_vector {
+                        debug!("store batch vacuum iid: {} with oid: {}", iid, oid);
+
+                        batch.delete(&key);
+                        batch.delete(
+                            StoreKeyerBuilder::oid_to_iid_from_atom(bucket_atom, oid).as_bytes(),
+                        );
+
+                        count += 2;
+
+                        if iid_terms.is_some() {
+                            batch.delete(iid_terms_key);
+
+                            count += 1;
+                        }
+                    }
                 } else {
-                    // Ensure last key is deleted (as RocksDB end key is exclusive; while \
-                    //   start key is inclusive, we need to ensure the end-of-range key is \
-                    //   deleted)
-                    store.delete(&key_prefix_end).ok();
+                    error!("failed decoding scanned store key: {:?}", key);
 
-                    debug!(
-                        "succeeded in store batch erase bucket: {}",
-                        self.bucket.as_str()
-                    );
+                    return Err(());
                 }
             }
 
-            info!(
-                "done processing store batch erase bucket: {}",
-                self.bucket.as_str()
-            );
+            store.do_write(batch).or(Err(()))?;
+        }
 
-            Ok(1)
+        Ok(count)
+    }
+
+    /// User-to-Boosts mapper
+    ///
+    /// [IDX=17] ((user)) ~> [((term), (factor))]
+    pub fn get_user_to_boosts(
+        store: &Option<StoreKVBox>,
+        user: StoreUserID,
+    ) -> Result<Option<StoreUserBoosts>, ()> {
+        if let Some(ref store) = store {
+            let store_key = StoreKeyerBuilder::user_to_boosts(user);
+
+            debug!("store get user-to-boosts: {}", store_key);
+
+            match store.get(&store_key.as_bytes()) {
+                Ok(Some(value)) => Self::decode_boosts(&value).map(Some),
+                Ok(None) => Ok(None),
+                Err(_) => Err(()),
+            }
         } else {
-            Err(())
+            Ok(None)
         }
     }
 
-    fn encode_u32(decoded: u32) -> [u8; 4] {
-        let mut encoded = [0; 4];
+    pub fn set_user_to_boosts(
+        store: &Option<StoreKVBox>,
+        user: StoreUserID,
+        boosts: &[(StoreTermHashed, f32)],
+    ) -> Result<(), ()> {
+        if let Some(ref store) = store {
+            let store_key = StoreKeyerBuilder::user_to_boosts(user);
 
-        LittleEndian::write_u32(&mut encoded, decoded);
+            debug!("store set user-to-boosts: {}", store_key);
 
-        encoded
+            store
+                .put(&store_key.as_bytes(), &Self::encode_boosts(boosts))
+                .or(Err(()))
+        } else {
+            Err(())
+        }
     }
 
-    fn decode_u32(encoded: &[u8]) -> Result<u32, ()> {
-        Cursor::new(encoded).read_u32::<LittleEndian>().or(Err(()))
+    pub fn batch_flush_collection_term(
+        store: &Option<StoreKVBox>,
+        term_hashed: StoreTermHashed,
+    ) -> Result<Vec<(StoreKeyerAtom, u32)>, ()> {
+        let mut buckets = Vec::new();
+
+        if let Some(ref store) = store {
+            debug!("store batch flush collection term: {}", term_hashed);
+
+            // Scan term-to-iids keys from all buckets, and retain those linked to term (bucket \
+            //   names cannot be recovered from keys, thus buckets are referenced by their atom)
+            let key_index = StoreKeyerBuilder::term_to_iids_from_atom(0, 0).as_index();
+
+            let term_buckets: Vec<(StoreKeyerAtom, Vec<StoreObjectIID>)> = store
+                .scan(&key_index)
+                .filter_map(|(key, value)| {
+                    match (
+                        Self::decode_key_atoms(&key),
+                        StoreKVAction::decode_u32_list(&value),
+                    ) {
+                        (Some((bucket_atom, route)), Ok(term_iids)) if route == term_hashed => {
+                            Some((bucket_atom, term_iids))
+                        }
+                        _ => None,
+                    }
+                })
+                .collect();
+
+            for (bucket_atom, term_iids) in term_buckets {
+                let mut count = 0;
+
+                // Remove term from IID to Terms list for all IIDs linked to term
+                for term_iid in term_iids {
+                    let iid_terms_key =
+                        StoreKeyerBuilder::iid_to_terms_from_atom(bucket_atom, term_iid).as_bytes();
+
+                    if let Ok(Some(value)) = store.get(&iid_terms_key) {
+                        let mut iid_terms = StoreKVAction::decode_u32_list(&value)?;
+
+                        if iid_terms.contains(&term_hashed) {
+                            count += 1;
+
+                            iid_terms.retain(|cur_term| cur_term != &term_hashed);
+
+                            // Notice: an object left without any term is kept, as its OID <> IID \
+                            //   association cannot be resolved without its bucket name.
+                            let is_ok = if iid_terms.is_empty() {
+                                store.delete(&iid_terms_key).is_ok()
+                            } else {
+                                store
+                                    .put(
+                                        &iid_terms_key,
+                                        &StoreKVAction::encode_u32_list(&iid_terms),
+                                    )
+                                    .is_ok()
+                            };
+
+                            if !is_ok {
+                                return Err(());
+                            }
+                        }
+                    }
+                }
+
+                // Nuke term from bucket
+                store
+                    .delete(
+                        &StoreKeyerBuilder::term_to_iids_from_atom(bucket_atom, term_hashed)
+                            .as_bytes(),
+                    )
+                    .or(Err(()))?;
+
+                debug!(
+                    "store batch flushed collection term: {} from bucket: {:x?} in {} objects",
+                    term_hashed, bucket_atom, count
+                );
+
+                buckets.push((bucket_atom, count));
+            }
+        }
+
+        Ok(buckets)
     }
 
-    fn encode_u32_list(decoded: &[u32]) -> Vec<u8> {
-        // Pre-reserve required capacity as to avoid heap resizes (50% performance gain relative \
-        //   to initializing this with a zero-capacity)
-        let mut encoded = Vec::with_capacity(decoded.len() * 4);
+    fn encode_boosts(decoded: &[(StoreTermHashed, f32)]) -> Vec<u8> {
+        let mut encoded = Vec::with_capacity(decoded.len() * 8);
 
-        for decoded_item in decoded {
-            encoded.extend(&Self::encode_u32(*decoded_item))
+        for (term_hashed, factor) in decoded {
+            encoded.extend(&StoreKVAction::encode_u32(*term_hashed));
+            encoded.extend(&StoreKVAction::encode_weight(*factor));
         }
 
         encoded
     }
 
-    fn decode_u32_list(encoded: &[u8]) -> Result<Vec<u32>, ()> {
-        // Pre-reserve required capacity as to avoid heap resizes (50% performance gain relative \
-        //   to initializing this with a zero-capacity)
-        let mut decoded = Vec::with_capacity(encoded.len() / 4);
+    fn decode_boosts(encoded: &[u8]) -> Result<StoreUserBoosts, ()> {
+        let mut decoded = Vec::with_capacity(encoded.len() / 8);
 
-        for encoded_chunk in encoded.chunks(4) {
-            if let Ok(decoded_chunk) = Self::decode_u32(encoded_chunk) {
-                decoded.push(decoded_chunk);
-            } else {
+        for encoded_chunk in encoded.chunks(8) {
+            if encoded_chunk.len() != 8 {
                 return Err(());
             }
+
+            decoded.push((
+                StoreKVAction::decode_u32(&encoded_chunk[0..4])?,
+                StoreKVAction::decode_weight(&encoded_chunk[4..8])?,
+            ));
         }
 
         Ok(decoded)
     }
+
+    fn decode_key_atoms(key: &[u8]) -> Option<(StoreKeyerAtom, u32)> {
+        // Key format: [idx<1B> | bucket<4B> | route<4B>]
+        match (key.get(1..5), key.get(5..9)) {
+            (Some(bucket_encoded), Some(route_encoded)) => match (
+                StoreKVAction::decode_u32(bucket_encoded),
+                StoreKVAction::decode_u32(route_encoded),
+            ) {
+                (Ok(bucket_atom), Ok(route)) => Some((bucket_atom, route)),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
 }
 
 impl StoreKVKey {
@@ -1238,6 +2867,27 @@ mod tests {
         assert!(action.delete_iid_to_terms(4).is_ok());
     }
 
+    #[test]
+    fn it_scans_actions() {
+        let store = StoreKVPool::acquire(StoreKVAcquireMode::Any, "c:test:4").unwrap();
+        let action =
+            StoreKVActionBuilder::access(StoreItemPart::from_str("b:test:4").unwrap(), store);
+
+        assert!(action.set_iid_to_oid(2, "s:2").is_ok());
+        assert!(action.set_iid_to_oid(7, "s:7").is_ok());
+        assert!(action.set_term_to_iids(1, &[7, 2]).is_ok());
+
+        let mut iids_to_oid = action.scan_iid_to_oid().unwrap();
+
+        iids_to_oid.sort();
+
+        assert_eq!(
+            iids_to_oid,
+            vec![(2, "s:2".to_string()), (7, "s:7".to_string())]
+        );
+        assert_eq!(action.scan_term_to_iids(), Ok(vec![(1, vec![7, 2])]));
+    }
+
     #[test]
     fn it_encodes_atom() {
         assert_eq!(StoreKVAction::encode_u32(0), [0, 0, 0, 0]);
@@ -1272,6 +2922,139 @@ mod tests {
             Ok(vec![45402])
         );
     }
+
+    #[test]
+    fn it_encodes_geo_point() {
+        assert_eq!(
+            StoreKVAction::decode_geo_point(&StoreKVAction::encode_geo_point((48.85, -2.35))),
+            Ok((48.85, -2.35))
+        );
+        assert_eq!(StoreKVAction::decode_geo_point(&[0, 0, 0, 0]), Err(()));
+    }
+
+    #[test]
+    fn it_encodes_weight() {
+        assert_eq!(
+            StoreKVAction::decode_weight(&StoreKVAction::encode_weight(0.75)),
+            Ok(0.75)
+        );
+        assert_eq!(StoreKVAction::decode_weight(&[0, 0]), Err(()));
+    }
+
+    #[test]
+    fn it_encodes_tags() {
+        let tags = vec!["category:books".to_string(), "status:published".to_string()];
+
+        assert_eq!(
+            StoreKVAction::decode_tags(&StoreKVAction::encode_tags(&tags)),
+            Ok(tags)
+        );
+        assert_eq!(StoreKVAction::decode_tags(&[]), Ok(vec![]));
+        assert_eq!(StoreKVAction::decode_tags(&[0xff]), Err(()));
+    }
+
+    #[test]
+    fn it_encodes_created() {
+        assert_eq!(
+            StoreKVAction::decode_created(&StoreKVAction::encode_created(1546300800)),
+            Ok(1546300800)
+        );
+        assert_eq!(StoreKVAction::decode_created(&[0, 0, 0, 0]), Err(()));
+    }
+
+    #[test]
+    fn it_decodes_segment() {
+        assert_eq!(StoreKVAction::decode_segment(&[42]), Ok(42));
+        assert_eq!(StoreKVAction::decode_segment(&[]), Err(()));
+        assert_eq!(StoreKVAction::decode_segment(&[1, 2]), Err(()));
+    }
+
+    #[test]
+    fn it_decodes_priority() {
+        assert_eq!(StoreKVAction::decode_priority(&[200]), Ok(200));
+        assert_eq!(StoreKVAction::decode_priority(&[]), Err(()));
+    }
+
+    #[test]
+    fn it_encodes_positions() {
+        let positions = vec![(1, vec![0, 4]), (2, vec![2])];
+
+        assert_eq!(
+            StoreKVAction::decode_positions(&StoreKVAction::encode_positions(&positions)),
+            Ok(positions)
+        );
+        assert_eq!(StoreKVAction::decode_positions(&[]), Ok(Vec::new()));
+        assert_eq!(
+            StoreKVAction::decode_positions(&[1, 0, 0, 0, 2, 0]),
+            Err(())
+        );
+    }
+
+    #[test]
+    fn it_encodes_vector() {
+        let vector = vec![0.6, -0.8, 0.0];
+
+        assert_eq!(
+            StoreKVAction::decode_vector(&StoreKVAction::encode_vector(&vector)),
+            Ok(vector)
+        );
+        assert_eq!(StoreKVAction::decode_vector(&[]), Ok(vec![]));
+        assert_eq!(StoreKVAction::decode_vector(&[0, 0]), Err(()));
+    }
+
+    #[test]
+    fn it_encodes_boosts() {
+        let boosts = vec![(45402, 1.5), (1, 0.25)];
+
+        assert_eq!(
+            StoreKVMisc::decode_boosts(&StoreKVMisc::encode_boosts(&boosts)),
+            Ok(boosts)
+        );
+        assert_eq!(StoreKVMisc::decode_boosts(&[]), Ok(vec![]));
+        assert_eq!(StoreKVMisc::decode_boosts(&[0, 0, 0, 0]), Err(()));
+    }
+
+    #[test]
+    fn it_prunes_incremental_backups() {
+        let db_path = std::env::temp_dir().join("sonic-test-store-kv-backup-db");
+        let backup_path = std::env::temp_dir().join("sonic-test-store-kv-backup");
+
+        fs::remove_dir_all(&db_path).ok();
+        fs::remove_dir_all(&backup_path).ok();
+
+        let mut db_options = DBOptions::default();
+
+        db_options.create_if_missing(true);
+
+        let db = DB::open(&db_options, &db_path).unwrap();
+
+        // Stack two backup generations in the same backup folder
+        {
+            let mut engine = StoreKVPool::open_backup_engine(&backup_path).unwrap();
+
+            assert!(db.put([0], [1]).is_ok());
+            assert!(engine.create_new_backup_flush(&db, true).is_ok());
+
+            assert!(db.put([1], [2]).is_ok());
+            assert!(engine.create_new_backup_flush(&db, true).is_ok());
+
+            assert_eq!(engine.get_backup_info().len(), 2);
+        }
+
+        // Prune down to a single backup generation
+        assert!(StoreKVPool::backup_prune_item(&backup_path, "test", 1).is_ok());
+
+        assert_eq!(
+            StoreKVPool::open_backup_engine(&backup_path)
+                .unwrap()
+                .get_backup_info()
+                .len(),
+            1
+        );
+
+        fs::remove_dir_all(db_path).unwrap();
+        fs::remove_dir_all(backup_path).unwrap();
+    }
 }
 
 #[cfg(all(feature = "benchmark", test))]
@@ -8,12 +8,25 @@ use crate::executor::count::ExecutorCount;
 use crate::executor::flushb::ExecutorFlushB;
 use crate::executor::flushc::ExecutorFlushC;
 use crate::executor::flusho::ExecutorFlushO;
+use crate::executor::flushorphans::ExecutorFlushOrphans;
+use crate::executor::flusht::ExecutorFlushT;
+use crate::executor::get::ExecutorGet;
+use crate::executor::inspect::ExecutorInspect;
+use crate::executor::learn::ExecutorLearn;
 use crate::executor::list::ExecutorList;
+use crate::executor::listbuckets::ExecutorListBuckets;
+use crate::executor::listcollections::ExecutorListCollections;
+use crate::executor::multibucketsearch::ExecutorMultiBucketSearch;
 use crate::executor::pop::ExecutorPop;
 use crate::executor::push::ExecutorPush;
 use crate::executor::search::ExecutorSearch;
+use crate::executor::spellcheck::ExecutorSpellcheck;
 use crate::executor::suggest::ExecutorSuggest;
+use crate::executor::suggestcontext::ExecutorSuggestContext;
+use crate::executor::vsearch::ExecutorVsearch;
 use crate::query::actions::Query;
+use crate::query::cache::QueryCache;
+use crate::store::item::StoreItemPart;
 
 pub struct StoreOperationDispatch;
 
@@ -21,12 +34,38 @@ impl StoreOperationDispatch {
     pub fn dispatch(query: Query) -> Result<Option<String>, ()> {
         // Dispatch de-constructed query to its target executor
         match query {
-            Query::Search(store, query_id, lexer, limit, offset) => {
-                ExecutorSearch::execute(store, query_id, lexer, limit, offset)
+            Query::Search(store, query_id, lexer, limit, offset, options) => {
+                ExecutorSearch::execute(store, query_id, lexer, limit, offset, options)
                     .map(|results| results.map(|results| results.join(" ")))
             }
-            Query::Suggest(store, query_id, lexer, limit) => {
-                ExecutorSuggest::execute(store, query_id, lexer, limit)
+            Query::MultiBucketSearch(store, query_id, terms, limit, offset, options) => {
+                ExecutorMultiBucketSearch::execute(store, query_id, terms, limit, offset, options)
+                    .map(|results| results.map(|results| results.join(" ")))
+            }
+            Query::Suggest(store, query_id, lexer, limit, options) => if options.context.is_empty()
+            {
+                ExecutorSuggest::execute(
+                    store,
+                    query_id,
+                    lexer,
+                    limit,
+                    options.expand,
+                    options.min_freq,
+                )
+            } else {
+                ExecutorSuggestContext::execute(
+                    store,
+                    query_id,
+                    lexer,
+                    limit,
+                    options.context,
+                    options.expand,
+                    options.min_freq,
+                )
+            }
+            .map(|results| results.map(|results| results.join(" "))),
+            Query::Spellcheck(store, query_id, lexer) => {
+                ExecutorSpellcheck::execute(store, query_id, lexer)
                     .map(|results| results.map(|results| results.join(" ")))
             }
             Query::List(store, query_id, limit, offset) => {
@@ -34,22 +73,95 @@ impl StoreOperationDispatch {
                     .map(|results| results.join(" "))
                     .map(|results| Some(results))
             }
-            Query::Push(store, lexer) => ExecutorPush::execute(store, lexer).map(|_| None),
+            Query::Vsearch(store, query_id, vector, limit) => {
+                ExecutorVsearch::execute(store, query_id, vector, limit)
+                    .map(|results| Some(results.join(" ")))
+            }
+            Query::Get(store) => ExecutorGet::execute(store),
+            Query::Push(store, lexer, options) => {
+                let (collection, bucket) = (store.0, store.1);
+
+                // Notice: a push yields a result only if it got rejected (ie. its requested IID \
+                //   is already in use, or its vector dimension does not match)
+                Self::invalidate_cache(
+                    ExecutorPush::execute(store, lexer, options),
+                    collection,
+                    bucket,
+                )
+                .map(|rejected| rejected.map(|reason| reason.to_string()))
+            }
             Query::Pop(store, lexer) => {
-                ExecutorPop::execute(store, lexer).map(|count| Some(count.to_string()))
+                let (collection, bucket) = (store.0, store.1);
+
+                Self::invalidate_cache(ExecutorPop::execute(store, lexer), collection, bucket)
+                    .map(|count| Some(count.to_string()))
             }
             Query::Count(store) => {
                 ExecutorCount::execute(store).map(|count| Some(count.to_string()))
             }
             Query::FlushC(store) => {
-                ExecutorFlushC::execute(store).map(|count| Some(count.to_string()))
+                let (collection, bucket) = (store.0, store.1);
+
+                Self::invalidate_cache(ExecutorFlushC::execute(store), collection, bucket)
+                    .map(|count| Some(count.to_string()))
             }
             Query::FlushB(store) => {
-                ExecutorFlushB::execute(store).map(|count| Some(count.to_string()))
+                let (collection, bucket) = (store.0, store.1);
+
+                Self::invalidate_cache(ExecutorFlushB::execute(store), collection, bucket)
+                    .map(|count| Some(count.to_string()))
             }
             Query::FlushO(store) => {
-                ExecutorFlushO::execute(store).map(|count| Some(count.to_string()))
+                let (collection, bucket) = (store.0, store.1);
+
+                Self::invalidate_cache(ExecutorFlushO::execute(store), collection, bucket)
+                    .map(|count| Some(count.to_string()))
+            }
+            Query::FlushT(store, lexer) => {
+                let (collection, bucket) = (store.0, store.1);
+
+                Self::invalidate_cache(ExecutorFlushT::execute(store, lexer), collection, bucket)
+                    .map(|count| Some(count.to_string()))
+            }
+            Query::FlushOrphans(store) => {
+                let (collection, bucket) = (store.0, store.1);
+
+                Self::invalidate_cache(ExecutorFlushOrphans::execute(store), collection, bucket)
+                    .map(|count| Some(count.to_string()))
+            }
+            Query::Learn(store, user, lexer) => {
+                let (collection, bucket) = (store.0, store.1);
+
+                // Notice: user boosts alter search results ranking in all buckets
+                Self::invalidate_cache(
+                    ExecutorLearn::execute(store, user, lexer),
+                    collection,
+                    bucket,
+                )
+                .map(|count| Some(count.to_string()))
+            }
+            Query::Inspect(store, limit) => {
+                ExecutorInspect::execute(store, limit).map(|results| Some(results.join(" ")))
+            }
+            Query::ListCollections => {
+                ExecutorListCollections::execute().map(|results| Some(results.join(" ")))
+            }
+            Query::ListBuckets(store) => {
+                ExecutorListBuckets::execute(store).map(|results| Some(results.join(" ")))
             }
         }
     }
+    pub fn invalidate_cache<T>(
+        result: Result<T, ()>,
+        collection: StoreItemPart,
+        bucket: Option<StoreItemPart>,
+    ) -> Result<T, ()> {
+        // Invalidate cached search results for changed bucket? (or whole collection, if no \
+        //   bucket is given)
+        if result.is_ok() {
+            QueryCache::invalidate(collection, bucket);
+        }
+
+        result
+    }
 }
@@ -0,0 +1,125 @@
+// Sonic
+//
+// Fast, lightweight and schema-less search backend
+// Copyright: 2019, Valerian Saliou <valerian@valeriansaliou.name>
+// License: Mozilla Public License v2.0 (MPL v2.0)
+
+use chrono::{DateTime, Utc};
+use cron::Schedule;
+use hashbrown::HashMap;
+use std::str::FromStr;
+use std::sync::{Mutex, RwLock};
+
+use crate::store::kv::{StoreKVKey, StoreKVPool};
+use crate::APP_CONF;
+
+pub struct TaskerCompaction;
+
+lazy_static! {
+    static ref COMPACTION_SCHEDULE_DEFAULT: Option<Schedule> = APP_CONF
+        .store
+        .kv
+        .compaction_schedule
+        .as_ref()
+        .and_then(|expression| {
+            let schedule = TaskerCompaction::parse_schedule(expression);
+
+            if schedule.is_none() {
+                error!("invalid kv compaction schedule: {}", expression);
+            }
+
+            schedule
+        });
+    static ref COMPACTION_SCHEDULE_OVERRIDES: RwLock<HashMap<StoreKVKey, Option<Schedule>>> =
+        RwLock::new(HashMap::new());
+    static ref COMPACTION_LAST_TICK: Mutex<Option<DateTime<Utc>>> = Mutex::new(None);
+}
+
+impl TaskerCompaction {
+    pub fn parse_schedule(expression: &str) -> Option<Schedule> {
+        // Standard cron expressions have 5 fields, while the parser expects seconds to come \
+        //   first (thus, 5-field expressions are completed to fire on the first second)
+        if expression.split_whitespace().count() == 5 {
+            Schedule::from_str(&format!("0 {}", expression.trim())).ok()
+        } else {
+            Schedule::from_str(expression.trim()).ok()
+        }
+    }
+
+    pub fn override_schedule(collection: &str, schedule: Option<Schedule>) {
+        info!(
+            "overriding kv compaction schedule for collection: {} with: {:?}",
+            collection,
+            schedule.as_ref().map(Schedule::to_string)
+        );
+
+        COMPACTION_SCHEDULE_OVERRIDES
+            .write()
+            .unwrap()
+            .insert(StoreKVKey::from_str(collection), schedule);
+    }
+
+    pub fn tick() {
+        let now = Utc::now();
+
+        // Notice: schedules are checked for any run time that got due since the last tick, as \
+        //   ticks do not happen at precise times (nothing is due on the very first tick).
+        let last_tick = COMPACTION_LAST_TICK.lock().unwrap().replace(now);
+
+        if let Some(last_tick) = last_tick {
+            // Notice: overrides are copied, so that their lock does not get held during \
+            //   compactions, which may take a long time.
+            let overrides = COMPACTION_SCHEDULE_OVERRIDES.read().unwrap().clone();
+
+            let count_compacted = StoreKVPool::compact(|pool_key| {
+                let schedule = overrides
+                    .get(pool_key)
+                    .unwrap_or(&*COMPACTION_SCHEDULE_DEFAULT);
+
+                Self::is_due(schedule.as_ref(), &last_tick, &now)
+            });
+
+            if count_compacted > 0 {
+                info!("ran scheduled compaction on {} kv stores", count_compacted);
+            }
+        }
+    }
+
+    fn is_due(schedule: Option<&Schedule>, since: &DateTime<Utc>, now: &DateTime<Utc>) -> bool {
+        if let Some(schedule) = schedule {
+            matches!(schedule.after(since).next(), Some(upcoming) if upcoming <= *now)
+        } else {
+            false
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_parses_schedule() {
+        assert!(TaskerCompaction::parse_schedule("0 2 * * *").is_some());
+        assert!(TaskerCompaction::parse_schedule("30 0 2 * * *").is_some());
+        assert!(TaskerCompaction::parse_schedule("0 25 * * *").is_none());
+        assert!(TaskerCompaction::parse_schedule("daily").is_none());
+    }
+
+    #[test]
+    fn it_checks_due_schedule() {
+        let schedule = TaskerCompaction::parse_schedule("0 2 * * *");
+        let (before, after) = (
+            DateTime::parse_from_rfc3339("2019-03-01T01:59:55Z")
+                .unwrap()
+                .with_timezone(&Utc),
+            DateTime::parse_from_rfc3339("2019-03-01T02:00:05Z")
+                .unwrap()
+                .with_timezone(&Utc),
+        );
+
+        assert!(TaskerCompaction::is_due(schedule.as_ref(), &before, &after));
+        assert!(!TaskerCompaction::is_due(schedule.as_ref(), &after, &after));
+        assert!(!TaskerCompaction::is_due(None, &before, &after));
+    }
+}
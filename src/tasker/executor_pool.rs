@@ -0,0 +1,106 @@
+// Sonic
+//
+// Fast, lightweight and schema-less search backend
+// Copyright: 2019, Valerian Saliou <valerian@valeriansaliou.name>
+// License: Mozilla Public License v2.0 (MPL v2.0)
+
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::mpsc::{self, Receiver, Sender};
+use std::sync::{Arc, Mutex};
+use std::thread;
+
+use crate::{APP_CONF, THREAD_NAME_EXECUTOR};
+
+pub struct TaskerExecutorPoolBuilder;
+pub struct TaskerExecutorPool;
+
+pub type TaskerExecutorJob = Box<dyn FnOnce() + Send>;
+
+lazy_static! {
+    static ref EXECUTOR_POOL_SENDER: Mutex<Option<Sender<TaskerExecutorJob>>> = Mutex::new(None);
+}
+
+impl TaskerExecutorPoolBuilder {
+    pub fn build() -> TaskerExecutorPool {
+        TaskerExecutorPool {}
+    }
+}
+
+impl TaskerExecutorPool {
+    pub fn run(&self) {
+        let (sender, receiver) = mpsc::channel();
+
+        // Notice: workers share a single job queue, thus a job gets picked by the first idle \
+        //   worker (a slow job does not hold other jobs queued behind it).
+        let receiver = Arc::new(Mutex::new(receiver));
+
+        let workers: Vec<_> = (0..Self::threads())
+            .filter_map(|_| {
+                let receiver = receiver.clone();
+
+                thread::Builder::new()
+                    .name(THREAD_NAME_EXECUTOR.to_string())
+                    .spawn(move || Self::consume(receiver))
+                    .map_err(|err| error!("failed spawning executor pool worker: {}", err))
+                    .ok()
+            })
+            .collect();
+
+        // Bind job sender (this replaces any sender left from a previous crashed run)
+        *EXECUTOR_POOL_SENDER.lock().unwrap() = Some(sender);
+
+        info!(
+            "executor pool tasker is now active with {} workers",
+            workers.len()
+        );
+
+        // Block on worker threads (join them)
+        for worker in workers {
+            if worker.join().is_err() {
+                error!("executor pool worker crashed");
+            }
+        }
+    }
+
+    pub fn submit(job: TaskerExecutorJob) -> Result<(), TaskerExecutorJob> {
+        if let Some(ref sender) = *EXECUTOR_POOL_SENDER.lock().unwrap() {
+            // Return the job to the caller if it could not be enqueued, so that it can run it
+            sender.send(job).map_err(|err| {
+                error!("failed submitting executor pool job, as workers are gone");
+
+                err.0
+            })
+        } else {
+            warn!("could not submit executor pool job, as workers are not active yet");
+
+            Err(job)
+        }
+    }
+
+    fn threads() -> usize {
+        // Default to the number of logical CPUs (if it cannot be determined, use a single worker)
+        APP_CONF.channel.executor_threads.unwrap_or_else(|| {
+            thread::available_parallelism()
+                .map(|threads| threads.get())
+                .unwrap_or(1)
+        })
+    }
+
+    fn consume(receiver: Arc<Mutex<Receiver<TaskerExecutorJob>>>) {
+        loop {
+            // Important: the receiver lock must be released before the job runs, as to let \
+            //   other workers pick the next jobs in the meantime.
+            let job = receiver.lock().unwrap().recv();
+
+            match job {
+                Ok(job) => {
+                    // Notice: a job that panics must not take the worker down with it
+                    if panic::catch_unwind(AssertUnwindSafe(job)).is_err() {
+                        error!("executor pool job panicked");
+                    }
+                }
+                Err(_) => break,
+            }
+        }
+    }
+}
@@ -4,5 +4,8 @@
 // Copyright: 2019, Valerian Saliou <valerian@valeriansaliou.name>
 // License: Mozilla Public License v2.0 (MPL v2.0)
 
+pub mod compaction;
+pub mod executor_pool;
 pub mod runtime;
 pub mod shutdown;
+pub mod webhook;
@@ -7,6 +7,8 @@
 use std::thread;
 use std::time::{Duration, Instant};
 
+use super::compaction::TaskerCompaction;
+use crate::channel::ratelimiter::ChannelRateLimiter;
 use crate::store::fst::StoreFSTPool;
 use crate::store::kv::StoreKVPool;
 
@@ -51,9 +53,13 @@ impl Tasker {
         // #1: Janitors
         StoreKVPool::janitor();
         StoreFSTPool::janitor();
+        ChannelRateLimiter::janitor();
 
         // #2: Others
         StoreKVPool::flush(false);
         StoreFSTPool::consolidate(false);
+
+        // #3: Scheduled
+        TaskerCompaction::tick();
     }
 }
@@ -0,0 +1,171 @@
+// Sonic
+//
+// Fast, lightweight and schema-less search backend
+// Copyright: 2019, Valerian Saliou <valerian@valeriansaliou.name>
+// License: Mozilla Public License v2.0 (MPL v2.0)
+
+use std::sync::mpsc::{self, Receiver, SyncSender, TrySendError};
+use std::sync::Mutex;
+use std::thread;
+use std::time::Duration;
+
+use crate::APP_CONF;
+
+pub struct TaskerWebhookBuilder;
+pub struct TaskerWebhook;
+
+pub enum TaskerWebhookEvent {
+    Push(String, String, String),
+}
+
+#[derive(Serialize)]
+struct TaskerWebhookPayload<'a> {
+    event: &'a str,
+    collection: &'a str,
+    bucket: &'a str,
+    oid: &'a str,
+}
+
+const WEBHOOK_RETRY_ATTEMPTS: u32 = 3;
+const WEBHOOK_RETRY_BACKOFF_BASE: Duration = Duration::from_secs(1);
+const WEBHOOK_REQUEST_TIMEOUT: u64 = 10;
+const WEBHOOK_QUEUE_SIZE: usize = 10000;
+
+lazy_static! {
+    static ref WEBHOOK_SENDER: Mutex<Option<SyncSender<TaskerWebhookEvent>>> = Mutex::new(None);
+}
+
+impl TaskerWebhookBuilder {
+    pub fn build() -> TaskerWebhook {
+        TaskerWebhook {}
+    }
+}
+
+impl TaskerWebhook {
+    pub fn run(&self) {
+        // Notice: the events queue is bounded, as events would otherwise pile up in memory if \
+        //   the webhook endpoint is slow or down (deliveries get retried with backoffs).
+        let (sender, receiver) = mpsc::sync_channel(WEBHOOK_QUEUE_SIZE);
+
+        // Bind event sender (this replaces any sender left from a previous crashed run)
+        *WEBHOOK_SENDER.lock().unwrap() = Some(sender);
+
+        info!("webhook tasker is now active");
+
+        Self::consume(receiver);
+    }
+
+    pub fn notify_push(collection: &str, bucket: &str, oid: &str) {
+        // Notice: only enqueue events if a webhook is configured, as nobody would consume them
+        if APP_CONF.store.webhooks.on_push.is_some() {
+            Self::enqueue(TaskerWebhookEvent::Push(
+                collection.to_owned(),
+                bucket.to_owned(),
+                oid.to_owned(),
+            ));
+        }
+    }
+
+    fn enqueue(event: TaskerWebhookEvent) {
+        if let Some(ref sender) = *WEBHOOK_SENDER.lock().unwrap() {
+            // Important: never block the caller on a full queue, as pushes would then stall on \
+            //   webhook deliveries; drop the event instead.
+            match sender.try_send(event) {
+                Ok(_) => {}
+                Err(TrySendError::Full(_)) => {
+                    warn!("dropped webhook event, as events queue is full");
+                }
+                Err(TrySendError::Disconnected(_)) => {
+                    error!("failed enqueuing webhook event, as consumer is gone");
+                }
+            }
+        } else {
+            warn!("could not enqueue webhook event, as consumer is not active yet");
+        }
+    }
+
+    fn consume(receiver: Receiver<TaskerWebhookEvent>) {
+        while let Ok(event) = receiver.recv() {
+            match event {
+                TaskerWebhookEvent::Push(collection, bucket, oid) => {
+                    if let Some(ref url) = APP_CONF.store.webhooks.on_push {
+                        Self::deliver(
+                            url,
+                            &TaskerWebhookPayload {
+                                event: "push",
+                                collection: &collection,
+                                bucket: &bucket,
+                                oid: &oid,
+                            },
+                        );
+                    }
+                }
+            }
+        }
+    }
+
+    fn deliver(url: &str, payload: &TaskerWebhookPayload) {
+        let body = match serde_json::to_string(payload) {
+            Ok(body) => body,
+            Err(err) => {
+                error!("failed serializing webhook payload: {}", err);
+
+                return;
+            }
+        };
+
+        // Retry failed deliveries with an exponential backoff (ie. 1s, 2s, 4s)
+        for attempt in 0..=WEBHOOK_RETRY_ATTEMPTS {
+            if attempt > 0 {
+                thread::sleep(WEBHOOK_RETRY_BACKOFF_BASE * 2_u32.pow(attempt - 1));
+            }
+
+            debug!(
+                "delivering {} webhook (attempt: {})",
+                payload.event, attempt
+            );
+
+            match minreq::post(url)
+                .with_header("Content-Type", "application/json")
+                .with_body(body.as_str())
+                .with_timeout(WEBHOOK_REQUEST_TIMEOUT)
+                .send()
+            {
+                Ok(response) if (200..300).contains(&response.status_code) => {
+                    debug!("delivered {} webhook", payload.event);
+
+                    return;
+                }
+                Ok(response) => warn!(
+                    "failed delivering {} webhook, got status: {}",
+                    payload.event, response.status_code
+                ),
+                Err(err) => warn!("failed delivering {} webhook: {}", payload.event, err),
+            }
+        }
+
+        error!(
+            "gave up delivering {} webhook after {} retries",
+            payload.event, WEBHOOK_RETRY_ATTEMPTS
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_serializes_payload() {
+        assert_eq!(
+            serde_json::to_string(&TaskerWebhookPayload {
+                event: "push",
+                collection: "messages",
+                bucket: "default",
+                oid: "conversation:\"1\"",
+            })
+            .unwrap(),
+            r#"{"event":"push","collection":"messages","bucket":"default","oid":"conversation:\"1\""}"#
+        );
+    }
+}